@@ -0,0 +1,112 @@
+use std::collections::HashMap;
+
+/// Parsed command-line arguments: positional words and `--flag value` options.
+/// Options may be repeated; `--flag=value` is accepted as well.
+#[derive(Debug, Default)]
+pub struct Args {
+    pub positional: Vec<String>,
+    options: HashMap<String, Vec<String>>,
+    switches: Vec<String>,
+}
+
+impl Args {
+    /// Parses arguments. `switches` lists the options that take no value.
+    pub fn parse(
+        args: impl IntoIterator<Item = String>,
+        switches: &[&str],
+    ) -> Result<Self, String> {
+        let mut parsed = Args::default();
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            let Some(name) = arg.strip_prefix("--") else {
+                parsed.positional.push(arg);
+                continue;
+            };
+
+            if let Some((name, value)) = name.split_once('=') {
+                parsed.push_option(name, value.to_string());
+            } else if switches.contains(&name) {
+                parsed.switches.push(name.to_string());
+            } else {
+                let value = args
+                    .next()
+                    .ok_or_else(|| format!("missing value for --{}", name))?;
+                parsed.push_option(name, value);
+            }
+        }
+
+        Ok(parsed)
+    }
+
+    fn push_option(&mut self, name: &str, value: String) {
+        self.options
+            .entry(name.to_string())
+            .or_default()
+            .push(value);
+    }
+
+    /// Returns the last value given for an option.
+    pub fn value(&self, name: &str) -> Option<&str> {
+        self.options
+            .get(name)
+            .and_then(|values| values.last())
+            .map(String::as_str)
+    }
+
+    /// Returns every value given for a repeatable option.
+    pub fn values(&self, name: &str) -> Vec<&str> {
+        self.options
+            .get(name)
+            .map(|values| values.iter().map(String::as_str).collect())
+            .unwrap_or_default()
+    }
+
+    /// Returns true if a switch was given.
+    pub fn switch(&self, name: &str) -> bool {
+        self.switches.iter().any(|s| s == name)
+    }
+
+    /// Returns the database URL from `--database-url` or `DATABASE_URL`.
+    pub fn database_url(&self) -> Result<String, String> {
+        match self.value("database-url") {
+            Some(url) => Ok(url.to_string()),
+            None => std::env::var("DATABASE_URL").map_err(|_| {
+                "no database given: pass --database-url or set DATABASE_URL".to_string()
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Args {
+        Args::parse(args.iter().map(|s| s.to_string()), &["dry-run"]).unwrap()
+    }
+
+    #[test]
+    fn test_parse_options_and_positional() {
+        let args = parse(&[
+            "codegen",
+            "--schema",
+            "app",
+            "--schema=public",
+            "--dry-run",
+            "--out",
+            "src/db",
+        ]);
+        assert_eq!(args.positional, ["codegen"]);
+        assert_eq!(args.values("schema"), ["app", "public"]);
+        assert_eq!(args.value("out"), Some("src/db"));
+        assert!(args.switch("dry-run"));
+        assert!(!args.switch("verbose"));
+    }
+
+    #[test]
+    fn test_missing_value() {
+        let err = Args::parse(vec!["--out".to_string()], &[]).unwrap_err();
+        assert_eq!(err, "missing value for --out");
+    }
+}
@@ -0,0 +1,44 @@
+use std::fs;
+use std::path::Path;
+
+use pgrs::codegen::{generate, CodegenOptions};
+use pgrs::drivers::TokioPostgresDriver;
+use pgrs::schema::introspect;
+
+use crate::args::Args;
+
+pub const USAGE: &str = "pgrs codegen [--database-url URL] [--schema NAME]... [--out DIR]
+             [--feature NAME]...
+
+Reads the given schemas (default: public) and writes one Rust module per
+schema plus a mod.rs into DIR (default: src/db).
+
+Each --feature (uuid, rust_decimal, serde_json, chrono or time) maps the
+matching column types to that crate's types instead of String.";
+
+/// Introspects the database and writes the generated modules.
+pub async fn codegen(args: &Args) -> Result<(), String> {
+    let url = args.database_url()?;
+    let mut schemas = args.values("schema");
+    if schemas.is_empty() {
+        schemas.push("public");
+    }
+    let out = Path::new(args.value("out").unwrap_or("src/db"));
+    let options = CodegenOptions::from_features(args.values("feature"))?;
+
+    let driver = TokioPostgresDriver::connect(&url)
+        .await
+        .map_err(|e| e.to_string())?;
+    let schema = introspect(&driver, &schemas)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    fs::create_dir_all(out).map_err(|e| format!("{}: {}", out.display(), e))?;
+    for file in generate(&schema, &options).map_err(|e| e.to_string())? {
+        let path = out.join(&file.path);
+        fs::write(&path, file.contents).map_err(|e| format!("{}: {}", path.display(), e))?;
+        println!("wrote {}", path.display());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,96 @@
+use std::fs;
+use std::path::Path;
+
+use pgrs::drivers::TokioPostgresDriver;
+use pgrs::migrations::Migrations;
+use pgrs::schema::{diff as schema_diff, introspect, DatabaseSchema, TableDef};
+
+use crate::args::Args;
+
+pub const USAGE: &str = "pgrs diff <name> [--database-url URL] [--snapshot FILE] [--dir DIR]
+
+Compares the tables registered with pgrs_cli::Cli against the database, or
+against the schema snapshot in FILE when --snapshot is given, and writes
+the statements needed to match them to DIR/V{n}__<name>.sql (default DIR:
+migrations). Drops and narrowing changes are commented out and marked
+MANUAL REVIEW. With --snapshot, FILE is updated to the registered tables;
+a missing FILE is treated as an empty schema.";
+
+/// Writes a migration for the differences between the registered tables
+/// and the database or snapshot.
+pub async fn diff(args: &Args, tables: &[TableDef]) -> Result<(), String> {
+    if tables.is_empty() {
+        return Err("no tables registered; see `pgrs diff --help`".to_string());
+    }
+    let name = match args.positional.get(1) {
+        Some(name) if !name.is_empty() => name.replace([' ', '-'], "_"),
+        _ => return Err(format!("expected a migration name\n\n{}", USAGE)),
+    };
+    let dir = Path::new(args.value("dir").unwrap_or("migrations"));
+    let snapshot = args.value("snapshot").map(Path::new);
+
+    let actual = match snapshot {
+        Some(path) if path.exists() => {
+            let contents =
+                fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+            DatabaseSchema::from_snapshot(&contents).map_err(|e| e.to_string())?
+        }
+        Some(_) => DatabaseSchema::default(),
+        None => {
+            let url = args.database_url()?;
+            let driver = TokioPostgresDriver::connect(&url)
+                .await
+                .map_err(|e| e.to_string())?;
+            let mut schemas: Vec<&str> = tables
+                .iter()
+                .map(|t| t.schema.as_deref().unwrap_or("public"))
+                .collect();
+            schemas.sort_unstable();
+            schemas.dedup();
+            introspect(&driver, &schemas)
+                .await
+                .map_err(|e| e.to_string())?
+        }
+    };
+
+    let changes = schema_diff(tables, &actual).map_err(|e| e.to_string())?;
+    if changes.is_empty() {
+        println!("no changes");
+        return Ok(());
+    }
+
+    let version = if dir.exists() {
+        let existing = Migrations::from_dir(dir).map_err(|e| e.to_string())?;
+        existing.iter().map(|m| m.version).max().unwrap_or(0) + 1
+    } else {
+        fs::create_dir_all(dir).map_err(|e| format!("{}: {}", dir.display(), e))?;
+        1
+    };
+    let path = dir.join(format!("V{}__{}.sql", version, name));
+    fs::write(&path, changes.to_migration_sql())
+        .map_err(|e| format!("{}: {}", path.display(), e))?;
+    println!("wrote {}", path.display());
+
+    if let Some(snapshot) = snapshot {
+        let declared = DatabaseSchema {
+            tables: tables.to_vec(),
+            enums: Vec::new(),
+        };
+        fs::write(snapshot, declared.to_snapshot())
+            .map_err(|e| format!("{}: {}", snapshot.display(), e))?;
+        println!("wrote {}", snapshot.display());
+    }
+
+    let review = changes
+        .changes
+        .iter()
+        .filter(|c| c.review.is_some())
+        .count();
+    if review > 0 {
+        println!(
+            "{} change(s) need manual review before they will run",
+            review
+        );
+    }
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use pgrs::drivers::TokioPostgresDriver;
+use pgrs::schema::{check_drift, TableDef};
+
+use crate::args::Args;
+
+pub const USAGE: &str = "pgrs drift [--database-url URL]
+
+Compares the tables registered with pgrs_cli::Cli against the database and
+exits with an error if any are missing or differ in columns, types or
+nullability. The stock pgrs binary has no tables registered; run this from
+a binary of your own that registers them.";
+
+/// Compares the registered tables with the database catalog.
+pub async fn drift(args: &Args, tables: &[TableDef]) -> Result<(), String> {
+    if tables.is_empty() {
+        return Err("no tables registered; see `pgrs drift --help`".to_string());
+    }
+    let url = args.database_url()?;
+
+    let driver = TokioPostgresDriver::connect(&url)
+        .await
+        .map_err(|e| e.to_string())?;
+    let report = check_drift(&driver, tables)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    if report.is_empty() {
+        println!("no drift in {} table(s)", tables.len());
+        return Ok(());
+    }
+    for issue in &report.issues {
+        println!("{}", issue);
+    }
+    Err(format!("{} drift issue(s) found", report.issues.len()))
+}
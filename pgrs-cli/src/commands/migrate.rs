@@ -0,0 +1,68 @@
+use std::sync::Arc;
+
+use pgrs::drivers::TokioPostgresDriver;
+use pgrs::migrations::{MigrationState, Migrations, Migrator};
+
+use crate::args::Args;
+
+pub const USAGE: &str = "pgrs migrate <up|down|status|verify> [--database-url URL] [--dir DIR]
+
+  up        apply all pending migrations
+  down      revert the latest applied migration
+  status    list migrations and whether they are applied
+  verify    fail if an applied migration was modified or is missing
+
+Migrations are read from DIR (default: migrations) unless they were
+registered with pgrs_cli::Cli and no --dir is given. Files are named
+V{n}__{name}.sql, with optional U{n}__{name}.sql files to revert them.";
+
+/// Runs a migration subcommand.
+pub async fn migrate(args: &Args, registered: Option<&Migrations>) -> Result<(), String> {
+    let command = match args.positional.get(1).map(String::as_str) {
+        Some(command @ ("up" | "down" | "status" | "verify")) => command,
+        _ => return Err(format!("expected a migrate subcommand\n\n{}", USAGE)),
+    };
+    let migrations = match (args.value("dir"), registered) {
+        (None, Some(migrations)) => migrations.clone(),
+        (dir, _) => Migrations::from_dir(dir.unwrap_or("migrations")).map_err(|e| e.to_string())?,
+    };
+    let url = args.database_url()?;
+
+    let driver = TokioPostgresDriver::connect(&url)
+        .await
+        .map_err(|e| e.to_string())?;
+    let migrator = Migrator::new(Arc::new(driver), migrations);
+
+    match command {
+        "up" => {
+            let applied = migrator.up().await.map_err(|e| e.to_string())?;
+            if applied.is_empty() {
+                println!("no pending migrations");
+            }
+            for version in applied {
+                println!("applied V{}", version);
+            }
+        }
+        "down" => match migrator.down().await.map_err(|e| e.to_string())? {
+            Some(version) => println!("reverted V{}", version),
+            None => println!("no applied migrations"),
+        },
+        "status" => {
+            for status in migrator.status().await.map_err(|e| e.to_string())? {
+                let state = match status.state {
+                    MigrationState::Pending => "pending",
+                    MigrationState::Applied => "applied",
+                    MigrationState::Modified => "modified",
+                    MigrationState::Missing => "missing",
+                };
+                println!("{:<9} V{} {}", state, status.version, status.name);
+            }
+        }
+        "verify" => {
+            migrator.verify().await.map_err(|e| e.to_string())?;
+            println!("applied migrations match");
+        }
+        _ => unreachable!(),
+    }
+    Ok(())
+}
@@ -0,0 +1,9 @@
+pub mod codegen;
+pub mod diff;
+pub mod drift;
+pub mod migrate;
+
+pub use codegen::codegen;
+pub use diff::diff;
+pub use drift::drift;
+pub use migrate::migrate;
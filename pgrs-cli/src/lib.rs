@@ -0,0 +1,148 @@
+//! `pgrs` command-line tool.
+//!
+//! The `pgrs` binary runs the commands that only need a database. Commands
+//! that compare the database against compiled tables, such as `drift` and
+//! `diff`, need those tables registered, so projects embed the tool in a
+//! binary of their own:
+//!
+//! ```ignore
+//! #[tokio::main]
+//! async fn main() -> std::process::ExitCode {
+//!     pgrs_cli::Cli::new().table::<Users>().table::<Orders>().run().await
+//! }
+//! ```
+
+use std::process::ExitCode;
+
+use pgrs::error::Result as PgRsResult;
+use pgrs::migrations::Migrations;
+use pgrs::schema::TableDef;
+use pgrs::traits::Table;
+
+mod args;
+mod commands;
+
+use args::Args;
+
+const USAGE: &str = "usage: pgrs <command> [options]
+
+commands:
+  codegen    generate Table/Column definitions from a live database
+  diff       write a migration from registered tables and a database or snapshot
+  drift      compare registered tables against a live database
+  migrate    apply, revert, list or verify migrations";
+
+/// The `pgrs` command-line tool, with the tables and migrations it should
+/// know about.
+#[derive(Default)]
+pub struct Cli {
+    tables: Vec<fn() -> PgRsResult<TableDef>>,
+    migrations: Option<Migrations>,
+}
+
+impl Cli {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a table for commands that compare against compiled tables.
+    pub fn table<T: Table>(mut self) -> Self {
+        self.tables.push(TableDef::of::<T>);
+        self
+    }
+
+    /// Registers migrations, typically from `pgrs::embed_migrations!`, for
+    /// `migrate` to use when no `--dir` is given.
+    pub fn migrations(mut self, migrations: Migrations) -> Self {
+        self.migrations = Some(migrations);
+        self
+    }
+
+    /// Runs the command given by the process arguments.
+    pub async fn run(self) -> ExitCode {
+        self.run_with(std::env::args().skip(1)).await
+    }
+
+    /// Runs the command given by `args`, excluding the program name.
+    pub async fn run_with(self, args: impl IntoIterator<Item = String>) -> ExitCode {
+        let args = match Args::parse(args, &["help"]) {
+            Ok(args) => args,
+            Err(e) => {
+                eprintln!("error: {}", e);
+                return ExitCode::FAILURE;
+            }
+        };
+
+        let result = match args.positional.first().map(String::as_str) {
+            Some("codegen") if args.switch("help") => {
+                println!("{}", commands::codegen::USAGE);
+                Ok(())
+            }
+            Some("codegen") => commands::codegen(&args).await,
+            Some("diff") if args.switch("help") => {
+                println!("{}", commands::diff::USAGE);
+                Ok(())
+            }
+            Some("diff") => match self.table_defs() {
+                Ok(tables) => commands::diff(&args, &tables).await,
+                Err(e) => Err(e),
+            },
+            Some("drift") if args.switch("help") => {
+                println!("{}", commands::drift::USAGE);
+                Ok(())
+            }
+            Some("drift") => match self.table_defs() {
+                Ok(tables) => commands::drift(&args, &tables).await,
+                Err(e) => Err(e),
+            },
+            Some("migrate") if args.switch("help") => {
+                println!("{}", commands::migrate::USAGE);
+                Ok(())
+            }
+            Some("migrate") => commands::migrate(&args, self.migrations.as_ref()).await,
+            Some("help") | None => {
+                println!("{}", USAGE);
+                Ok(())
+            }
+            Some(command) => {
+                eprintln!("error: unknown command `{}`\n\n{}", command, USAGE);
+                return ExitCode::FAILURE;
+            }
+        };
+
+        match result {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(e) => {
+                eprintln!("error: {}", e);
+                ExitCode::FAILURE
+            }
+        }
+    }
+
+    fn table_defs(&self) -> Result<Vec<TableDef>, String> {
+        self.tables
+            .iter()
+            .map(|of| of().map_err(|e| e.to_string()))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[tokio::test]
+    async fn test_run_with_usage() {
+        for given in [&[][..], &["help"], &["--help"]] {
+            assert_eq!(Cli::new().run_with(args(given)).await, ExitCode::SUCCESS);
+        }
+        assert_eq!(
+            Cli::new().run_with(args(&["migrat"])).await,
+            ExitCode::FAILURE
+        );
+    }
+}
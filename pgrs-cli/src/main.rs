@@ -0,0 +1,6 @@
+use std::process::ExitCode;
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    pgrs_cli::Cli::new().run().await
+}
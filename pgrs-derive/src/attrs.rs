@@ -0,0 +1,112 @@
+use syn::{Attribute, LitStr, Result, Token};
+
+/// Options from `#[pgrs(...)]` on the deriving struct.
+#[derive(Default)]
+pub struct ContainerAttrs {
+    pub table: Option<String>,
+    pub schema: Option<String>,
+}
+
+impl ContainerAttrs {
+    pub fn parse(attrs: &[Attribute]) -> Result<Self> {
+        let mut parsed = Self::default();
+        for attr in attrs.iter().filter(|a| a.path().is_ident("pgrs")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("table") {
+                    parsed.table = Some(meta.value()?.parse::<LitStr>()?.value());
+                } else if meta.path.is_ident("schema") {
+                    parsed.schema = Some(meta.value()?.parse::<LitStr>()?.value());
+                } else {
+                    return Err(meta.error("unknown pgrs container attribute"));
+                }
+                Ok(())
+            })?;
+        }
+        Ok(parsed)
+    }
+}
+
+/// Options from `#[pgrs(...)]` on a struct field.
+#[derive(Default)]
+pub struct FieldAttrs {
+    pub rename: Option<String>,
+    pub skip: bool,
+    pub primary_key: bool,
+    pub unique: bool,
+    /// The column DEFAULT expression, from `sql_default = "..."`.
+    pub sql_default: Option<String>,
+    /// Fill the field with `Default::default()` when the column is absent,
+    /// from `default`.
+    pub use_default: bool,
+    pub flatten: bool,
+    pub sql_type: Option<LitStr>,
+    pub custom_type: Option<String>,
+}
+
+impl FieldAttrs {
+    pub fn parse(attrs: &[Attribute]) -> Result<Self> {
+        let mut parsed = Self::default();
+        for attr in attrs.iter().filter(|a| a.path().is_ident("pgrs")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename") {
+                    parsed.rename = Some(meta.value()?.parse::<LitStr>()?.value());
+                } else if meta.path.is_ident("skip") {
+                    parsed.skip = true;
+                } else if meta.path.is_ident("primary_key") {
+                    parsed.primary_key = true;
+                } else if meta.path.is_ident("unique") {
+                    parsed.unique = true;
+                } else if meta.path.is_ident("sql_default") {
+                    parsed.sql_default = Some(meta.value()?.parse::<LitStr>()?.value());
+                } else if meta.path.is_ident("default") {
+                    if meta.input.peek(Token![=]) {
+                        return Err(meta.error(
+                            "`default` takes no value; use `sql_default = \"...\"` for a column DEFAULT",
+                        ));
+                    }
+                    parsed.use_default = true;
+                } else if meta.path.is_ident("flatten") {
+                    parsed.flatten = true;
+                } else if meta.path.is_ident("sql_type") {
+                    parsed.sql_type = Some(meta.value()?.parse::<LitStr>()?);
+                } else if meta.path.is_ident("custom_type") {
+                    parsed.custom_type = Some(meta.value()?.parse::<LitStr>()?.value());
+                } else {
+                    return Err(meta.error("unknown pgrs field attribute"));
+                }
+                Ok(())
+            })?;
+        }
+        Ok(parsed)
+    }
+}
+
+/// Converts `snake_case` to `PascalCase`.
+pub fn pascal_case(name: &str) -> String {
+    name.split('_')
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect::<String>(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+/// Converts `PascalCase` to `snake_case`.
+pub fn snake_case(name: &str) -> String {
+    let mut out = String::with_capacity(name.len() + 4);
+    for (i, ch) in name.chars().enumerate() {
+        if ch.is_uppercase() {
+            if i > 0 {
+                out.push('_');
+            }
+            out.extend(ch.to_lowercase());
+        } else {
+            out.push(ch);
+        }
+    }
+    out
+}
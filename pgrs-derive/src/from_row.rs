@@ -0,0 +1,66 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Fields, Result};
+
+use crate::attrs::{ContainerAttrs, FieldAttrs};
+
+pub fn expand(input: DeriveInput) -> Result<TokenStream> {
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "FromRow can only be derived for structs",
+        ));
+    };
+    let Fields::Named(fields) = &data.fields else {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "FromRow can only be derived for structs with named fields",
+        ));
+    };
+
+    // Table and FromRow share `#[pgrs(...)]`; validate it even though the
+    // container attributes do not affect mapping.
+    ContainerAttrs::parse(&input.attrs)?;
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let mut field_inits = Vec::new();
+    for field in &fields.named {
+        let attrs = FieldAttrs::parse(&field.attrs)?;
+        let field_ident = field.ident.as_ref().expect("named field");
+        let ty = &field.ty;
+
+        let init = if attrs.skip {
+            quote! { ::std::default::Default::default() }
+        } else if attrs.flatten {
+            quote! { <#ty as ::pgrs::types::FromRow>::from_row(row)? }
+        } else {
+            let field_name = field_ident.to_string();
+            let column_name = attrs
+                .rename
+                .unwrap_or_else(|| field_name.trim_start_matches("r#").to_string());
+            if attrs.use_default {
+                quote! {
+                    if row.contains(#column_name) {
+                        row.get_named_as::<#ty>(#column_name)?
+                    } else {
+                        ::std::default::Default::default()
+                    }
+                }
+            } else {
+                quote! { row.get_named_as::<#ty>(#column_name)? }
+            }
+        };
+        field_inits.push(quote! { #field_ident: #init });
+    }
+
+    Ok(quote! {
+        impl #impl_generics ::pgrs::types::FromRow for #ident #ty_generics #where_clause {
+            fn from_row(row: &::pgrs::types::Row) -> ::pgrs::Result<Self> {
+                ::std::result::Result::Ok(Self {
+                    #(#field_inits,)*
+                })
+            }
+        }
+    })
+}
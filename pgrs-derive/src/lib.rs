@@ -0,0 +1,43 @@
+//! Derive macros for pgrs.
+//!
+//! These are re-exported by `pgrs` when its `derive` feature is enabled;
+//! depend on `pgrs` rather than on this crate directly.
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput};
+
+mod attrs;
+mod from_row;
+mod table;
+
+/// Derives `Table` for a struct, along with a `{Struct}Columns` accessor
+/// struct and one `Column` type per field named `{Struct}{Field}`.
+///
+/// Container attributes: `#[pgrs(table = "...", schema = "...")]`.
+/// Field attributes: `rename = "..."`, `skip`, `primary_key`, `unique`,
+/// `sql_default = "..."`, `sql_type = "..."` for a built-in type and
+/// `custom_type = "..."` for any other type, such as an enum.
+///
+/// Also generates a unit struct `{Struct}Table` implementing `Table`, to pass
+/// the table as a value to builders.
+#[proc_macro_derive(Table, attributes(pgrs))]
+pub fn derive_table(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    table::expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derives `FromRow` for a struct, reading each field from the column of
+/// the same name with `Row::get_named_as`.
+///
+/// Field attributes: `rename = "..."` reads another column, `flatten` maps
+/// the field from the same row with its own `FromRow`, `default` uses
+/// `Default::default()` when the column is absent, and `skip` always does.
+#[proc_macro_derive(FromRow, attributes(pgrs))]
+pub fn derive_from_row(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    from_row::expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
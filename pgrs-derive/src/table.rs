@@ -0,0 +1,285 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Data, DeriveInput, Fields, GenericArgument, PathArguments, Result, Type};
+
+use crate::attrs::{pascal_case, snake_case, ContainerAttrs, FieldAttrs};
+
+/// Returns the inner type if `ty` is written as `Option<T>`.
+fn option_inner(ty: &Type) -> Option<&Type> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    if segment.ident != "Option" {
+        return None;
+    }
+    let PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    match args.args.first()? {
+        GenericArgument::Type(inner) => Some(inner),
+        _ => None,
+    }
+}
+
+/// Type names `PgType` parses into a built-in type, with optional
+/// modifiers such as `(255)` and any number of `[]` suffixes.
+fn is_known_sql_type(name: &str) -> bool {
+    let mut name = name.trim();
+    while let Some(inner) = name.strip_suffix("[]") {
+        name = inner.trim_end();
+    }
+    let lower = name.to_ascii_lowercase();
+    let base = match lower.split_once('(') {
+        Some((before, rest)) => {
+            let Some((modifiers, after)) = rest.split_once(')') else {
+                return false;
+            };
+            if !modifiers
+                .split(',')
+                .all(|m| m.trim().parse::<u32>().is_ok())
+            {
+                return false;
+            }
+            format!("{} {}", before.trim(), after.trim())
+                .trim()
+                .to_string()
+        }
+        None => lower,
+    };
+    base.starts_with("interval ")
+        || matches!(
+            base.as_str(),
+            "smallint"
+                | "int2"
+                | "integer"
+                | "int"
+                | "int4"
+                | "bigint"
+                | "int8"
+                | "smallserial"
+                | "serial2"
+                | "serial"
+                | "serial4"
+                | "bigserial"
+                | "serial8"
+                | "real"
+                | "float4"
+                | "double precision"
+                | "float8"
+                | "numeric"
+                | "decimal"
+                | "text"
+                | "character varying"
+                | "varchar"
+                | "character"
+                | "char"
+                | "bpchar"
+                | "boolean"
+                | "bool"
+                | "bytea"
+                | "uuid"
+                | "date"
+                | "time"
+                | "time without time zone"
+                | "timestamp"
+                | "timestamp without time zone"
+                | "timestamptz"
+                | "timestamp with time zone"
+                | "interval"
+                | "json"
+                | "jsonb"
+        )
+}
+
+pub fn expand(input: DeriveInput) -> Result<TokenStream> {
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "Table can only be derived for structs",
+        ));
+    };
+    let Fields::Named(fields) = &data.fields else {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "Table can only be derived for structs with named fields",
+        ));
+    };
+
+    let container = ContainerAttrs::parse(&input.attrs)?;
+    let vis = &input.vis;
+    let ident = &input.ident;
+    let columns_ident = format_ident!("{}Columns", ident);
+    let table_ident = format_ident!("{}Table", ident);
+    let table_name = container
+        .table
+        .unwrap_or_else(|| snake_case(&ident.to_string()));
+    let schema = match container.schema {
+        Some(schema) => quote! { Some(#schema) },
+        None => quote! { None },
+    };
+
+    let mut column_fields = Vec::new();
+    let mut column_inits = Vec::new();
+    let mut column_items = Vec::new();
+    let mut column_idents = Vec::new();
+
+    for field in &fields.named {
+        let attrs = FieldAttrs::parse(&field.attrs)?;
+        if attrs.skip {
+            continue;
+        }
+
+        if attrs.flatten {
+            return Err(syn::Error::new_spanned(
+                field,
+                "flatten is only supported by FromRow",
+            ));
+        }
+
+        let field_ident = field.ident.as_ref().expect("named field");
+        let field_name = field_ident.to_string();
+        let field_name = field_name.trim_start_matches("r#");
+        let column_name = attrs.rename.unwrap_or_else(|| field_name.to_string());
+        let column_ident = format_ident!("{}{}", ident, pascal_case(field_name));
+        if column_ident == columns_ident || column_ident == table_ident {
+            return Err(syn::Error::new_spanned(
+                field_ident,
+                format!(
+                    "column type {} would collide with a generated type",
+                    column_ident
+                ),
+            ));
+        }
+
+        let (nullable, value_ty) = match option_inner(&field.ty) {
+            Some(inner) => (true, inner),
+            None => (false, &field.ty),
+        };
+        let sql_type = match attrs.sql_type {
+            Some(sql_type) => {
+                if !is_known_sql_type(&sql_type.value()) {
+                    return Err(syn::Error::new_spanned(
+                        &sql_type,
+                        format!(
+                            "unknown sql_type \"{}\"; use custom_type for other types",
+                            sql_type.value()
+                        ),
+                    ));
+                }
+                quote! {
+                    #sql_type.parse::<::pgrs::types::PgType>().unwrap()
+                }
+            }
+            None => match attrs.custom_type {
+                Some(custom_type) => quote! {
+                    ::pgrs::types::PgType::Custom(#custom_type.to_string())
+                },
+                None => quote! {
+                    <#value_ty as ::pgrs::types::ToPgType>::pg_type()
+                },
+            },
+        };
+        let default_expression = match attrs.sql_default {
+            Some(default) => quote! { Some(#default) },
+            None => quote! { None },
+        };
+        let primary_key = attrs.primary_key;
+        let unique = attrs.unique;
+
+        column_fields.push(quote! { pub #field_ident: #column_ident });
+        column_inits.push(quote! { #field_ident: #column_ident });
+        column_items.push(quote! {
+            #[derive(Debug, Clone, Copy, Default)]
+            #vis struct #column_ident;
+
+            impl ::pgrs::traits::Column for #column_ident {
+                fn column_name(&self) -> &'static str {
+                    #column_name
+                }
+                fn table_name(&self) -> &'static str {
+                    #table_name
+                }
+                fn schema_name(&self) -> Option<&'static str> {
+                    #schema
+                }
+                fn sql_type(&self) -> Option<::pgrs::types::PgType> {
+                    Some(#sql_type)
+                }
+                fn is_nullable(&self) -> bool {
+                    #nullable
+                }
+                fn default_expression(&self) -> Option<&'static str> {
+                    #default_expression
+                }
+                fn is_primary_key(&self) -> bool {
+                    #primary_key
+                }
+                fn is_unique(&self) -> bool {
+                    #unique
+                }
+            }
+        });
+        column_idents.push(column_ident);
+    }
+
+    let table_impl = |target: &syn::Ident| {
+        quote! {
+            impl ::pgrs::traits::Table for #target {
+                type Columns = #columns_ident;
+
+                fn table_name() -> &'static str {
+                    #table_name
+                }
+
+                fn schema() -> Option<&'static str> {
+                    #schema
+                }
+
+                fn columns() -> Self::Columns {
+                    #columns_ident {
+                        #(#column_inits,)*
+                    }
+                }
+
+                fn all_columns() -> Vec<&'static dyn ::pgrs::traits::Column> {
+                    vec![#(&#column_idents),*]
+                }
+            }
+        }
+    };
+    let struct_table = table_impl(ident);
+    let unit_table = table_impl(&table_ident);
+
+    Ok(quote! {
+        #vis struct #columns_ident {
+            #(#column_fields,)*
+        }
+
+        #(#column_items)*
+
+        #struct_table
+
+        /// The table of the derived struct as a value, for builders that take
+        /// a table such as `SelectBuilder::from`.
+        #[derive(Debug, Clone, Copy, Default)]
+        #vis struct #table_ident;
+
+        #unit_table
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_known_sql_type() {
+        assert!(is_known_sql_type("timestamptz"));
+        assert!(is_known_sql_type("character varying(255)"));
+        assert!(is_known_sql_type("numeric(10, 2)[]"));
+        assert!(is_known_sql_type("timestamp(3) with time zone"));
+        assert!(!is_known_sql_type("timestamtz"));
+        assert!(!is_known_sql_type("varchar(n)"));
+    }
+}
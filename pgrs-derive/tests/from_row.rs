@@ -0,0 +1,157 @@
+use std::sync::Arc;
+
+use pgrs::drivers::{InMemoryTestDriver, InMemoryTestResponseBuilder};
+use pgrs::types::{FromRow, QueryResult, RawQueryResult, Row, SqlValue, UNKNOWN_TYPE_OID};
+use pgrs::{PgRsClient, PgRsError, Table};
+
+#[derive(pgrs::Table, pgrs::FromRow, Debug, PartialEq)]
+#[pgrs(table = "users")]
+struct User {
+    #[pgrs(primary_key)]
+    id: i64,
+    #[pgrs(rename = "full_name")]
+    name: String,
+    email: Option<String>,
+    #[pgrs(skip)]
+    cached_display: String,
+}
+
+#[derive(pgrs::FromRow, Debug, PartialEq)]
+struct Team {
+    #[pgrs(rename = "team_name")]
+    name: String,
+}
+
+#[derive(pgrs::FromRow, Debug, PartialEq)]
+struct Membership {
+    #[pgrs(flatten)]
+    user: User,
+    #[pgrs(flatten)]
+    team: Team,
+    #[pgrs(default)]
+    roles: Vec<String>,
+    r#type: String,
+}
+
+fn row(columns: &[&str], values: Vec<SqlValue>) -> Row {
+    let columns: Vec<String> = columns.iter().map(|c| c.to_string()).collect();
+    let column_types = vec![UNKNOWN_TYPE_OID; columns.len()];
+    QueryResult::from_raw(RawQueryResult::new(columns, column_types, vec![values]))
+        .single_row()
+        .unwrap()
+}
+
+fn text(s: &str) -> SqlValue {
+    SqlValue::Text(s.to_string())
+}
+
+#[test]
+fn test_from_row_fields() {
+    let user = User::from_row(&row(
+        &["id", "full_name", "email"],
+        vec![SqlValue::Int64(1), text("Ada"), SqlValue::Null],
+    ))
+    .unwrap();
+
+    assert_eq!(
+        user,
+        User {
+            id: 1,
+            name: "Ada".to_string(),
+            email: None,
+            cached_display: String::new(),
+        }
+    );
+}
+
+#[test]
+fn test_from_row_flatten_and_default() {
+    let columns = ["id", "full_name", "email", "team_name", "type"];
+    let values = vec![
+        SqlValue::Int64(1),
+        text("Ada"),
+        text("ada@example.com"),
+        text("Core"),
+        text("owner"),
+    ];
+    let membership = Membership::from_row(&row(&columns, values.clone())).unwrap();
+    assert_eq!(membership.user.email.as_deref(), Some("ada@example.com"));
+    assert_eq!(membership.team.name, "Core");
+    assert!(membership.roles.is_empty());
+    assert_eq!(membership.r#type, "owner");
+
+    let mut columns = columns.to_vec();
+    let mut values = values;
+    columns.push("roles");
+    values.push(SqlValue::Array(vec![text("admin")]));
+    let membership = Membership::from_row(&row(&columns, values)).unwrap();
+    assert_eq!(membership.roles, ["admin"]);
+}
+
+#[test]
+fn test_from_row_errors() {
+    assert!(matches!(
+        User::from_row(&row(&["id", "email"], vec![SqlValue::Int64(1), SqlValue::Null])),
+        Err(PgRsError::ColumnNotFound(column)) if column == "full_name"
+    ));
+    assert!(matches!(
+        User::from_row(&row(
+            &["id", "full_name", "email"],
+            vec![text("one"), text("Ada"), SqlValue::Null],
+        )),
+        Err(PgRsError::ConversionFailed { column, .. }) if column == "id"
+    ));
+}
+
+#[tokio::test]
+async fn test_select_into_structs() {
+    let driver = Arc::new(
+        InMemoryTestDriver::new()
+            .with_response(
+                InMemoryTestResponseBuilder::new()
+                    .columns(&["id", "full_name", "email"])
+                    .row_opt(&[Some("1"), Some("Ada"), None])
+                    .row_opt(&[Some("2"), Some("Grace"), Some("grace@example.com")])
+                    .build(),
+            )
+            .with_response(
+                InMemoryTestResponseBuilder::new()
+                    .columns(&["id", "full_name", "email"])
+                    .row_opt(&[Some("2"), Some("Grace"), None])
+                    .build(),
+            ),
+    );
+    let client = PgRsClient::with_driver(driver);
+    let columns = User::columns();
+
+    let users: Vec<User> = client
+        .querier()
+        .select()
+        .columns(&[&columns.id, &columns.name, &columns.email])
+        .from(UserTable)
+        .execute()
+        .await
+        .unwrap()
+        .into_structs()
+        .unwrap();
+    assert_eq!(users.len(), 2);
+    assert_eq!(users[1].email.as_deref(), Some("grace@example.com"));
+
+    let user: User = client
+        .querier()
+        .select()
+        .columns(&[&columns.id, &columns.name, &columns.email])
+        .from(UserTable)
+        .execute()
+        .await
+        .unwrap()
+        .single_as()
+        .unwrap();
+    assert_eq!(user.name, "Grace");
+
+    let empty = QueryResult::from_raw(InMemoryTestResponseBuilder::new().columns(&["id"]).build());
+    assert!(matches!(
+        empty.single_as::<User>(),
+        Err(PgRsError::UnexpectedRowCount { actual: 0, .. })
+    ));
+}
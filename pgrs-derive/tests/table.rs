@@ -0,0 +1,109 @@
+use std::sync::Arc;
+
+use pgrs::drivers::InMemoryTestDriver;
+use pgrs::schema::{CreateTableBuilder, DdlStatement};
+use pgrs::traits::{Column, DatabaseDriver, Table};
+use pgrs::types::{PgType, SqlValue};
+use pgrs::{PgRsClient, WhereClause};
+
+#[derive(pgrs::Table)]
+#[pgrs(table = "users", schema = "app")]
+#[allow(dead_code)]
+struct User {
+    #[pgrs(primary_key)]
+    id: i64,
+    #[pgrs(rename = "full_name", unique)]
+    name: String,
+    email: Option<String>,
+    #[pgrs(sql_type = "timestamptz", sql_default = "now()")]
+    created_at: String,
+    #[pgrs(skip)]
+    cached_display: String,
+}
+
+#[derive(pgrs::Table)]
+#[allow(dead_code)]
+struct AuditEvent {
+    r#type: String,
+    #[pgrs(custom_type = "audit_level")]
+    level: String,
+}
+
+#[test]
+fn test_table_metadata() {
+    assert_eq!(User::table_name(), "users");
+    assert_eq!(User::schema(), Some("app"));
+    assert_eq!(User::qualified_name(), "app.users");
+
+    let names: Vec<&str> = User::all_columns()
+        .iter()
+        .map(|c| c.column_name())
+        .collect();
+    assert_eq!(names, ["id", "full_name", "email", "created_at"]);
+}
+
+#[test]
+fn test_column_metadata() {
+    let columns = User::columns();
+
+    assert_eq!(columns.id.table_name(), "users");
+    assert_eq!(columns.id.sql_type(), Some(PgType::BigInt));
+    assert!(columns.id.is_primary_key());
+    assert!(!columns.id.is_nullable());
+
+    assert_eq!(columns.name.column_name(), "full_name");
+    assert!(columns.name.is_unique());
+
+    assert_eq!(columns.email.sql_type(), Some(PgType::Text));
+    assert!(columns.email.is_nullable());
+
+    assert_eq!(columns.created_at.sql_type(), Some(PgType::TimestampTz));
+    assert_eq!(columns.created_at.default_expression(), Some("now()"));
+}
+
+#[test]
+fn test_default_table_name_and_raw_identifiers() {
+    assert_eq!(AuditEvent::table_name(), "audit_event");
+    assert_eq!(AuditEvent::schema(), None);
+    assert_eq!(AuditEvent::columns().r#type.column_name(), "type");
+    assert_eq!(
+        AuditEvent::columns().level.sql_type(),
+        Some(PgType::Custom("audit_level".to_string()))
+    );
+}
+
+#[test]
+fn test_create_table_from_derived_table() {
+    let sql = CreateTableBuilder::from_table(UserTable)
+        .unwrap()
+        .build_sql()
+        .unwrap();
+    assert_eq!(
+        sql,
+        "CREATE TABLE app.users (id bigint PRIMARY KEY, full_name text NOT NULL UNIQUE, \
+         email text, created_at timestamp with time zone NOT NULL DEFAULT now())"
+    );
+}
+
+#[tokio::test]
+async fn test_select_with_derived_table() {
+    let in_memory_test_driver = Arc::new(InMemoryTestDriver::new());
+    let driver: Arc<dyn DatabaseDriver> =
+        Arc::clone(&in_memory_test_driver) as Arc<dyn DatabaseDriver>;
+    let client = PgRsClient::with_driver(driver);
+
+    client
+        .querier()
+        .select()
+        .columns(&[&User::columns().id, &User::columns().name])
+        .from(UserTable)
+        .where_(WhereClause::eq(&User::columns().email, "a@example.com"))
+        .execute()
+        .await
+        .unwrap();
+
+    in_memory_test_driver.assert_last_query(
+        "SELECT app.users.id, app.users.full_name FROM app.users WHERE app.users.email = $1",
+        &[SqlValue::Text("a@example.com".to_string())],
+    );
+}
@@ -1,3 +1,5 @@
 mod select;
+mod update;
 
 pub use select::SelectBuilder;
+pub use update::UpdateBuilder;
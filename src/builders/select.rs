@@ -1,8 +1,9 @@
 use std::sync::Arc;
 
-use crate::clauses::WhereClause;
+use crate::clauses::{Operand, SortDirection, WhereClause};
 use crate::error::{PgRsError, Result};
-use crate::traits::{Column, ColumnRef, DatabaseDriver, Table};
+use crate::ident::quote_ident;
+use crate::traits::{Column, ColumnRef, DatabaseDriver, TableSource};
 use crate::types::{QueryResult, SqlValue};
 
 /// Builder for SELECT queries.
@@ -12,8 +13,10 @@ use crate::types::{QueryResult, SqlValue};
 pub struct SelectBuilder {
     driver: Arc<dyn DatabaseDriver>,
     columns: Vec<ColumnRef>,
+    expressions: Vec<(Operand, String)>,
     table: Option<String>,
     where_clause: Option<WhereClause>,
+    order_by: Vec<(Operand, SortDirection)>,
     limit: Option<u64>,
 }
 
@@ -22,8 +25,10 @@ impl SelectBuilder {
         Self {
             driver,
             columns: Vec::new(),
+            expressions: Vec::new(),
             table: None,
             where_clause: None,
+            order_by: Vec::new(),
             limit: None,
         }
     }
@@ -34,18 +39,87 @@ impl SelectBuilder {
         self
     }
 
-    /// Specify the table to select from.
-    pub fn from<T: Table>(mut self, _table: T) -> Self {
-        self.table = Some(T::qualified_name());
+    /// Select every column of a table, as listed by `Table::all_columns`.
+    pub fn columns_of<T: TableSource>(mut self, table: T) -> Self {
+        self.columns = table
+            .declared_columns()
+            .into_iter()
+            .map(ColumnRef::from_column)
+            .collect();
         self
     }
 
-    /// Add a WHERE clause to the query.
+    /// Add a computed expression to the select list, named by an alias.
+    /// Expressions follow the columns, and are read with `Row::get_named`.
+    pub fn expression<E: Into<Operand>>(mut self, expression: E, alias: &str) -> Self {
+        self.expressions
+            .push((expression.into(), alias.to_string()));
+        self
+    }
+
+    /// Specify the table to select from, either a `Table` or a `DynTable`.
+    pub fn from<T: TableSource>(mut self, table: T) -> Self {
+        self.table = Some(table.quoted_name());
+        self
+    }
+
+    /// Set the WHERE clause of the query, replacing any set earlier.
+    /// Use `and_where` to add to an existing clause instead.
     pub fn where_(mut self, clause: WhereClause) -> Self {
         self.where_clause = Some(clause);
         self
     }
 
+    /// Add a condition that must also hold, combining it with any existing
+    /// WHERE clause using AND.
+    pub fn and_where(mut self, clause: WhereClause) -> Self {
+        self.where_clause = Some(match self.where_clause.take() {
+            Some(existing) => existing.and(clause),
+            None => clause,
+        });
+        self
+    }
+
+    /// Add an alternative condition, combining it with any existing WHERE
+    /// clause using OR.
+    pub fn or_where(mut self, clause: WhereClause) -> Self {
+        self.where_clause = Some(match self.where_clause.take() {
+            Some(existing) => existing.or(clause),
+            None => clause,
+        });
+        self
+    }
+
+    /// Add a condition with `and_where` if there is one, for filters that
+    /// are only sometimes present.
+    pub fn where_opt(self, clause: Option<WhereClause>) -> Self {
+        match clause {
+            Some(clause) => self.and_where(clause),
+            None => self,
+        }
+    }
+
+    /// Apply a reusable scope: a function that refines the builder, such as
+    /// one adding the filters shared by several queries.
+    ///
+    /// # Example
+    /// ```ignore
+    /// fn active(query: SelectBuilder) -> SelectBuilder {
+    ///     query.and_where(WhereClause::is_null(&Users::columns().deleted_at))
+    /// }
+    ///
+    /// let result = querier.select().columns_of(Users).from(Users).scope(active).execute().await?;
+    /// ```
+    pub fn scope<F: FnOnce(Self) -> Self>(self, scope: F) -> Self {
+        scope(self)
+    }
+
+    /// Add a term to the ORDER BY clause, after any added earlier.
+    pub fn order_by<E: Into<Operand>>(mut self, expression: E, direction: SortDirection) -> Self {
+        self.order_by.push((expression.into(), direction));
+        self
+    }
+
     /// Add a LIMIT to the query.
     pub fn limit(mut self, n: u64) -> Self {
         self.limit = Some(n);
@@ -54,7 +128,7 @@ impl SelectBuilder {
 
     /// Build the SQL query string and parameters.
     fn build_sql(&self) -> Result<(String, Vec<SqlValue>)> {
-        if self.columns.is_empty() {
+        if self.columns.is_empty() && self.expressions.is_empty() {
             return Err(PgRsError::NoColumnsSpecified);
         }
 
@@ -65,12 +139,19 @@ impl SelectBuilder {
 
         // SELECT clause
         sql.push_str("SELECT ");
-        for (i, col) in self.columns.iter().enumerate() {
-            if i > 0 {
-                sql.push_str(", ");
-            }
-            sql.push_str(&col.qualified_name());
+        let mut select_list: Vec<String> = self
+            .columns
+            .iter()
+            .map(|col| col.qualified_name())
+            .collect();
+        for (expression, alias) in &self.expressions {
+            select_list.push(format!(
+                "{} AS {}",
+                expression.build_sql(0, &mut params),
+                quote_ident(alias)
+            ));
         }
+        sql.push_str(&select_list.join(", "));
 
         // FROM clause
         sql.push_str(" FROM ");
@@ -79,10 +160,23 @@ impl SelectBuilder {
         // WHERE clause
         if let Some(ref where_clause) = self.where_clause {
             sql.push_str(" WHERE ");
-            let where_sql = where_clause.build_sql(0, &mut params);
+            let where_sql = where_clause.build_sql(0, &mut params)?;
             sql.push_str(&where_sql);
         }
 
+        // ORDER BY clause
+        if !self.order_by.is_empty() {
+            let terms: Vec<String> = self
+                .order_by
+                .iter()
+                .map(|(expression, direction)| {
+                    format!("{} {}", expression.build_sql(0, &mut params), direction)
+                })
+                .collect();
+            sql.push_str(" ORDER BY ");
+            sql.push_str(&terms.join(", "));
+        }
+
         // LIMIT clause
         if let Some(limit) = self.limit {
             sql.push_str(" LIMIT ");
@@ -103,6 +197,9 @@ impl SelectBuilder {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::clauses::TextSearch;
+    use crate::dynamic::DynTable;
+    use crate::traits::Table;
     use crate::types::RawQueryResult;
     use async_trait::async_trait;
 
@@ -138,6 +235,9 @@ mod tests {
                 name: UsersName,
             }
         }
+        fn all_columns() -> Vec<&'static dyn Column> {
+            vec![&UsersId, &UsersName]
+        }
     }
 
     impl Column for UsersId {
@@ -173,6 +273,18 @@ mod tests {
         assert!(params.is_empty());
     }
 
+    #[test]
+    fn test_build_select_columns_of_table() {
+        let driver = Arc::new(MockDriver {
+            result: RawQueryResult::empty(),
+        });
+
+        let builder = SelectBuilder::new(driver).columns_of(Users).from(Users);
+
+        let (sql, _) = builder.build_sql().unwrap();
+        assert_eq!(sql, "SELECT users.id, users.name FROM users");
+    }
+
     #[test]
     fn test_build_select_with_where() {
         let driver = Arc::new(MockDriver {
@@ -226,6 +338,71 @@ mod tests {
         assert_eq!(params.len(), 1);
     }
 
+    #[test]
+    fn test_build_select_from_dyn_table() {
+        let driver = Arc::new(MockDriver {
+            result: RawQueryResult::empty(),
+        });
+
+        let table = DynTable::new("Audit Log").unwrap().schema("ops").unwrap();
+        let user = table.column("user").unwrap();
+        let builder = SelectBuilder::new(driver)
+            .columns(&[&user])
+            .from(table.clone())
+            .where_(WhereClause::eq(&user, "admin"));
+
+        let (sql, params) = builder.build_sql().unwrap();
+        assert_eq!(
+            sql,
+            "SELECT ops.\"Audit Log\".\"user\" FROM ops.\"Audit Log\" WHERE ops.\"Audit Log\".\"user\" = $1"
+        );
+        assert_eq!(params.len(), 1);
+    }
+
+    #[test]
+    fn test_build_select_with_accumulated_where() {
+        let driver = Arc::new(MockDriver {
+            result: RawQueryResult::empty(),
+        });
+
+        fn named_john(query: SelectBuilder) -> SelectBuilder {
+            query.and_where(WhereClause::eq(&Users::columns().name, "John"))
+        }
+
+        let builder = SelectBuilder::new(driver)
+            .columns(&[&Users::columns().id])
+            .from(Users)
+            .where_opt(None)
+            .scope(named_john)
+            .and_where(WhereClause::gt(&Users::columns().id, 10))
+            .where_opt(Some(WhereClause::lt(&Users::columns().id, 20)))
+            .or_where(WhereClause::eq(&Users::columns().id, 1));
+
+        let (sql, params) = builder.build_sql().unwrap();
+        assert_eq!(
+            sql,
+            "SELECT users.id FROM users WHERE \
+             (users.name = $1 AND users.id > $2 AND users.id < $3) OR users.id = $4"
+        );
+        assert_eq!(params.len(), 4);
+    }
+
+    #[test]
+    fn test_build_select_where_replaces() {
+        let driver = Arc::new(MockDriver {
+            result: RawQueryResult::empty(),
+        });
+
+        let builder = SelectBuilder::new(driver)
+            .columns(&[&Users::columns().id])
+            .from(Users)
+            .and_where(WhereClause::eq(&Users::columns().name, "John"))
+            .where_(WhereClause::eq(&Users::columns().id, 1));
+
+        let (sql, _) = builder.build_sql().unwrap();
+        assert_eq!(sql, "SELECT users.id FROM users WHERE users.id = $1");
+    }
+
     #[test]
     fn test_build_fails_without_columns() {
         let driver = Arc::new(MockDriver {
@@ -249,4 +426,39 @@ mod tests {
         let err = builder.build_sql().unwrap_err();
         assert!(matches!(err, PgRsError::NoTableSpecified));
     }
+
+    #[test]
+    fn test_build_select_with_expressions_and_order_by() {
+        let driver = Arc::new(MockDriver {
+            result: RawQueryResult::empty(),
+        });
+
+        let search = TextSearch::new("english");
+        let vector = search.to_tsvector(&Users::columns().name);
+        let query = search.plainto_tsquery("john");
+        let builder = SelectBuilder::new(driver)
+            .columns(&[&Users::columns().id])
+            .expression(
+                search.headline(&Users::columns().name, query.clone()),
+                "snippet",
+            )
+            .from(Users)
+            .where_(WhereClause::text_match(vector.clone(), query.clone()))
+            .order_by(vector.ts_rank(query), SortDirection::Desc)
+            .order_by(&Users::columns().id, SortDirection::Asc)
+            .limit(5);
+
+        let (sql, params) = builder.build_sql().unwrap();
+        assert_eq!(
+            sql,
+            "SELECT users.id, ts_headline($1::text::regconfig, users.name, \
+             plainto_tsquery($2::text::regconfig, $3)) AS snippet FROM users \
+             WHERE to_tsvector($4::text::regconfig, users.name) @@ \
+             plainto_tsquery($5::text::regconfig, $6) \
+             ORDER BY ts_rank(to_tsvector($7::text::regconfig, users.name), \
+             plainto_tsquery($8::text::regconfig, $9)) DESC, users.id ASC LIMIT 5"
+        );
+        assert_eq!(params.len(), 9);
+        assert_eq!(params[2], SqlValue::Text("john".to_string()));
+    }
 }
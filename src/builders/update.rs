@@ -0,0 +1,218 @@
+use std::sync::Arc;
+
+use crate::clauses::{Operand, WhereClause};
+use crate::error::{PgRsError, Result};
+use crate::ident::quote_ident;
+use crate::traits::{Column, ColumnRef, DatabaseDriver, TableSource};
+use crate::types::{QueryResult, SqlValue};
+
+/// Builder for UPDATE queries.
+///
+/// Use the fluent API to construct a query, then call `execute()` to run it.
+/// Required fields (table, assignments) are validated at execution time.
+///
+/// # Example
+/// ```ignore
+/// querier
+///     .update()
+///     .table(Users)
+///     .set(&Users::columns().name, "Bob")
+///     .set_expr(
+///         &Users::columns().settings,
+///         Operand::column(&Users::columns().settings)
+///             .jsonb_set(["theme"], SqlValue::Json("\"dark\"".to_string())),
+///     )
+///     .where_(WhereClause::eq(&Users::columns().id, 1))
+///     .execute()
+///     .await?;
+/// ```
+pub struct UpdateBuilder {
+    driver: Arc<dyn DatabaseDriver>,
+    table: Option<String>,
+    assignments: Vec<(String, Operand)>,
+    where_clause: Option<WhereClause>,
+    returning: Vec<ColumnRef>,
+}
+
+impl UpdateBuilder {
+    pub(crate) fn new(driver: Arc<dyn DatabaseDriver>) -> Self {
+        Self {
+            driver,
+            table: None,
+            assignments: Vec::new(),
+            where_clause: None,
+            returning: Vec::new(),
+        }
+    }
+
+    /// Specify the table to update, either a `Table` or a `DynTable`.
+    pub fn table<T: TableSource>(mut self, table: T) -> Self {
+        self.table = Some(table.quoted_name());
+        self
+    }
+
+    /// Set a column to a value: column = value
+    pub fn set<C: Column, V: Into<SqlValue>>(self, column: &C, value: V) -> Self {
+        self.set_expr(column, Operand::Value(value.into()))
+    }
+
+    /// Set a column to an expression, such as one computed from its current
+    /// value: column = expression
+    pub fn set_expr<C: Column, O: Into<Operand>>(mut self, column: &C, expression: O) -> Self {
+        self.assignments.push((
+            quote_ident(column.column_name()).into_owned(),
+            expression.into(),
+        ));
+        self
+    }
+
+    /// Set the WHERE clause of the query, replacing any set earlier.
+    /// Use `and_where` to add to an existing clause instead.
+    pub fn where_(mut self, clause: WhereClause) -> Self {
+        self.where_clause = Some(clause);
+        self
+    }
+
+    /// Add a condition that must also hold, combining it with any existing
+    /// WHERE clause using AND.
+    pub fn and_where(mut self, clause: WhereClause) -> Self {
+        self.where_clause = Some(match self.where_clause.take() {
+            Some(existing) => existing.and(clause),
+            None => clause,
+        });
+        self
+    }
+
+    /// Return columns of the updated rows.
+    pub fn returning(mut self, cols: &[&dyn Column]) -> Self {
+        self.returning = cols.iter().map(|c| ColumnRef::from_column(*c)).collect();
+        self
+    }
+
+    /// Build the SQL query string and parameters.
+    fn build_sql(&self) -> Result<(String, Vec<SqlValue>)> {
+        let table = self.table.as_ref().ok_or(PgRsError::NoTableSpecified)?;
+        if self.assignments.is_empty() {
+            return Err(PgRsError::NoAssignmentsSpecified);
+        }
+
+        let mut sql = String::with_capacity(256);
+        let mut params = Vec::new();
+
+        // UPDATE ... SET clause
+        sql.push_str("UPDATE ");
+        sql.push_str(table);
+        sql.push_str(" SET ");
+        for (i, (column, expression)) in self.assignments.iter().enumerate() {
+            if i > 0 {
+                sql.push_str(", ");
+            }
+            sql.push_str(column);
+            sql.push_str(" = ");
+            sql.push_str(&expression.build_sql(0, &mut params));
+        }
+
+        // WHERE clause
+        if let Some(ref where_clause) = self.where_clause {
+            sql.push_str(" WHERE ");
+            let where_sql = where_clause.build_sql(0, &mut params)?;
+            sql.push_str(&where_sql);
+        }
+
+        // RETURNING clause
+        if !self.returning.is_empty() {
+            sql.push_str(" RETURNING ");
+            for (i, col) in self.returning.iter().enumerate() {
+                if i > 0 {
+                    sql.push_str(", ");
+                }
+                sql.push_str(&col.qualified_name());
+            }
+        }
+
+        Ok((sql, params))
+    }
+
+    /// Execute the query and return the result, which holds the
+    /// `returning` columns of the updated rows.
+    pub async fn execute(self) -> Result<QueryResult> {
+        let (sql, params) = self.build_sql()?;
+        let raw_result = self.driver.execute(&sql, &params).await?;
+        Ok(QueryResult::from_raw(raw_result))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::drivers::InMemoryTestDriver;
+    use crate::dynamic::DynTable;
+
+    fn builder() -> UpdateBuilder {
+        UpdateBuilder::new(Arc::new(InMemoryTestDriver::new()))
+    }
+
+    #[test]
+    fn test_build_update() {
+        let users = DynTable::new("users").unwrap().schema("app").unwrap();
+        let id = users.column("id").unwrap();
+        let name = users.column("name").unwrap();
+        let settings = users.column("settings").unwrap();
+
+        let (sql, params) = builder()
+            .table(users.clone())
+            .set(&name, "Bob")
+            .set_expr(
+                &settings,
+                Operand::column(&settings)
+                    .jsonb_set(["theme"], SqlValue::Json("\"dark\"".to_string()))
+                    .concat(SqlValue::Json("{\"beta\": true}".to_string())),
+            )
+            .where_(WhereClause::eq(&id, 1))
+            .returning(&[&id])
+            .build_sql()
+            .unwrap();
+
+        assert_eq!(
+            sql,
+            "UPDATE app.users SET name = $1, \
+             settings = jsonb_set(app.users.settings, $2, $3) || $4 \
+             WHERE app.users.id = $5 RETURNING app.users.id"
+        );
+        assert_eq!(
+            params,
+            vec![
+                SqlValue::Text("Bob".to_string()),
+                SqlValue::Array(vec![SqlValue::Text("theme".to_string())]),
+                SqlValue::Json("\"dark\"".to_string()),
+                SqlValue::Json("{\"beta\": true}".to_string()),
+                SqlValue::Int32(1),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_build_update_quotes_assigned_columns() {
+        let orders = DynTable::new("order").unwrap();
+        let user = orders.column("user").unwrap();
+
+        let (sql, _) = builder()
+            .table(orders.clone())
+            .set(&user, "bob")
+            .build_sql()
+            .unwrap();
+        assert_eq!(sql, "UPDATE \"order\" SET \"user\" = $1");
+    }
+
+    #[test]
+    fn test_build_update_fails_without_table_or_assignments() {
+        let users = DynTable::new("users").unwrap();
+        let name = users.column("name").unwrap();
+
+        let err = builder().set(&name, "Bob").build_sql().unwrap_err();
+        assert!(matches!(err, PgRsError::NoTableSpecified));
+
+        let err = builder().table(users).build_sql().unwrap_err();
+        assert!(matches!(err, PgRsError::NoAssignmentsSpecified));
+    }
+}
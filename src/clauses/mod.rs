@@ -1,3 +1,13 @@
+mod operand;
+mod operator;
+mod order_by;
+mod pattern;
+mod text_search;
 mod where_clause;
 
-pub use where_clause::WhereClause;
+pub use operand::{JsonKey, Operand};
+pub use operator::{BinaryOperator, Operator};
+pub use order_by::SortDirection;
+pub use pattern::{escape_like, LIKE_ESCAPE};
+pub use text_search::TextSearch;
+pub use where_clause::{InListStyle, WhereClause};
@@ -0,0 +1,283 @@
+use crate::traits::{Column, ColumnRef};
+use crate::types::{PgType, SqlValue};
+
+use super::operator::BinaryOperator;
+
+/// A value in a condition or assignment: a bound parameter, a column, or an
+/// expression built from them.
+///
+/// # Example
+/// ```
+/// use pgrs::clauses::{Operand, WhereClause};
+/// use pgrs::dynamic::DynTable;
+///
+/// let users = DynTable::new("users").unwrap();
+/// let data = users.column("data").unwrap();
+///
+/// // (users.data ->> $1) = $2
+/// let clause = WhereClause::eq(Operand::column(&data).get_text("name"), "bob");
+/// let mut params = Vec::new();
+/// assert_eq!(clause.build_sql(0, &mut params).unwrap(), "(users.data ->> $1) = $2");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum Operand {
+    /// A value sent as a query parameter.
+    Value(SqlValue),
+    /// A column, compared without a parameter.
+    Column(ColumnRef),
+    /// left <operator> right
+    Binary(Box<Operand>, BinaryOperator, Box<Operand>),
+    /// name(arguments)
+    Function(&'static str, Vec<Operand>),
+    /// operand::type
+    Cast(Box<Operand>, PgType),
+}
+
+/// A key into a JSON value: an object field name or an array index.
+#[derive(Debug, Clone, PartialEq)]
+pub enum JsonKey {
+    Field(String),
+    Index(i32),
+}
+
+impl From<&str> for JsonKey {
+    fn from(field: &str) -> Self {
+        JsonKey::Field(field.to_string())
+    }
+}
+
+impl From<String> for JsonKey {
+    fn from(field: String) -> Self {
+        JsonKey::Field(field)
+    }
+}
+
+impl From<i32> for JsonKey {
+    fn from(index: i32) -> Self {
+        JsonKey::Index(index)
+    }
+}
+
+impl JsonKey {
+    fn into_operand(self) -> Operand {
+        match self {
+            JsonKey::Field(field) => Operand::Value(SqlValue::Text(field)),
+            // Cast so the placeholder is not inferred as a field name
+            JsonKey::Index(index) => Operand::value(index).cast(PgType::Integer),
+        }
+    }
+}
+
+impl Operand {
+    /// A value sent as a query parameter.
+    pub fn value<V: Into<SqlValue>>(value: V) -> Self {
+        Operand::Value(value.into())
+    }
+
+    /// A column of a table.
+    pub fn column<C: Column + ?Sized>(column: &C) -> Self {
+        Operand::Column(ColumnRef::from_column(column))
+    }
+
+    /// A call to a built-in SQL function.
+    pub fn function(name: &'static str, arguments: Vec<Operand>) -> Self {
+        Operand::Function(name, arguments)
+    }
+
+    /// Casts the operand: operand::type
+    pub fn cast(self, sql_type: PgType) -> Self {
+        Operand::Cast(Box::new(self), sql_type)
+    }
+
+    /// The JSON object field or array element, as JSON: operand -> key
+    pub fn get<K: Into<JsonKey>>(self, key: K) -> Self {
+        self.binary(BinaryOperator::JsonGet, key.into().into_operand())
+    }
+
+    /// The JSON object field or array element, as text: operand ->> key
+    pub fn get_text<K: Into<JsonKey>>(self, key: K) -> Self {
+        self.binary(BinaryOperator::JsonGetText, key.into().into_operand())
+    }
+
+    /// The JSON value at a path, as JSON: operand #> path
+    pub fn get_path<I, S>(self, path: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.binary(BinaryOperator::JsonGetPath, path_operand(path))
+    }
+
+    /// The JSON value at a path, as text: operand #>> path
+    pub fn get_path_text<I, S>(self, path: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.binary(BinaryOperator::JsonGetPathText, path_operand(path))
+    }
+
+    /// Concatenates strings, arrays or JSON values: operand || other
+    ///
+    /// For `jsonb`, concatenating two objects merges their keys.
+    pub fn concat<O: Into<Operand>>(self, other: O) -> Self {
+        self.binary(BinaryOperator::Concat, other.into())
+    }
+
+    /// Replaces the JSON value at a path, creating it if missing:
+    /// jsonb_set(operand, path, value)
+    pub fn jsonb_set<I, S, O>(self, path: I, value: O) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+        O: Into<Operand>,
+    {
+        Operand::function("jsonb_set", vec![self, path_operand(path), value.into()])
+    }
+
+    /// Ranks how well a search vector matches a query, by how often the
+    /// query's words occur: ts_rank(operand, query)
+    pub fn ts_rank(self, query: Operand) -> Self {
+        Operand::function("ts_rank", vec![self, query])
+    }
+
+    /// Ranks how well a search vector matches a query, by how close
+    /// together the query's words occur: ts_rank_cd(operand, query)
+    pub fn ts_rank_cd(self, query: Operand) -> Self {
+        Operand::function("ts_rank_cd", vec![self, query])
+    }
+
+    fn binary(self, operator: BinaryOperator, right: Operand) -> Self {
+        Operand::Binary(Box::new(self), operator, Box::new(right))
+    }
+
+    /// Renders the operand, pushing its parameters.
+    pub(crate) fn build_sql(&self, param_offset: usize, params: &mut Vec<SqlValue>) -> String {
+        match self {
+            Operand::Value(value) => {
+                params.push(value.clone());
+                let placeholder = format!("${}", param_offset + params.len());
+                match value.text_cast() {
+                    Some(sql_type @ PgType::Array(_)) => {
+                        format!("{}::text[]::{}", placeholder, sql_type)
+                    }
+                    Some(sql_type) => format!("{}::text::{}", placeholder, sql_type),
+                    None => placeholder,
+                }
+            }
+            Operand::Column(column) => column.qualified_name(),
+            Operand::Binary(left, operator, right) => {
+                // Value operators share a precedence and associate to the left
+                let left_sql = left.build_sql(param_offset, params);
+                let right_sql = right.build_sql(param_offset, params);
+                match **right {
+                    Operand::Binary(..) => format!("{} {} ({})", left_sql, operator, right_sql),
+                    _ => format!("{} {} {}", left_sql, operator, right_sql),
+                }
+            }
+            Operand::Function(name, arguments) => {
+                let arguments: Vec<String> = arguments
+                    .iter()
+                    .map(|argument| argument.build_sql(param_offset, params))
+                    .collect();
+                format!("{}({})", name, arguments.join(", "))
+            }
+            Operand::Cast(operand, sql_type) => {
+                let sql = operand.build_sql(param_offset, params);
+                match **operand {
+                    Operand::Binary(..) => format!("({})::{}", sql, sql_type),
+                    _ => format!("{}::{}", sql, sql_type),
+                }
+            }
+        }
+    }
+}
+
+impl<C: Column + ?Sized> From<&C> for Operand {
+    fn from(column: &C) -> Self {
+        Operand::column(column)
+    }
+}
+
+impl From<ColumnRef> for Operand {
+    fn from(column: ColumnRef) -> Self {
+        Operand::Column(column)
+    }
+}
+
+impl From<SqlValue> for Operand {
+    fn from(value: SqlValue) -> Self {
+        Operand::Value(value)
+    }
+}
+
+fn path_operand<I, S>(path: I) -> Operand
+where
+    I: IntoIterator<Item = S>,
+    S: Into<String>,
+{
+    Operand::Value(SqlValue::Array(
+        path.into_iter()
+            .map(|key| SqlValue::Text(key.into()))
+            .collect(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Data;
+
+    impl Column for Data {
+        fn column_name(&self) -> &'static str {
+            "data"
+        }
+        fn table_name(&self) -> &'static str {
+            "docs"
+        }
+    }
+
+    fn render(operand: &Operand) -> (String, Vec<SqlValue>) {
+        let mut params = Vec::new();
+        let sql = operand.build_sql(0, &mut params);
+        (sql, params)
+    }
+
+    #[test]
+    fn test_json_access() {
+        let (sql, params) = render(&Operand::column(&Data).get("tags").get_text(0));
+        assert_eq!(sql, "docs.data -> $1 ->> $2::integer");
+        assert_eq!(
+            params,
+            vec![SqlValue::Text("tags".to_string()), SqlValue::Int32(0)]
+        );
+
+        let (sql, params) = render(&Operand::column(&Data).get_path_text(["a", "b"]));
+        assert_eq!(sql, "docs.data #>> $1");
+        assert_eq!(
+            params,
+            vec![SqlValue::Array(vec![
+                SqlValue::Text("a".to_string()),
+                SqlValue::Text("b".to_string())
+            ])]
+        );
+
+        let (sql, _) = render(&Operand::column(&Data).get_path(["a"]).cast(PgType::Jsonb));
+        assert_eq!(sql, "(docs.data #> $1)::jsonb");
+    }
+
+    #[test]
+    fn test_json_updates() {
+        let (sql, params) = render(
+            &Operand::column(&Data)
+                .jsonb_set(["profile", "name"], SqlValue::Json("\"bob\"".to_string()))
+                .concat(SqlValue::Json("{\"seen\": true}".to_string())),
+        );
+        assert_eq!(sql, "jsonb_set(docs.data, $1, $2) || $3");
+        assert_eq!(params.len(), 3);
+
+        let (sql, _) = render(&Operand::value("a").concat(Operand::value("b").concat(&Data)));
+        assert_eq!(sql, "$1 || ($2 || docs.data)");
+    }
+}
@@ -0,0 +1,150 @@
+use std::fmt;
+
+/// A binary comparison operator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operator {
+    /// `=`
+    Eq,
+    /// `<>`
+    Ne,
+    /// `<`
+    Lt,
+    /// `<=`
+    Le,
+    /// `>`
+    Gt,
+    /// `>=`
+    Ge,
+    /// `IS DISTINCT FROM`: like `<>`, but treats NULLs as comparable values.
+    IsDistinctFrom,
+    /// `IS NOT DISTINCT FROM`: like `=`, but treats NULLs as comparable values.
+    IsNotDistinctFrom,
+    /// `LIKE`
+    Like,
+    /// `NOT LIKE`
+    NotLike,
+    /// `ILIKE`: case-insensitive `LIKE`.
+    ILike,
+    /// `NOT ILIKE`
+    NotILike,
+    /// `SIMILAR TO`: SQL regular expression match.
+    SimilarTo,
+    /// `NOT SIMILAR TO`
+    NotSimilarTo,
+    /// `~`: POSIX regular expression match.
+    RegexMatch,
+    /// `~*`: case-insensitive POSIX regular expression match.
+    RegexIMatch,
+    /// `!~`: POSIX regular expression non-match.
+    NotRegexMatch,
+    /// `!~*`: case-insensitive POSIX regular expression non-match.
+    NotRegexIMatch,
+    /// `@>`: the left value contains the right one.
+    Contains,
+    /// `<@`: the left value is contained in the right one.
+    ContainedBy,
+    /// `&&`: the values have elements in common.
+    Overlaps,
+    /// `-|-`: the ranges are adjacent.
+    Adjacent,
+    /// `<<`: the left range is strictly before the right one.
+    StrictlyLeft,
+    /// `>>`: the left range is strictly after the right one.
+    StrictlyRight,
+    /// `?`: the JSON object has the key, or the JSON array has the string.
+    HasKey,
+    /// `?|`: the JSON value has any of the keys.
+    HasAnyKey,
+    /// `?&`: the JSON value has all of the keys.
+    HasAllKeys,
+    /// `@?`: the JSON path returns any item.
+    PathExists,
+    /// `@@`: the JSON path predicate is true.
+    PathMatch,
+    /// `@@`: the text search vector matches the query.
+    TextMatch,
+}
+
+impl Operator {
+    /// The SQL spelling of the operator.
+    pub fn as_sql(&self) -> &'static str {
+        match self {
+            Operator::Eq => "=",
+            Operator::Ne => "<>",
+            Operator::Lt => "<",
+            Operator::Le => "<=",
+            Operator::Gt => ">",
+            Operator::Ge => ">=",
+            Operator::IsDistinctFrom => "IS DISTINCT FROM",
+            Operator::IsNotDistinctFrom => "IS NOT DISTINCT FROM",
+            Operator::Like => "LIKE",
+            Operator::NotLike => "NOT LIKE",
+            Operator::ILike => "ILIKE",
+            Operator::NotILike => "NOT ILIKE",
+            Operator::SimilarTo => "SIMILAR TO",
+            Operator::NotSimilarTo => "NOT SIMILAR TO",
+            Operator::RegexMatch => "~",
+            Operator::RegexIMatch => "~*",
+            Operator::NotRegexMatch => "!~",
+            Operator::NotRegexIMatch => "!~*",
+            Operator::Contains => "@>",
+            Operator::ContainedBy => "<@",
+            Operator::Overlaps => "&&",
+            Operator::Adjacent => "-|-",
+            Operator::StrictlyLeft => "<<",
+            Operator::StrictlyRight => ">>",
+            Operator::HasKey => "?",
+            Operator::HasAnyKey => "?|",
+            Operator::HasAllKeys => "?&",
+            Operator::PathExists => "@?",
+            Operator::PathMatch => "@@",
+            Operator::TextMatch => "@@",
+        }
+    }
+
+    /// Whether the operator gives a true or false result for NULL operands
+    /// rather than NULL.
+    pub fn is_null_safe(&self) -> bool {
+        matches!(self, Operator::IsDistinctFrom | Operator::IsNotDistinctFrom)
+    }
+}
+
+impl fmt::Display for Operator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_sql())
+    }
+}
+
+/// An operator that computes a value from two operands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BinaryOperator {
+    /// `->`: the JSON object field or array element, as JSON.
+    JsonGet,
+    /// `->>`: the JSON object field or array element, as text.
+    JsonGetText,
+    /// `#>`: the JSON value at a path, as JSON.
+    JsonGetPath,
+    /// `#>>`: the JSON value at a path, as text.
+    JsonGetPathText,
+    /// `||`: concatenation of strings, arrays or JSON values.
+    Concat,
+}
+
+impl BinaryOperator {
+    /// The SQL spelling of the operator.
+    pub fn as_sql(&self) -> &'static str {
+        match self {
+            BinaryOperator::JsonGet => "->",
+            BinaryOperator::JsonGetText => "->>",
+            BinaryOperator::JsonGetPath => "#>",
+            BinaryOperator::JsonGetPathText => "#>>",
+            BinaryOperator::Concat => "||",
+        }
+    }
+}
+
+impl fmt::Display for BinaryOperator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_sql())
+    }
+}
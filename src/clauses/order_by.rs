@@ -0,0 +1,27 @@
+use std::fmt;
+
+/// The direction of an ORDER BY term.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SortDirection {
+    /// `ASC`: smallest first.
+    #[default]
+    Asc,
+    /// `DESC`: largest first.
+    Desc,
+}
+
+impl SortDirection {
+    /// Returns the SQL keyword for this direction.
+    pub fn as_sql(&self) -> &'static str {
+        match self {
+            SortDirection::Asc => "ASC",
+            SortDirection::Desc => "DESC",
+        }
+    }
+}
+
+impl fmt::Display for SortDirection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_sql())
+    }
+}
@@ -0,0 +1,37 @@
+/// The escape character used by the pattern helpers on `WhereClause`.
+pub const LIKE_ESCAPE: char = '\\';
+
+/// Escapes the LIKE wildcards `%` and `_`, and the escape character itself,
+/// so that `text` matches only literally inside a LIKE or ILIKE pattern
+/// declared with `ESCAPE '\'`.
+///
+/// # Example
+/// ```
+/// use pgrs::clauses::escape_like;
+///
+/// assert_eq!(escape_like("50%_off\\"), "50\\%\\_off\\\\");
+/// ```
+pub fn escape_like(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(c, '%' | '_' | LIKE_ESCAPE) {
+            escaped.push(LIKE_ESCAPE);
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escape_like() {
+        assert_eq!(escape_like("plain text"), "plain text");
+        assert_eq!(escape_like("100%"), "100\\%");
+        assert_eq!(escape_like("snake_case"), "snake\\_case");
+        assert_eq!(escape_like("C:\\dir"), "C:\\\\dir");
+        assert_eq!(escape_like(""), "");
+    }
+}
@@ -0,0 +1,122 @@
+use crate::types::{PgType, SqlValue};
+
+use super::operand::Operand;
+
+/// Builds full-text search expressions using a text search configuration,
+/// such as `english` or `simple`, which decides how text is split into
+/// lexemes.
+///
+/// `TextSearch::default()` uses the server's `default_text_search_config`.
+///
+/// # Example
+/// ```
+/// use pgrs::clauses::{TextSearch, WhereClause};
+/// use pgrs::dynamic::DynTable;
+///
+/// let posts = DynTable::new("posts").unwrap();
+/// let body = posts.column("body").unwrap();
+/// let search = TextSearch::new("english");
+///
+/// let clause = WhereClause::text_match(
+///     search.to_tsvector(&body),
+///     search.websearch_to_tsquery("rust -java"),
+/// );
+/// let mut params = Vec::new();
+/// assert_eq!(
+///     clause.build_sql(0, &mut params).unwrap(),
+///     "to_tsvector($1::text::regconfig, posts.body) @@ \
+///      websearch_to_tsquery($2::text::regconfig, $3)"
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TextSearch {
+    config: Option<String>,
+}
+
+impl TextSearch {
+    /// Uses the named text search configuration.
+    pub fn new(config: impl Into<String>) -> Self {
+        Self {
+            config: Some(config.into()),
+        }
+    }
+
+    /// Converts a document to a search vector: to_tsvector(config, document)
+    pub fn to_tsvector<D: Into<Operand>>(&self, document: D) -> Operand {
+        self.function("to_tsvector", vec![document.into()])
+    }
+
+    /// Parses a query written like a web search, with quoted phrases, `or`
+    /// and `-` for exclusion: websearch_to_tsquery(config, query)
+    ///
+    /// Never fails on malformed input, so it suits text typed by users.
+    pub fn websearch_to_tsquery<Q: Into<SqlValue>>(&self, query: Q) -> Operand {
+        self.function("websearch_to_tsquery", vec![Operand::value(query)])
+    }
+
+    /// Parses plain text into a query matching all of its words:
+    /// plainto_tsquery(config, query)
+    pub fn plainto_tsquery<Q: Into<SqlValue>>(&self, query: Q) -> Operand {
+        self.function("plainto_tsquery", vec![Operand::value(query)])
+    }
+
+    /// A snippet of the document with the query's matches highlighted:
+    /// ts_headline(config, document, query)
+    pub fn headline<D: Into<Operand>>(&self, document: D, query: Operand) -> Operand {
+        self.function("ts_headline", vec![document.into(), query])
+    }
+
+    fn function(&self, name: &'static str, arguments: Vec<Operand>) -> Operand {
+        let mut all = Vec::with_capacity(arguments.len() + 1);
+        if let Some(config) = &self.config {
+            // Cast through text so the placeholder is bound as a string
+            all.push(
+                Operand::value(config.as_str())
+                    .cast(PgType::Text)
+                    .cast(PgType::Custom("regconfig".to_string())),
+            );
+        }
+        all.extend(arguments);
+        Operand::function(name, all)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render(operand: &Operand) -> (String, Vec<SqlValue>) {
+        let mut params = Vec::new();
+        let sql = operand.build_sql(0, &mut params);
+        (sql, params)
+    }
+
+    #[test]
+    fn test_default_config() {
+        let search = TextSearch::default();
+        let (sql, params) = render(&search.plainto_tsquery("fast cars"));
+        assert_eq!(sql, "plainto_tsquery($1)");
+        assert_eq!(params, vec![SqlValue::Text("fast cars".to_string())]);
+    }
+
+    #[test]
+    fn test_rank_and_headline() {
+        let search = TextSearch::new("simple");
+        let body = Operand::Value(SqlValue::Text("the body".to_string()));
+        let query = search.websearch_to_tsquery("body");
+
+        let (sql, params) = render(&search.to_tsvector(body.clone()).ts_rank(query.clone()));
+        assert_eq!(
+            sql,
+            "ts_rank(to_tsvector($1::text::regconfig, $2), \
+             websearch_to_tsquery($3::text::regconfig, $4))"
+        );
+        assert_eq!(params.len(), 4);
+
+        let (sql, _) = render(&search.headline(body, query));
+        assert_eq!(
+            sql,
+            "ts_headline($1::text::regconfig, $2, websearch_to_tsquery($3::text::regconfig, $4))"
+        );
+    }
+}
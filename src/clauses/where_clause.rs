@@ -1,64 +1,757 @@
-use crate::traits::{Column, ColumnRef};
-use crate::types::SqlValue;
+use crate::error::{PgRsError, Result};
+use crate::traits::Column;
+use crate::types::{PgType, SqlValue};
+
+use super::operand::Operand;
+use super::operator::Operator;
+use super::pattern::{escape_like, LIKE_ESCAPE};
+
+/// How `IN` and `NOT IN` lists are sent to the database.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum InListStyle {
+    /// One parameter per value: `column IN ($1, $2, $3)`.
+    #[default]
+    Expanded,
+    /// A single array parameter: `column = ANY($1)`. The SQL text stays the
+    /// same for any number of values, so the statement can be reused.
+    Any,
+}
 
 /// Represents a WHERE clause condition.
-/// Supports basic comparison operations and logical combinations.
+/// Supports comparison operations and logical combinations.
 #[derive(Debug, Clone)]
 pub enum WhereClause {
-    /// column = value
-    Eq(ColumnRef, SqlValue),
-    /// clause AND clause
-    And(Box<WhereClause>, Box<WhereClause>),
-    /// clause OR clause
-    Or(Box<WhereClause>, Box<WhereClause>),
+    /// left <operator> right
+    Compare(Operand, Operator, Operand),
+    /// operand IS NULL
+    IsNull(Operand),
+    /// operand IS NOT NULL
+    IsNotNull(Operand),
+    /// operand <operator> pattern ESCAPE 'escape'
+    Pattern(Operand, Operator, String, char),
+    /// operand IN (values)
+    In(Operand, Vec<SqlValue>, InListStyle),
+    /// operand NOT IN (values)
+    NotIn(Operand, Vec<SqlValue>, InListStyle),
+    /// operand BETWEEN low AND high
+    Between(Operand, SqlValue, SqlValue),
+    /// operand NOT BETWEEN low AND high
+    NotBetween(Operand, SqlValue, SqlValue),
+    /// NOT clause
+    Not(Box<WhereClause>),
+    /// clause AND clause AND ...; TRUE when empty
+    And(Vec<WhereClause>),
+    /// clause OR clause OR ...; FALSE when empty
+    Or(Vec<WhereClause>),
 }
 
 impl WhereClause {
+    /// Creates a comparison between two operands.
+    ///
+    /// The condition constructors below accept a column or any `Operand`,
+    /// such as a JSON field, on their left.
+    pub fn compare<L: Into<Operand>, R: Into<Operand>>(
+        left: L,
+        operator: Operator,
+        right: R,
+    ) -> Self {
+        WhereClause::Compare(left.into(), operator, right.into())
+    }
+
+    /// Creates a condition that matches NULLs: column IS NULL
+    pub fn is_null<L: Into<Operand>>(left: L) -> Self {
+        WhereClause::IsNull(left.into())
+    }
+
+    /// Creates a condition that matches non-NULLs: column IS NOT NULL
+    pub fn is_not_null<L: Into<Operand>>(left: L) -> Self {
+        WhereClause::IsNotNull(left.into())
+    }
+
+    /// Creates a membership condition: column IN (values)
+    ///
+    /// An empty list matches no rows and renders as `FALSE`. NULLs in the
+    /// list never match and are left out.
+    pub fn in_list<L, I, V>(left: L, values: I) -> Self
+    where
+        L: Into<Operand>,
+        I: IntoIterator<Item = V>,
+        V: Into<SqlValue>,
+    {
+        Self::in_list_with(left, values, InListStyle::default())
+    }
+
+    /// Creates a membership condition rendered in the given style.
+    pub fn in_list_with<L, I, V>(left: L, values: I, style: InListStyle) -> Self
+    where
+        L: Into<Operand>,
+        I: IntoIterator<Item = V>,
+        V: Into<SqlValue>,
+    {
+        WhereClause::In(
+            left.into(),
+            values.into_iter().map(Into::into).collect(),
+            style,
+        )
+    }
+
+    /// Creates a non-membership condition: column NOT IN (values)
+    ///
+    /// An empty list matches every row and renders as `TRUE`. A NULL in the
+    /// list would match no rows, so building fails with
+    /// `PgRsError::NullComparison`.
+    pub fn not_in<L, I, V>(left: L, values: I) -> Self
+    where
+        L: Into<Operand>,
+        I: IntoIterator<Item = V>,
+        V: Into<SqlValue>,
+    {
+        Self::not_in_with(left, values, InListStyle::default())
+    }
+
+    /// Creates a non-membership condition rendered in the given style.
+    pub fn not_in_with<L, I, V>(left: L, values: I, style: InListStyle) -> Self
+    where
+        L: Into<Operand>,
+        I: IntoIterator<Item = V>,
+        V: Into<SqlValue>,
+    {
+        WhereClause::NotIn(
+            left.into(),
+            values.into_iter().map(Into::into).collect(),
+            style,
+        )
+    }
+
     /// Creates an equality condition: column = value
-    pub fn eq<C: Column, V: Into<SqlValue>>(column: &C, value: V) -> Self {
-        WhereClause::Eq(ColumnRef::from_column(column), value.into())
+    ///
+    /// Comparing with `SqlValue::Null` renders `column IS NULL`.
+    pub fn eq<L: Into<Operand>, V: Into<SqlValue>>(left: L, value: V) -> Self {
+        Self::compare(left, Operator::Eq, Operand::Value(value.into()))
+    }
+
+    /// Creates an inequality condition: column <> value
+    ///
+    /// Comparing with `SqlValue::Null` renders `column IS NOT NULL`.
+    pub fn ne<L: Into<Operand>, V: Into<SqlValue>>(left: L, value: V) -> Self {
+        Self::compare(left, Operator::Ne, Operand::Value(value.into()))
+    }
+
+    /// Creates a less-than condition: column < value
+    pub fn lt<L: Into<Operand>, V: Into<SqlValue>>(left: L, value: V) -> Self {
+        Self::compare(left, Operator::Lt, Operand::Value(value.into()))
+    }
+
+    /// Creates a less-than-or-equal condition: column <= value
+    pub fn le<L: Into<Operand>, V: Into<SqlValue>>(left: L, value: V) -> Self {
+        Self::compare(left, Operator::Le, Operand::Value(value.into()))
+    }
+
+    /// Creates a greater-than condition: column > value
+    pub fn gt<L: Into<Operand>, V: Into<SqlValue>>(left: L, value: V) -> Self {
+        Self::compare(left, Operator::Gt, Operand::Value(value.into()))
+    }
+
+    /// Creates a greater-than-or-equal condition: column >= value
+    pub fn ge<L: Into<Operand>, V: Into<SqlValue>>(left: L, value: V) -> Self {
+        Self::compare(left, Operator::Ge, Operand::Value(value.into()))
+    }
+
+    /// Creates a NULL-safe inequality condition: column IS DISTINCT FROM value
+    pub fn is_distinct_from<L: Into<Operand>, V: Into<SqlValue>>(left: L, value: V) -> Self {
+        Self::compare(left, Operator::IsDistinctFrom, Operand::Value(value.into()))
+    }
+
+    /// Creates a NULL-safe equality condition: column IS NOT DISTINCT FROM value
+    pub fn is_not_distinct_from<L: Into<Operand>, V: Into<SqlValue>>(left: L, value: V) -> Self {
+        Self::compare(
+            left,
+            Operator::IsNotDistinctFrom,
+            Operand::Value(value.into()),
+        )
+    }
+
+    /// Creates a pattern match: column LIKE pattern
+    ///
+    /// `%` and `_` in the pattern are wildcards. Use `contains`,
+    /// `starts_with` or `ends_with` to match user-supplied text literally.
+    pub fn like<L: Into<Operand>, V: Into<SqlValue>>(left: L, pattern: V) -> Self {
+        Self::compare(left, Operator::Like, Operand::Value(pattern.into()))
+    }
+
+    /// Creates a negated pattern match: column NOT LIKE pattern
+    pub fn not_like<L: Into<Operand>, V: Into<SqlValue>>(left: L, pattern: V) -> Self {
+        Self::compare(left, Operator::NotLike, Operand::Value(pattern.into()))
+    }
+
+    /// Creates a case-insensitive pattern match: column ILIKE pattern
+    pub fn ilike<L: Into<Operand>, V: Into<SqlValue>>(left: L, pattern: V) -> Self {
+        Self::compare(left, Operator::ILike, Operand::Value(pattern.into()))
+    }
+
+    /// Creates a negated case-insensitive pattern match: column NOT ILIKE pattern
+    pub fn not_ilike<L: Into<Operand>, V: Into<SqlValue>>(left: L, pattern: V) -> Self {
+        Self::compare(left, Operator::NotILike, Operand::Value(pattern.into()))
+    }
+
+    /// Creates an SQL regular expression match: column SIMILAR TO pattern
+    pub fn similar_to<L: Into<Operand>, V: Into<SqlValue>>(left: L, pattern: V) -> Self {
+        Self::compare(left, Operator::SimilarTo, Operand::Value(pattern.into()))
+    }
+
+    /// Creates a negated SQL regular expression match: column NOT SIMILAR TO pattern
+    pub fn not_similar_to<L: Into<Operand>, V: Into<SqlValue>>(left: L, pattern: V) -> Self {
+        Self::compare(left, Operator::NotSimilarTo, Operand::Value(pattern.into()))
+    }
+
+    /// Creates a POSIX regular expression match: column ~ pattern
+    pub fn regex_match<L: Into<Operand>, V: Into<SqlValue>>(left: L, pattern: V) -> Self {
+        Self::compare(left, Operator::RegexMatch, Operand::Value(pattern.into()))
+    }
+
+    /// Creates a case-insensitive POSIX regular expression match: column ~* pattern
+    pub fn regex_imatch<L: Into<Operand>, V: Into<SqlValue>>(left: L, pattern: V) -> Self {
+        Self::compare(left, Operator::RegexIMatch, Operand::Value(pattern.into()))
+    }
+
+    /// Creates a POSIX regular expression non-match: column !~ pattern
+    pub fn not_regex_match<L: Into<Operand>, V: Into<SqlValue>>(left: L, pattern: V) -> Self {
+        Self::compare(
+            left,
+            Operator::NotRegexMatch,
+            Operand::Value(pattern.into()),
+        )
+    }
+
+    /// Creates a case-insensitive POSIX regular expression non-match: column !~* pattern
+    pub fn not_regex_imatch<L: Into<Operand>, V: Into<SqlValue>>(left: L, pattern: V) -> Self {
+        Self::compare(
+            left,
+            Operator::NotRegexIMatch,
+            Operand::Value(pattern.into()),
+        )
+    }
+
+    /// Matches values containing `text`, which is matched literally:
+    /// column LIKE '%text%' ESCAPE '\'
+    pub fn contains<L: Into<Operand>>(left: L, text: &str) -> Self {
+        Self::literal_pattern(left, Operator::Like, format!("%{}%", escape_like(text)))
+    }
+
+    /// Matches values starting with `text`, which is matched literally:
+    /// column LIKE 'text%' ESCAPE '\'
+    pub fn starts_with<L: Into<Operand>>(left: L, text: &str) -> Self {
+        Self::literal_pattern(left, Operator::Like, format!("{}%", escape_like(text)))
+    }
+
+    /// Matches values ending with `text`, which is matched literally:
+    /// column LIKE '%text' ESCAPE '\'
+    pub fn ends_with<L: Into<Operand>>(left: L, text: &str) -> Self {
+        Self::literal_pattern(left, Operator::Like, format!("%{}", escape_like(text)))
+    }
+
+    /// Case-insensitive `contains`: column ILIKE '%text%' ESCAPE '\'
+    pub fn icontains<L: Into<Operand>>(left: L, text: &str) -> Self {
+        Self::literal_pattern(left, Operator::ILike, format!("%{}%", escape_like(text)))
+    }
+
+    /// Case-insensitive `starts_with`: column ILIKE 'text%' ESCAPE '\'
+    pub fn istarts_with<L: Into<Operand>>(left: L, text: &str) -> Self {
+        Self::literal_pattern(left, Operator::ILike, format!("{}%", escape_like(text)))
+    }
+
+    /// Case-insensitive `ends_with`: column ILIKE '%text' ESCAPE '\'
+    pub fn iends_with<L: Into<Operand>>(left: L, text: &str) -> Self {
+        Self::literal_pattern(left, Operator::ILike, format!("%{}", escape_like(text)))
+    }
+
+    fn literal_pattern<L: Into<Operand>>(left: L, operator: Operator, pattern: String) -> Self {
+        WhereClause::Pattern(left.into(), operator, pattern, LIKE_ESCAPE)
+    }
+
+    /// Matches JSON values containing the given JSON: left @> value
+    pub fn json_contains<L: Into<Operand>>(left: L, json: &str) -> Self {
+        Self::compare(left, Operator::Contains, SqlValue::Json(json.to_string()))
+    }
+
+    /// Matches JSON values contained in the given JSON: left <@ value
+    pub fn json_contained_by<L: Into<Operand>>(left: L, json: &str) -> Self {
+        Self::compare(
+            left,
+            Operator::ContainedBy,
+            SqlValue::Json(json.to_string()),
+        )
+    }
+
+    /// Matches JSON objects with the key, or JSON arrays with the string: left ? key
+    pub fn has_key<L: Into<Operand>>(left: L, key: &str) -> Self {
+        Self::compare(left, Operator::HasKey, SqlValue::from(key))
+    }
+
+    /// Matches JSON values with any of the keys: left ?| keys
+    pub fn has_any_key<L, I, S>(left: L, keys: I) -> Self
+    where
+        L: Into<Operand>,
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self::compare(left, Operator::HasAnyKey, text_array(keys))
+    }
+
+    /// Matches JSON values with all of the keys: left ?& keys
+    pub fn has_all_keys<L, I, S>(left: L, keys: I) -> Self
+    where
+        L: Into<Operand>,
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self::compare(left, Operator::HasAllKeys, text_array(keys))
+    }
+
+    /// Matches JSON values for which the JSON path returns any item: left @? path
+    pub fn json_path_exists<L: Into<Operand>>(left: L, path: &str) -> Self {
+        Self::compare(left, Operator::PathExists, json_path(path))
+    }
+
+    /// Matches JSON values for which the JSON path predicate is true: left @@ path
+    pub fn json_path_match<L: Into<Operand>>(left: L, path: &str) -> Self {
+        Self::compare(left, Operator::PathMatch, json_path(path))
+    }
+
+    /// Matches arrays containing every one of the values: left @> values
+    pub fn array_contains<L, I, V>(left: L, values: I) -> Self
+    where
+        L: Into<Operand>,
+        I: IntoIterator<Item = V>,
+        V: Into<SqlValue>,
+    {
+        Self::compare(left, Operator::Contains, array(values))
+    }
+
+    /// Matches arrays whose elements are all among the values: left <@ values
+    pub fn array_contained_by<L, I, V>(left: L, values: I) -> Self
+    where
+        L: Into<Operand>,
+        I: IntoIterator<Item = V>,
+        V: Into<SqlValue>,
+    {
+        Self::compare(left, Operator::ContainedBy, array(values))
+    }
+
+    /// Matches arrays sharing any element with the values: left && values
+    pub fn array_overlaps<L, I, V>(left: L, values: I) -> Self
+    where
+        L: Into<Operand>,
+        I: IntoIterator<Item = V>,
+        V: Into<SqlValue>,
+    {
+        Self::compare(left, Operator::Overlaps, array(values))
+    }
+
+    /// Matches arrays with an element equal to the value: value = ANY(array)
+    pub fn eq_any<A: Into<Operand>, V: Into<SqlValue>>(array: A, value: V) -> Self {
+        Self::compare(
+            Operand::value(value),
+            Operator::Eq,
+            Operand::function("ANY", vec![array.into()]),
+        )
+    }
+
+    /// Matches ranges containing every value of the range: left @> range
+    pub fn range_contains<L: Into<Operand>, V: Into<SqlValue>>(left: L, range: V) -> Self {
+        Self::compare(left, Operator::Contains, Operand::value(range))
+    }
+
+    /// Matches ranges containing the element: left @> element
+    pub fn range_contains_element<L: Into<Operand>, V: Into<SqlValue>>(
+        left: L,
+        element: V,
+    ) -> Self {
+        // Cast integers, or the placeholder is inferred to be a range
+        let element = match element.into() {
+            SqlValue::Int32(i) => Operand::value(i).cast(PgType::Integer),
+            SqlValue::Int64(i) => Operand::value(i).cast(PgType::BigInt),
+            other => Operand::Value(other),
+        };
+        Self::compare(left, Operator::Contains, element)
+    }
+
+    /// Matches ranges or elements contained in the range: left <@ range
+    pub fn range_contained_by<L: Into<Operand>, V: Into<SqlValue>>(left: L, range: V) -> Self {
+        Self::compare(left, Operator::ContainedBy, Operand::value(range))
+    }
+
+    /// Matches ranges sharing any value with the range: left && range
+    pub fn range_overlaps<L: Into<Operand>, V: Into<SqlValue>>(left: L, range: V) -> Self {
+        Self::compare(left, Operator::Overlaps, Operand::value(range))
+    }
+
+    /// Matches ranges that meet the range without overlapping it:
+    /// left -|- range
+    pub fn range_adjacent<L: Into<Operand>, V: Into<SqlValue>>(left: L, range: V) -> Self {
+        Self::compare(left, Operator::Adjacent, Operand::value(range))
+    }
+
+    /// Matches ranges ending before the range starts: left << range
+    pub fn range_left_of<L: Into<Operand>, V: Into<SqlValue>>(left: L, range: V) -> Self {
+        Self::compare(left, Operator::StrictlyLeft, Operand::value(range))
+    }
+
+    /// Matches ranges starting after the range ends: left >> range
+    pub fn range_right_of<L: Into<Operand>, V: Into<SqlValue>>(left: L, range: V) -> Self {
+        Self::compare(left, Operator::StrictlyRight, Operand::value(range))
+    }
+
+    /// Matches a text search vector against a query: vector @@ query
+    ///
+    /// Build both sides with `TextSearch`.
+    pub fn text_match<V: Into<Operand>, Q: Into<Operand>>(vector: V, query: Q) -> Self {
+        Self::compare(vector, Operator::TextMatch, query)
+    }
+
+    /// Compares two columns: left = right
+    pub fn col_eq<L: Column, R: Column>(left: &L, right: &R) -> Self {
+        Self::compare_columns(left, Operator::Eq, right)
+    }
+
+    /// Compares two columns: left <> right
+    pub fn col_ne<L: Column, R: Column>(left: &L, right: &R) -> Self {
+        Self::compare_columns(left, Operator::Ne, right)
+    }
+
+    /// Compares two columns: left < right
+    pub fn col_lt<L: Column, R: Column>(left: &L, right: &R) -> Self {
+        Self::compare_columns(left, Operator::Lt, right)
+    }
+
+    /// Compares two columns: left <= right
+    pub fn col_le<L: Column, R: Column>(left: &L, right: &R) -> Self {
+        Self::compare_columns(left, Operator::Le, right)
+    }
+
+    /// Compares two columns: left > right
+    pub fn col_gt<L: Column, R: Column>(left: &L, right: &R) -> Self {
+        Self::compare_columns(left, Operator::Gt, right)
+    }
+
+    /// Compares two columns: left >= right
+    pub fn col_ge<L: Column, R: Column>(left: &L, right: &R) -> Self {
+        Self::compare_columns(left, Operator::Ge, right)
+    }
+
+    fn compare_columns<L: Column, R: Column>(left: &L, operator: Operator, right: &R) -> Self {
+        Self::compare(left, operator, Operand::column(right))
+    }
+
+    /// Creates a range condition: column BETWEEN low AND high
+    ///
+    /// Both bounds are inclusive.
+    pub fn between<L, V, W>(left: L, low: V, high: W) -> Self
+    where
+        L: Into<Operand>,
+        V: Into<SqlValue>,
+        W: Into<SqlValue>,
+    {
+        WhereClause::Between(left.into(), low.into(), high.into())
+    }
+
+    /// Creates a negated range condition: column NOT BETWEEN low AND high
+    pub fn not_between<L, V, W>(left: L, low: V, high: W) -> Self
+    where
+        L: Into<Operand>,
+        V: Into<SqlValue>,
+        W: Into<SqlValue>,
+    {
+        WhereClause::NotBetween(left.into(), low.into(), high.into())
+    }
+
+    /// Negates a clause: NOT (clause). `!clause` does the same.
+    #[allow(clippy::should_implement_trait)]
+    pub fn not(clause: WhereClause) -> Self {
+        WhereClause::Not(Box::new(clause))
+    }
+
+    /// Combines any number of clauses with AND.
+    /// An empty set of clauses matches every row.
+    pub fn all<I: IntoIterator<Item = WhereClause>>(clauses: I) -> Self {
+        WhereClause::And(clauses.into_iter().collect())
+    }
+
+    /// Combines any number of clauses with OR.
+    /// An empty set of clauses matches no rows.
+    pub fn any<I: IntoIterator<Item = WhereClause>>(clauses: I) -> Self {
+        WhereClause::Or(clauses.into_iter().collect())
     }
 
     /// Combines this clause with another using AND
     pub fn and(self, other: WhereClause) -> Self {
-        WhereClause::And(Box::new(self), Box::new(other))
+        match self {
+            WhereClause::And(mut clauses) => {
+                clauses.push(other);
+                WhereClause::And(clauses)
+            }
+            clause => WhereClause::And(vec![clause, other]),
+        }
     }
 
     /// Combines this clause with another using OR
     pub fn or(self, other: WhereClause) -> Self {
-        WhereClause::Or(Box::new(self), Box::new(other))
+        match self {
+            WhereClause::Or(mut clauses) => {
+                clauses.push(other);
+                WhereClause::Or(clauses)
+            }
+            clause => WhereClause::Or(vec![clause, other]),
+        }
     }
 
     /// Builds the SQL string and collects parameters.
     /// Returns the SQL fragment and updates the params vector.
     /// `param_offset` is the starting parameter number (1-indexed for PostgreSQL).
-    pub fn build_sql(&self, param_offset: usize, params: &mut Vec<SqlValue>) -> String {
-        match self {
-            WhereClause::Eq(col, value) => {
-                params.push(value.clone());
+    ///
+    /// `=` and `<>` against `SqlValue::Null` render as `IS NULL` and
+    /// `IS NOT NULL`. Any other ordering comparison with NULL would never
+    /// match a row, so it fails with `PgRsError::NullComparison`, as does a
+    /// NULL in a `NOT IN` list or a `BETWEEN` bound. NULLs in an `IN` list
+    /// can never match and are left out.
+    ///
+    /// Chains of AND or of OR render flat; parentheses are only added where
+    /// an OR is nested in an AND or vice versa.
+    pub fn build_sql(&self, param_offset: usize, params: &mut Vec<SqlValue>) -> Result<String> {
+        let sql = match self {
+            WhereClause::Compare(left, operator, Operand::Value(SqlValue::Null))
+                if !operator.is_null_safe() =>
+            {
+                let left_sql = left.build_sql(param_offset, params);
+                match operator {
+                    Operator::Eq => format!("{} IS NULL", left_sql),
+                    Operator::Ne => format!("{} IS NOT NULL", left_sql),
+                    _ => {
+                        return Err(PgRsError::NullComparison(format!(
+                            "{} {} NULL",
+                            left_sql, operator
+                        )))
+                    }
+                }
+            }
+            WhereClause::Compare(Operand::Value(SqlValue::Null), operator, right)
+                if !operator.is_null_safe() =>
+            {
+                return Err(PgRsError::NullComparison(format!(
+                    "NULL {} {}",
+                    operator,
+                    right.build_sql(param_offset, params)
+                )))
+            }
+            WhereClause::Compare(left, operator, right) => {
+                // Comparison operators such as @> share the precedence of the
+                // value operators, so a value operator on either side must
+                // be grouped to bind first
+                let left_sql = build_compared(left, param_offset, params);
+                let right_sql = build_compared(right, param_offset, params);
+                format!("{} {} {}", left_sql, operator, right_sql)
+            }
+            WhereClause::Pattern(left, operator, pattern, escape) => {
+                let left_sql = left.build_sql(param_offset, params);
+                params.push(SqlValue::Text(pattern.clone()));
                 format!(
-                    "{} = ${}",
-                    col.qualified_name(),
-                    param_offset + params.len()
+                    "{} {} ${} ESCAPE '{}'",
+                    left_sql,
+                    operator,
+                    param_offset + params.len(),
+                    escape.to_string().replace('\'', "''")
                 )
             }
-            WhereClause::And(left, right) => {
-                let left_sql = left.build_sql(param_offset, params);
-                let right_sql = right.build_sql(param_offset, params);
-                format!("({}) AND ({})", left_sql, right_sql)
+            WhereClause::IsNull(operand) => {
+                format!("{} IS NULL", operand.build_sql(param_offset, params))
             }
-            WhereClause::Or(left, right) => {
-                let left_sql = left.build_sql(param_offset, params);
-                let right_sql = right.build_sql(param_offset, params);
-                format!("({}) OR ({})", left_sql, right_sql)
+            WhereClause::IsNotNull(operand) => {
+                format!("{} IS NOT NULL", operand.build_sql(param_offset, params))
+            }
+            WhereClause::In(left, values, style) => {
+                build_in_list(left, values, *style, false, param_offset, params)?
+            }
+            WhereClause::NotIn(left, values, style) => {
+                build_in_list(left, values, *style, true, param_offset, params)?
+            }
+            WhereClause::Between(left, low, high) => {
+                build_between(left, low, high, false, param_offset, params)?
+            }
+            WhereClause::NotBetween(left, low, high) => {
+                build_between(left, low, high, true, param_offset, params)?
+            }
+            WhereClause::Not(clause) => {
+                format!("NOT ({})", clause.build_sql(param_offset, params)?)
+            }
+            WhereClause::And(clauses) => {
+                build_connective(clauses, "AND", "TRUE", param_offset, params)?
+            }
+            WhereClause::Or(clauses) => {
+                build_connective(clauses, "OR", "FALSE", param_offset, params)?
             }
+        };
+        Ok(sql)
+    }
+
+    /// The connective this clause renders with at its top level, if it
+    /// renders as a chain of two or more clauses.
+    fn connective(&self) -> Option<&'static str> {
+        let (clauses, connective) = match self {
+            WhereClause::And(clauses) => (clauses, "AND"),
+            WhereClause::Or(clauses) => (clauses, "OR"),
+            _ => return None,
+        };
+        match clauses.as_slice() {
+            [] => None,
+            [only] => only.connective(),
+            _ => Some(connective),
         }
     }
 }
 
+impl std::ops::Not for WhereClause {
+    type Output = WhereClause;
+
+    fn not(self) -> Self::Output {
+        WhereClause::not(self)
+    }
+}
+
+fn array<I, V>(values: I) -> SqlValue
+where
+    I: IntoIterator<Item = V>,
+    V: Into<SqlValue>,
+{
+    SqlValue::Array(values.into_iter().map(Into::into).collect())
+}
+
+fn text_array<I, S>(items: I) -> SqlValue
+where
+    I: IntoIterator<Item = S>,
+    S: Into<String>,
+{
+    SqlValue::Array(
+        items
+            .into_iter()
+            .map(|item| SqlValue::Text(item.into()))
+            .collect(),
+    )
+}
+
+/// A JSON path parameter. Sent as text and cast, since drivers have no
+/// `jsonpath` type to bind it as.
+fn json_path(path: &str) -> Operand {
+    Operand::value(path)
+        .cast(PgType::Text)
+        .cast(PgType::Custom("jsonpath".to_string()))
+}
+
+fn build_connective(
+    clauses: &[WhereClause],
+    connective: &'static str,
+    empty: &str,
+    param_offset: usize,
+    params: &mut Vec<SqlValue>,
+) -> Result<String> {
+    if clauses.is_empty() {
+        return Ok(empty.to_string());
+    }
+
+    let mut parts = Vec::with_capacity(clauses.len());
+    for clause in clauses {
+        let sql = clause.build_sql(param_offset, params)?;
+        match clause.connective() {
+            Some(inner) if inner != connective => parts.push(format!("({})", sql)),
+            _ => parts.push(sql),
+        }
+    }
+    Ok(parts.join(&format!(" {} ", connective)))
+}
+
+fn build_between(
+    left: &Operand,
+    low: &SqlValue,
+    high: &SqlValue,
+    negated: bool,
+    param_offset: usize,
+    params: &mut Vec<SqlValue>,
+) -> Result<String> {
+    let keyword = if negated { "NOT BETWEEN" } else { "BETWEEN" };
+    let left_sql = left.build_sql(param_offset, params);
+    if *low == SqlValue::Null || *high == SqlValue::Null {
+        return Err(PgRsError::NullComparison(format!(
+            "{} {} with a NULL bound",
+            left_sql, keyword
+        )));
+    }
+
+    let low_sql = Operand::Value(low.clone()).build_sql(param_offset, params);
+    let high_sql = Operand::Value(high.clone()).build_sql(param_offset, params);
+    Ok(format!(
+        "{} {} {} AND {}",
+        left_sql, keyword, low_sql, high_sql
+    ))
+}
+
+fn build_compared(operand: &Operand, param_offset: usize, params: &mut Vec<SqlValue>) -> String {
+    let sql = operand.build_sql(param_offset, params);
+    match operand {
+        Operand::Binary(..) => format!("({})", sql),
+        _ => sql,
+    }
+}
+
+fn build_in_list(
+    left: &Operand,
+    values: &[SqlValue],
+    style: InListStyle,
+    negated: bool,
+    param_offset: usize,
+    params: &mut Vec<SqlValue>,
+) -> Result<String> {
+    // `x NOT IN (..., NULL)` is never true, while a NULL in an IN list
+    // simply matches nothing
+    if negated && values.contains(&SqlValue::Null) {
+        return Err(PgRsError::NullComparison(format!(
+            "{} NOT IN (..., NULL)",
+            left.build_sql(param_offset, &mut Vec::new())
+        )));
+    }
+    let values: Vec<SqlValue> = values
+        .iter()
+        .filter(|value| **value != SqlValue::Null)
+        .cloned()
+        .collect();
+
+    // `IN ()` is a syntax error; an empty list is a constant instead
+    if values.is_empty() {
+        return Ok(if negated { "TRUE" } else { "FALSE" }.to_string());
+    }
+
+    let left_sql = left.build_sql(param_offset, params);
+    let sql = match style {
+        InListStyle::Expanded => {
+            let placeholders: Vec<String> = values
+                .iter()
+                .map(|value| Operand::Value(value.clone()).build_sql(param_offset, params))
+                .collect();
+            let keyword = if negated { "NOT IN" } else { "IN" };
+            format!("{} {} ({})", left_sql, keyword, placeholders.join(", "))
+        }
+        InListStyle::Any => {
+            let array_sql = Operand::Value(SqlValue::Array(values)).build_sql(param_offset, params);
+            let comparison = if negated { "<> ALL" } else { "= ANY" };
+            format!("{} {}({})", left_sql, comparison, array_sql)
+        }
+    };
+    Ok(sql)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::types::PgRange;
+    use std::ops::Bound;
 
     // Test column implementation
     struct TestColumn {
@@ -83,13 +776,25 @@ mod tests {
         };
         let clause = WhereClause::eq(&col, "John");
         let mut params = Vec::new();
-        let sql = clause.build_sql(0, &mut params);
+        let sql = clause.build_sql(0, &mut params).unwrap();
 
         assert_eq!(sql, "users.name = $1");
         assert_eq!(params.len(), 1);
         assert_eq!(params[0], SqlValue::Text("John".to_string()));
     }
 
+    #[test]
+    fn test_eq_clause_quotes_identifiers() {
+        let col = TestColumn {
+            table: "order",
+            column: "userId",
+        };
+        let mut params = Vec::new();
+        let sql = WhereClause::eq(&col, 1).build_sql(0, &mut params).unwrap();
+
+        assert_eq!(sql, "\"order\".\"userId\" = $1");
+    }
+
     #[test]
     fn test_and_clause() {
         let name_col = TestColumn {
@@ -104,9 +809,642 @@ mod tests {
         let clause = WhereClause::eq(&name_col, "John").and(WhereClause::eq(&age_col, 30));
 
         let mut params = Vec::new();
-        let sql = clause.build_sql(0, &mut params);
+        let sql = clause.build_sql(0, &mut params).unwrap();
+
+        assert_eq!(sql, "users.name = $1 AND users.age = $2");
+        assert_eq!(params.len(), 2);
+    }
+
+    #[test]
+    fn test_comparison_operators() {
+        let col = TestColumn {
+            table: "events",
+            column: "created_at",
+        };
+        let cases = [
+            (WhereClause::ne(&col, 1), "events.created_at <> $1"),
+            (WhereClause::lt(&col, 1), "events.created_at < $1"),
+            (WhereClause::le(&col, 1), "events.created_at <= $1"),
+            (WhereClause::gt(&col, 1), "events.created_at > $1"),
+            (WhereClause::ge(&col, 1), "events.created_at >= $1"),
+            (
+                WhereClause::is_distinct_from(&col, 1),
+                "events.created_at IS DISTINCT FROM $1",
+            ),
+            (
+                WhereClause::is_not_distinct_from(&col, 1),
+                "events.created_at IS NOT DISTINCT FROM $1",
+            ),
+        ];
+
+        for (clause, expected) in cases {
+            let mut params = Vec::new();
+            assert_eq!(clause.build_sql(0, &mut params).unwrap(), expected);
+            assert_eq!(params, vec![SqlValue::Int32(1)]);
+        }
+    }
+
+    #[test]
+    fn test_column_comparisons() {
+        let updated_at = TestColumn {
+            table: "events",
+            column: "updated_at",
+        };
+        let created_at = TestColumn {
+            table: "events",
+            column: "created_at",
+        };
+
+        let clause = WhereClause::col_gt(&updated_at, &created_at)
+            .and(WhereClause::col_eq(&created_at, &updated_at))
+            .or(WhereClause::ge(&created_at, 5));
+        let mut params = Vec::new();
+        let sql = clause.build_sql(0, &mut params).unwrap();
+
+        assert_eq!(
+            sql,
+            "(events.updated_at > events.created_at AND \
+             events.created_at = events.updated_at) OR events.created_at >= $1"
+        );
+        assert_eq!(params, vec![SqlValue::Int32(5)]);
+    }
+
+    #[test]
+    fn test_null_checks() {
+        let col = TestColumn {
+            table: "users",
+            column: "deleted_at",
+        };
+        let cases = [
+            (WhereClause::is_null(&col), "users.deleted_at IS NULL"),
+            (
+                WhereClause::is_not_null(&col),
+                "users.deleted_at IS NOT NULL",
+            ),
+            (
+                WhereClause::eq(&col, None::<i32>),
+                "users.deleted_at IS NULL",
+            ),
+            (
+                WhereClause::ne(&col, SqlValue::Null),
+                "users.deleted_at IS NOT NULL",
+            ),
+        ];
+
+        for (clause, expected) in cases {
+            let mut params = Vec::new();
+            assert_eq!(clause.build_sql(0, &mut params).unwrap(), expected);
+            assert!(params.is_empty());
+        }
+
+        let mut params = Vec::new();
+        let sql = WhereClause::is_distinct_from(&col, SqlValue::Null)
+            .build_sql(0, &mut params)
+            .unwrap();
+        assert_eq!(sql, "users.deleted_at IS DISTINCT FROM $1");
+        assert_eq!(params, vec![SqlValue::Null]);
+    }
+
+    #[test]
+    fn test_ordering_comparison_with_null_fails() {
+        let col = TestColumn {
+            table: "users",
+            column: "age",
+        };
+        let clause = WhereClause::eq(&col, 30).and(WhereClause::lt(&col, None::<i32>));
+        let mut params = Vec::new();
+        match clause.build_sql(0, &mut params) {
+            Err(PgRsError::NullComparison(message)) => assert_eq!(message, "users.age < NULL"),
+            other => panic!("Expected NullComparison, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_in_list_expanded() {
+        let col = TestColumn {
+            table: "users",
+            column: "id",
+        };
+        let mut params = Vec::new();
+        let sql = WhereClause::eq(&col, 0)
+            .and(WhereClause::in_list(&col, [1, 2, 3]))
+            .build_sql(0, &mut params)
+            .unwrap();
+
+        assert_eq!(sql, "users.id = $1 AND users.id IN ($2, $3, $4)");
+        assert_eq!(
+            params,
+            vec![
+                SqlValue::Int32(0),
+                SqlValue::Int32(1),
+                SqlValue::Int32(2),
+                SqlValue::Int32(3)
+            ]
+        );
+
+        let mut params = Vec::new();
+        let sql = WhereClause::not_in(&col, vec![7i64])
+            .build_sql(0, &mut params)
+            .unwrap();
+        assert_eq!(sql, "users.id NOT IN ($1)");
+        assert_eq!(params, vec![SqlValue::Int64(7)]);
+    }
+
+    #[test]
+    fn test_in_list_any() {
+        let col = TestColumn {
+            table: "users",
+            column: "id",
+        };
+        let mut params = Vec::new();
+        let sql = WhereClause::in_list_with(&col, [1, 2], InListStyle::Any)
+            .build_sql(0, &mut params)
+            .unwrap();
+        assert_eq!(sql, "users.id = ANY($1)");
+        assert_eq!(
+            params,
+            vec![SqlValue::Array(vec![
+                SqlValue::Int32(1),
+                SqlValue::Int32(2)
+            ])]
+        );
+
+        let mut params = Vec::new();
+        let sql = WhereClause::not_in_with(&col, ["a"], InListStyle::Any)
+            .build_sql(0, &mut params)
+            .unwrap();
+        assert_eq!(sql, "users.id <> ALL($1)");
+    }
+
+    #[test]
+    fn test_empty_in_list() {
+        let col = TestColumn {
+            table: "users",
+            column: "id",
+        };
+        for style in [InListStyle::Expanded, InListStyle::Any] {
+            let mut params = Vec::new();
+            let in_sql = WhereClause::in_list_with(&col, Vec::<i32>::new(), style)
+                .build_sql(0, &mut params)
+                .unwrap();
+            let not_in_sql = WhereClause::not_in_with(&col, Vec::<i32>::new(), style)
+                .build_sql(0, &mut params)
+                .unwrap();
+            assert_eq!(in_sql, "FALSE");
+            assert_eq!(not_in_sql, "TRUE");
+            assert!(params.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_in_list_skips_null() {
+        let col = TestColumn {
+            table: "users",
+            column: "id",
+        };
+        let mut params = Vec::new();
+        let sql = WhereClause::in_list(&col, [Some(1), None, Some(2)])
+            .build_sql(0, &mut params)
+            .unwrap();
+        assert_eq!(sql, "users.id IN ($1, $2)");
+        assert_eq!(params, vec![SqlValue::Int32(1), SqlValue::Int32(2)]);
+
+        let mut params = Vec::new();
+        let sql = WhereClause::in_list(&col, [None::<i32>])
+            .build_sql(0, &mut params)
+            .unwrap();
+        assert_eq!(sql, "FALSE");
+        assert!(params.is_empty());
+    }
+
+    #[test]
+    fn test_not_in_list_with_null_fails() {
+        let col = TestColumn {
+            table: "users",
+            column: "id",
+        };
+        let clause = WhereClause::not_in(&col, [Some(1), None]);
+        let mut params = Vec::new();
+        assert!(matches!(
+            clause.build_sql(0, &mut params),
+            Err(PgRsError::NullComparison(_))
+        ));
+    }
+
+    #[test]
+    fn test_pattern_operators() {
+        let col = TestColumn {
+            table: "users",
+            column: "name",
+        };
+        let cases = [
+            (WhereClause::like(&col, "J%"), "users.name LIKE $1"),
+            (WhereClause::not_like(&col, "J%"), "users.name NOT LIKE $1"),
+            (WhereClause::ilike(&col, "J%"), "users.name ILIKE $1"),
+            (
+                WhereClause::not_ilike(&col, "J%"),
+                "users.name NOT ILIKE $1",
+            ),
+            (
+                WhereClause::similar_to(&col, "J%"),
+                "users.name SIMILAR TO $1",
+            ),
+            (
+                WhereClause::not_similar_to(&col, "J%"),
+                "users.name NOT SIMILAR TO $1",
+            ),
+            (WhereClause::regex_match(&col, "J%"), "users.name ~ $1"),
+            (WhereClause::regex_imatch(&col, "J%"), "users.name ~* $1"),
+            (WhereClause::not_regex_match(&col, "J%"), "users.name !~ $1"),
+            (
+                WhereClause::not_regex_imatch(&col, "J%"),
+                "users.name !~* $1",
+            ),
+        ];
+
+        for (clause, expected) in cases {
+            let mut params = Vec::new();
+            assert_eq!(clause.build_sql(0, &mut params).unwrap(), expected);
+            assert_eq!(params, vec![SqlValue::Text("J%".to_string())]);
+        }
+    }
+
+    #[test]
+    fn test_literal_pattern_helpers() {
+        let col = TestColumn {
+            table: "users",
+            column: "name",
+        };
+        let cases = [
+            (
+                WhereClause::contains(&col, "50%_off\\"),
+                "users.name LIKE $1 ESCAPE '\\'",
+                "%50\\%\\_off\\\\%",
+            ),
+            (
+                WhereClause::starts_with(&col, "a_b"),
+                "users.name LIKE $1 ESCAPE '\\'",
+                "a\\_b%",
+            ),
+            (
+                WhereClause::ends_with(&col, "a_b"),
+                "users.name LIKE $1 ESCAPE '\\'",
+                "%a\\_b",
+            ),
+            (
+                WhereClause::icontains(&col, "Bob"),
+                "users.name ILIKE $1 ESCAPE '\\'",
+                "%Bob%",
+            ),
+            (
+                WhereClause::istarts_with(&col, "Bob"),
+                "users.name ILIKE $1 ESCAPE '\\'",
+                "Bob%",
+            ),
+            (
+                WhereClause::iends_with(&col, "Bob"),
+                "users.name ILIKE $1 ESCAPE '\\'",
+                "%Bob",
+            ),
+        ];
+
+        for (clause, expected_sql, expected_pattern) in cases {
+            let mut params = Vec::new();
+            assert_eq!(clause.build_sql(0, &mut params).unwrap(), expected_sql);
+            assert_eq!(params, vec![SqlValue::Text(expected_pattern.to_string())]);
+        }
+    }
+
+    #[test]
+    fn test_pattern_with_null_fails() {
+        let col = TestColumn {
+            table: "users",
+            column: "name",
+        };
+        let mut params = Vec::new();
+        assert!(matches!(
+            WhereClause::like(&col, None::<String>).build_sql(0, &mut params),
+            Err(PgRsError::NullComparison(_))
+        ));
+    }
+
+    #[test]
+    fn test_between() {
+        let col = TestColumn {
+            table: "events",
+            column: "created_at",
+        };
+        let mut params = Vec::new();
+        let sql = WhereClause::between(&col, 1, 10)
+            .and(WhereClause::not_between(&col, 4, 5))
+            .build_sql(0, &mut params)
+            .unwrap();
+        assert_eq!(
+            sql,
+            "events.created_at BETWEEN $1 AND $2 AND events.created_at NOT BETWEEN $3 AND $4"
+        );
+        assert_eq!(
+            params,
+            vec![
+                SqlValue::Int32(1),
+                SqlValue::Int32(10),
+                SqlValue::Int32(4),
+                SqlValue::Int32(5)
+            ]
+        );
+
+        let mut params = Vec::new();
+        assert!(matches!(
+            WhereClause::between(&col, 1, None::<i32>).build_sql(0, &mut params),
+            Err(PgRsError::NullComparison(_))
+        ));
+    }
 
-        assert_eq!(sql, "(users.name = $1) AND (users.age = $2)");
+    #[test]
+    fn test_flat_chains() {
+        let a = TestColumn {
+            table: "t",
+            column: "a",
+        };
+        let b = TestColumn {
+            table: "t",
+            column: "b",
+        };
+        let c = TestColumn {
+            table: "t",
+            column: "c",
+        };
+
+        let mut params = Vec::new();
+        let sql = WhereClause::eq(&a, 1)
+            .and(WhereClause::eq(&b, 2))
+            .and(WhereClause::eq(&c, 3))
+            .build_sql(0, &mut params)
+            .unwrap();
+        assert_eq!(sql, "t.a = $1 AND t.b = $2 AND t.c = $3");
+
+        let mut params = Vec::new();
+        let sql = WhereClause::eq(&a, 1)
+            .or(WhereClause::eq(&b, 2).or(WhereClause::eq(&c, 3)))
+            .build_sql(0, &mut params)
+            .unwrap();
+        assert_eq!(sql, "t.a = $1 OR t.b = $2 OR t.c = $3");
+
+        let mut params = Vec::new();
+        let sql = WhereClause::any([
+            WhereClause::all([WhereClause::eq(&a, 1), WhereClause::eq(&b, 2)]),
+            WhereClause::all([WhereClause::eq(&c, 3)]),
+            !WhereClause::any([WhereClause::eq(&a, 4), WhereClause::eq(&b, 5)]),
+        ])
+        .and(WhereClause::is_null(&c))
+        .build_sql(0, &mut params)
+        .unwrap();
+        assert_eq!(
+            sql,
+            "((t.a = $1 AND t.b = $2) OR t.c = $3 OR NOT (t.a = $4 OR t.b = $5)) AND t.c IS NULL"
+        );
+        assert_eq!(params.len(), 5);
+    }
+
+    #[test]
+    fn test_empty_chains() {
+        let col = TestColumn {
+            table: "t",
+            column: "a",
+        };
+        let mut params = Vec::new();
+        assert_eq!(
+            WhereClause::all([]).build_sql(0, &mut params).unwrap(),
+            "TRUE"
+        );
+        assert_eq!(
+            WhereClause::any([]).build_sql(0, &mut params).unwrap(),
+            "FALSE"
+        );
+        let sql = WhereClause::any([])
+            .and(WhereClause::eq(&col, 1))
+            .build_sql(0, &mut params)
+            .unwrap();
+        assert_eq!(sql, "FALSE AND t.a = $1");
+    }
+
+    #[test]
+    fn test_json_predicates() {
+        let data = TestColumn {
+            table: "docs",
+            column: "data",
+        };
+        let cases = [
+            (
+                WhereClause::json_contains(&data, "{\"a\": 1}"),
+                "docs.data @> $1",
+            ),
+            (
+                WhereClause::json_contained_by(&data, "{\"a\": 1}"),
+                "docs.data <@ $1",
+            ),
+            (WhereClause::has_key(&data, "a"), "docs.data ? $1"),
+            (
+                WhereClause::has_any_key(&data, ["a", "b"]),
+                "docs.data ?| $1",
+            ),
+            (
+                WhereClause::has_all_keys(&data, ["a", "b"]),
+                "docs.data ?& $1",
+            ),
+            (
+                WhereClause::json_path_exists(&data, "$.a"),
+                "docs.data @? $1::text::jsonpath",
+            ),
+            (
+                WhereClause::json_path_match(&data, "$.a > 1"),
+                "docs.data @@ $1::text::jsonpath",
+            ),
+        ];
+
+        for (clause, expected) in cases {
+            let mut params = Vec::new();
+            assert_eq!(clause.build_sql(0, &mut params).unwrap(), expected);
+            assert_eq!(params.len(), 1);
+        }
+
+        let mut params = Vec::new();
+        WhereClause::json_contains(&data, "[1]")
+            .build_sql(0, &mut params)
+            .unwrap();
+        assert_eq!(params, vec![SqlValue::Json("[1]".to_string())]);
+    }
+
+    #[test]
+    fn test_conditions_on_expressions() {
+        let data = TestColumn {
+            table: "docs",
+            column: "data",
+        };
+        let mut params = Vec::new();
+        let sql = WhereClause::eq(Operand::column(&data).get_text("name"), "bob")
+            .and(WhereClause::is_null(Operand::column(&data).get("deleted")))
+            .and(WhereClause::in_list(
+                Operand::column(&data).get_path_text(["a", "b"]),
+                ["x", "y"],
+            ))
+            .and(WhereClause::starts_with(
+                Operand::column(&data).get_text("email"),
+                "admin_",
+            ))
+            .build_sql(0, &mut params)
+            .unwrap();
+
+        assert_eq!(
+            sql,
+            "(docs.data ->> $1) = $2 AND docs.data -> $3 IS NULL \
+             AND docs.data #>> $4 IN ($5, $6) AND docs.data ->> $7 LIKE $8 ESCAPE '\\'"
+        );
+        assert_eq!(params.len(), 8);
+        assert_eq!(params[7], SqlValue::Text("admin\\_%".to_string()));
+    }
+
+    #[test]
+    fn test_compare_groups_expressions() {
+        let data = TestColumn {
+            table: "docs",
+            column: "data",
+        };
+        let defaults = SqlValue::Json(r#"{"theme": "dark"}"#.to_string());
+        let mut params = Vec::new();
+        let sql = WhereClause::compare(
+            &data,
+            Operator::Contains,
+            Operand::column(&data).get("settings").concat(defaults),
+        )
+        .build_sql(0, &mut params)
+        .unwrap();
+
+        assert_eq!(sql, "docs.data @> (docs.data -> $1 || $2)");
         assert_eq!(params.len(), 2);
     }
+
+    #[test]
+    fn test_array_predicates() {
+        let tags = TestColumn {
+            table: "posts",
+            column: "tags",
+        };
+        let cases = [
+            (
+                WhereClause::array_contains(&tags, ["a", "b"]),
+                "posts.tags @> $1",
+            ),
+            (
+                WhereClause::array_contained_by(&tags, ["a", "b"]),
+                "posts.tags <@ $1",
+            ),
+            (
+                WhereClause::array_overlaps(&tags, ["a", "b"]),
+                "posts.tags && $1",
+            ),
+        ];
+        for (clause, expected) in cases {
+            let mut params = Vec::new();
+            assert_eq!(clause.build_sql(0, &mut params).unwrap(), expected);
+            assert_eq!(
+                params,
+                vec![SqlValue::Array(vec![
+                    SqlValue::Text("a".to_string()),
+                    SqlValue::Text("b".to_string())
+                ])]
+            );
+        }
+
+        let mut params = Vec::new();
+        let sql = WhereClause::eq_any(&tags, "a")
+            .build_sql(0, &mut params)
+            .unwrap();
+        assert_eq!(sql, "$1 = ANY(posts.tags)");
+        assert_eq!(params, vec![SqlValue::Text("a".to_string())]);
+
+        let mut params = Vec::new();
+        assert!(matches!(
+            WhereClause::eq_any(&tags, None::<String>).build_sql(0, &mut params),
+            Err(PgRsError::NullComparison(_))
+        ));
+    }
+
+    #[test]
+    fn test_range_predicates() {
+        let during = TestColumn {
+            table: "bookings",
+            column: "during",
+        };
+        let period = PgRange::new(
+            Bound::Included("2024-01-01".to_string()),
+            Bound::Excluded("2024-02-01".to_string()),
+        );
+        let cases = [
+            (
+                WhereClause::range_contains(&during, SqlValue::TstzRange(period.clone())),
+                "bookings.during @> $1::text::tstzrange",
+            ),
+            (
+                WhereClause::range_contained_by(&during, SqlValue::DateRange(period.clone())),
+                "bookings.during <@ $1::text::daterange",
+            ),
+            (
+                WhereClause::range_overlaps(&during, PgRange::from(1..5)),
+                "bookings.during && $1::text::int4range",
+            ),
+            (
+                WhereClause::range_adjacent(&during, PgRange::from(1i64..5)),
+                "bookings.during -|- $1::text::int8range",
+            ),
+            (
+                WhereClause::range_left_of(&during, SqlValue::NumRange(period.clone())),
+                "bookings.during << $1::text::numrange",
+            ),
+            (
+                WhereClause::range_right_of(&during, SqlValue::TsRange(period)),
+                "bookings.during >> $1::text::tsrange",
+            ),
+            (
+                WhereClause::range_contains_element(&during, 3),
+                "bookings.during @> $1::integer",
+            ),
+        ];
+        for (clause, expected) in cases {
+            let mut params = Vec::new();
+            assert_eq!(clause.build_sql(0, &mut params).unwrap(), expected);
+            assert_eq!(params.len(), 1);
+        }
+    }
+
+    #[test]
+    fn test_text_cast_values() {
+        let created = TestColumn {
+            table: "events",
+            column: "created_on",
+        };
+        let day = |d: &str| SqlValue::Date(d.to_string());
+
+        let mut params = Vec::new();
+        let sql = WhereClause::between(&created, day("2024-01-01"), day("2024-01-31"))
+            .build_sql(0, &mut params)
+            .unwrap();
+        assert_eq!(
+            sql,
+            "events.created_on BETWEEN $1::text::date AND $2::text::date"
+        );
+
+        let mut params = Vec::new();
+        let sql = WhereClause::in_list(&created, [day("2024-01-01"), day("2024-01-02")])
+            .build_sql(0, &mut params)
+            .unwrap();
+        assert_eq!(sql, "events.created_on IN ($1::text::date, $2::text::date)");
+
+        let mut params = Vec::new();
+        let sql = WhereClause::in_list_with(&created, [day("2024-01-01")], InListStyle::Any)
+            .build_sql(0, &mut params)
+            .unwrap();
+        assert_eq!(sql, "events.created_on = ANY($1::text[]::date[])");
+        assert_eq!(params, vec![SqlValue::Array(vec![day("2024-01-01")])]);
+    }
 }
@@ -2,7 +2,9 @@ use std::sync::Arc;
 
 use crate::drivers::TokioPostgresDriver;
 use crate::error::Result;
+use crate::migrations::{Migrations, Migrator};
 use crate::querier::Querier;
+use crate::schema::{check_drift, DriftReport, TableDef};
 use crate::traits::DatabaseDriver;
 
 /// Main entry point for pgrs.
@@ -35,4 +37,26 @@ impl PgRsClient {
     pub fn querier(&self) -> Querier {
         Querier::new(Arc::clone(&self.driver))
     }
+
+    /// Compare table definitions against the connected database's catalog.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let report = client.check_drift(&[TableDef::of::<Users>()?]).await?;
+    /// report.into_result()?;
+    /// ```
+    pub async fn check_drift(&self, tables: &[TableDef]) -> Result<DriftReport> {
+        check_drift(self.driver.as_ref(), tables).await
+    }
+
+    /// Create a Migrator for applying, reverting and checking migrations.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let migrations = Migrations::from_dir("migrations")?;
+    /// client.migrator(migrations).up().await?;
+    /// ```
+    pub fn migrator(&self, migrations: Migrations) -> Migrator {
+        Migrator::new(Arc::clone(&self.driver), migrations)
+    }
 }
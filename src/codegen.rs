@@ -0,0 +1,764 @@
+//! Rust source generation from an introspected database schema.
+//!
+//! Each PostgreSQL schema becomes one module containing, per table, a
+//! `Table` implementation, a `Columns` accessor struct, one `Column` type per
+//! column and a `Record` struct with the mapped Rust field types. Enum types
+//! become Rust enums convertible to and from their labels.
+//!
+//! Types without a standard library equivalent map to the crates selected in
+//! `CodegenOptions`, matching the `pgrs` features enabled where the code is
+//! compiled, and to `String` otherwise.
+//!
+//! Output is deterministic: tables, columns and enums are emitted in a fixed
+//! order, so regenerated files diff cleanly. Names that would generate the
+//! same Rust identifier, such as the columns `user_id` and `userId`, fail
+//! with `PgRsError::Codegen`.
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+use crate::error::{PgRsError, Result};
+use crate::schema::{ColumnDef, DatabaseSchema, EnumDef, TableDef};
+use crate::types::PgType;
+
+const HEADER: &str = "// @generated by pgrs codegen. Do not edit by hand.\n";
+
+/// A generated source file, relative to the output directory.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GeneratedFile {
+    pub path: String,
+    pub contents: String,
+}
+
+/// The crate used for date and time columns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateTimeCrate {
+    Chrono,
+    Time,
+}
+
+/// Optional crates generated records may use, named like the `pgrs`
+/// features that convert them to and from `SqlValue`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CodegenOptions {
+    /// Map `uuid` to `uuid::Uuid`.
+    pub uuid: bool,
+    /// Map `numeric` to `rust_decimal::Decimal`.
+    pub rust_decimal: bool,
+    /// Map `json` and `jsonb` to `serde_json::Value`.
+    pub serde_json: bool,
+    /// Map date, time, timestamp and interval types to this crate's types.
+    pub date_time: Option<DateTimeCrate>,
+}
+
+impl CodegenOptions {
+    /// Builds options from `pgrs` feature names: `uuid`, `rust_decimal`,
+    /// `serde_json`, `chrono` and `time`.
+    pub fn from_features<'a>(
+        features: impl IntoIterator<Item = &'a str>,
+    ) -> std::result::Result<Self, String> {
+        let mut options = Self::default();
+        for feature in features {
+            let date_time = match feature {
+                "uuid" => {
+                    options.uuid = true;
+                    continue;
+                }
+                "rust_decimal" => {
+                    options.rust_decimal = true;
+                    continue;
+                }
+                "serde_json" => {
+                    options.serde_json = true;
+                    continue;
+                }
+                "chrono" => DateTimeCrate::Chrono,
+                "time" => DateTimeCrate::Time,
+                other => return Err(format!("unknown feature: {}", other)),
+            };
+            if options.date_time.is_some_and(|chosen| chosen != date_time) {
+                return Err("choose one of the chrono and time features".to_string());
+            }
+            options.date_time = Some(date_time);
+        }
+        Ok(options)
+    }
+}
+
+/// Generates one module per schema plus a `mod.rs` declaring them.
+pub fn generate(schema: &DatabaseSchema, options: &CodegenOptions) -> Result<Vec<GeneratedFile>> {
+    let mut tables: BTreeMap<String, Vec<&TableDef>> = BTreeMap::new();
+    let mut enums: BTreeMap<String, Vec<&EnumDef>> = BTreeMap::new();
+
+    for table in &schema.tables {
+        let name = table.schema.clone().unwrap_or_else(|| "public".to_string());
+        tables.entry(name).or_default().push(table);
+    }
+    for enum_def in &schema.enums {
+        let name = enum_def
+            .schema
+            .clone()
+            .unwrap_or_else(|| "public".to_string());
+        enums.entry(name).or_default().push(enum_def);
+    }
+
+    let mut schema_names: Vec<&String> = tables.keys().chain(enums.keys()).collect();
+    schema_names.sort();
+    schema_names.dedup();
+
+    let mut files = Vec::new();
+    let mut mod_rs = String::from(HEADER);
+    mod_rs.push('\n');
+    let mut modules = Names::default();
+
+    for schema_name in schema_names {
+        let module = modules.claim(
+            rust_field_name(schema_name),
+            format!("schema {}", schema_name),
+        )?;
+        writeln!(mod_rs, "pub mod {};", module).unwrap();
+
+        let mut schema_tables = tables.get(schema_name).cloned().unwrap_or_default();
+        schema_tables.sort_by(|a, b| a.name.cmp(&b.name));
+        let mut schema_enums = enums.get(schema_name).cloned().unwrap_or_default();
+        schema_enums.sort_by(|a, b| a.name.cmp(&b.name));
+
+        files.push(GeneratedFile {
+            path: format!("{}.rs", module.trim_start_matches("r#")),
+            contents: generate_module(&schema_tables, &schema_enums, &schema.enums, options)?,
+        });
+    }
+
+    files.push(GeneratedFile {
+        path: "mod.rs".to_string(),
+        contents: mod_rs,
+    });
+    Ok(files)
+}
+
+/// The Rust identifiers generated so far in one namespace, with the
+/// database object each was generated for.
+#[derive(Default)]
+struct Names(BTreeMap<String, String>);
+
+impl Names {
+    /// Records `ident` as generated for `source`, failing if it already was
+    /// for another object.
+    fn claim(&mut self, ident: String, source: String) -> Result<String> {
+        if let Some(existing) = self.0.get(&ident) {
+            return Err(PgRsError::Codegen(format!(
+                "{} and {} both generate `{}`",
+                existing, source, ident
+            )));
+        }
+        self.0.insert(ident.clone(), source);
+        Ok(ident)
+    }
+}
+
+/// Generates the module for one schema.
+/// `all_enums` is used to resolve enum columns that refer to other schemas.
+pub fn generate_module(
+    tables: &[&TableDef],
+    enums: &[&EnumDef],
+    all_enums: &[EnumDef],
+    options: &CodegenOptions,
+) -> Result<String> {
+    let mut out = String::from(HEADER);
+    out.push_str("\n#![allow(dead_code)]\n\n");
+    if !tables.is_empty() {
+        out.push_str("use pgrs::traits::{Column, Table};\n");
+        out.push_str("use pgrs::types::PgType;\n");
+    }
+    if !enums.is_empty() {
+        out.push_str("use pgrs::types::{FromSqlValue, SqlValue};\n");
+    }
+
+    // Enums, tables and column types share the module's namespace
+    let mut types = Names::default();
+
+    for enum_def in enums {
+        out.push('\n');
+        generate_enum(&mut out, enum_def, &mut types)?;
+    }
+
+    for table in tables {
+        out.push('\n');
+        generate_table(&mut out, table, all_enums, options, &mut types)?;
+    }
+
+    Ok(out)
+}
+
+fn generate_enum(out: &mut String, enum_def: &EnumDef, types: &mut Names) -> Result<()> {
+    let display = display_name(&enum_def.schema, &enum_def.name);
+    let ident = types.claim(rust_type_name(&enum_def.name), format!("enum {}", display))?;
+    let mut variant_names = Names::default();
+    let variants: Vec<(String, &str)> = enum_def
+        .labels
+        .iter()
+        .map(|label| {
+            let variant = variant_names.claim(
+                rust_type_name(label),
+                format!("label {:?} of enum {}", label, display),
+            )?;
+            Ok((variant, label.as_str()))
+        })
+        .collect::<Result<_>>()?;
+
+    writeln!(out, "/// PostgreSQL enum `{}`.", display).unwrap();
+    writeln!(out, "#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]").unwrap();
+    writeln!(out, "pub enum {} {{", ident).unwrap();
+    for (variant, _) in &variants {
+        writeln!(out, "    {},", variant).unwrap();
+    }
+    writeln!(out, "}}\n").unwrap();
+
+    writeln!(out, "impl {} {{", ident).unwrap();
+    writeln!(out, "    /// Returns the PostgreSQL label of this value.").unwrap();
+    writeln!(out, "    pub fn as_str(&self) -> &'static str {{").unwrap();
+    writeln!(out, "        match self {{").unwrap();
+    for (variant, label) in &variants {
+        writeln!(out, "            {}::{} => {:?},", ident, variant, label).unwrap();
+    }
+    writeln!(out, "        }}").unwrap();
+    writeln!(out, "    }}").unwrap();
+    writeln!(out, "}}\n").unwrap();
+
+    writeln!(out, "impl std::str::FromStr for {} {{", ident).unwrap();
+    writeln!(out, "    type Err = String;\n").unwrap();
+    writeln!(
+        out,
+        "    fn from_str(s: &str) -> Result<Self, Self::Err> {{"
+    )
+    .unwrap();
+    writeln!(out, "        match s {{").unwrap();
+    for (variant, label) in &variants {
+        writeln!(
+            out,
+            "            {:?} => Ok({}::{}),",
+            label, ident, variant
+        )
+        .unwrap();
+    }
+    writeln!(
+        out,
+        "            other => Err(format!(\"invalid {} label: {{}}\", other)),",
+        enum_def.name
+    )
+    .unwrap();
+    writeln!(out, "        }}").unwrap();
+    writeln!(out, "    }}").unwrap();
+    writeln!(out, "}}\n").unwrap();
+
+    writeln!(out, "impl From<{}> for SqlValue {{", ident).unwrap();
+    writeln!(out, "    fn from(value: {}) -> Self {{", ident).unwrap();
+    writeln!(out, "        SqlValue::Text(value.as_str().to_string())").unwrap();
+    writeln!(out, "    }}").unwrap();
+    writeln!(out, "}}\n").unwrap();
+
+    writeln!(out, "impl FromSqlValue for {} {{", ident).unwrap();
+    writeln!(
+        out,
+        "    fn from_sql_value(value: &SqlValue) -> Option<Self> {{"
+    )
+    .unwrap();
+    writeln!(out, "        match value {{").unwrap();
+    writeln!(out, "            SqlValue::Text(s) => s.parse().ok(),").unwrap();
+    writeln!(out, "            _ => None,").unwrap();
+    writeln!(out, "        }}").unwrap();
+    writeln!(out, "    }}").unwrap();
+    writeln!(out, "}}").unwrap();
+    Ok(())
+}
+
+fn generate_table(
+    out: &mut String,
+    table: &TableDef,
+    all_enums: &[EnumDef],
+    options: &CodegenOptions,
+    types: &mut Names,
+) -> Result<()> {
+    let display = display_name(&table.schema, &table.name);
+    let source = format!("table {}", display);
+    let ident = types.claim(rust_type_name(&table.name), source.clone())?;
+    let columns_ident = types.claim(format!("{}Columns", ident), source.clone())?;
+    let record_ident = types.claim(format!("{}Record", ident), source)?;
+    let mut fields = Names::default();
+    let column_idents: Vec<(String, String)> = table
+        .columns
+        .iter()
+        .map(|c| {
+            let source = format!("column {}.{}", display, c.name);
+            Ok((
+                fields.claim(rust_field_name(&c.name), source.clone())?,
+                types.claim(format!("{}{}", ident, rust_type_name(&c.name)), source)?,
+            ))
+        })
+        .collect::<Result<_>>()?;
+
+    writeln!(out, "/// Table `{}`.", display).unwrap();
+    writeln!(out, "pub struct {};\n", ident).unwrap();
+
+    writeln!(out, "/// Column accessors for `{}`.", display).unwrap();
+    writeln!(out, "pub struct {} {{", columns_ident).unwrap();
+    for (field, column_ident) in &column_idents {
+        writeln!(out, "    pub {}: {},", field, column_ident).unwrap();
+    }
+    writeln!(out, "}}\n").unwrap();
+
+    writeln!(out, "/// A row of `{}`.", display).unwrap();
+    writeln!(out, "#[derive(Debug, Clone, PartialEq)]").unwrap();
+    writeln!(out, "pub struct {} {{", record_ident).unwrap();
+    for (column, (field, _)) in table.columns.iter().zip(&column_idents) {
+        writeln!(
+            out,
+            "    pub {}: {},",
+            field,
+            record_field_type(column, all_enums, options)
+        )
+        .unwrap();
+    }
+    writeln!(out, "}}\n").unwrap();
+
+    writeln!(out, "impl Table for {} {{", ident).unwrap();
+    writeln!(out, "    type Columns = {};\n", columns_ident).unwrap();
+    writeln!(out, "    fn table_name() -> &'static str {{").unwrap();
+    writeln!(out, "        {:?}", table.name).unwrap();
+    writeln!(out, "    }}\n").unwrap();
+    writeln!(out, "    fn schema() -> Option<&'static str> {{").unwrap();
+    writeln!(out, "        {}", option_str(table.schema.as_deref())).unwrap();
+    writeln!(out, "    }}\n").unwrap();
+    writeln!(out, "    fn columns() -> Self::Columns {{").unwrap();
+    writeln!(out, "        {} {{", columns_ident).unwrap();
+    for (field, column_ident) in &column_idents {
+        writeln!(out, "            {}: {},", field, column_ident).unwrap();
+    }
+    writeln!(out, "        }}").unwrap();
+    writeln!(out, "    }}\n").unwrap();
+    writeln!(out, "    fn all_columns() -> Vec<&'static dyn Column> {{").unwrap();
+    let refs: Vec<String> = column_idents
+        .iter()
+        .map(|(_, column_ident)| format!("&{}", column_ident))
+        .collect();
+    // Match rustfmt so generated files are stable under `cargo fmt`
+    let single_line = format!("        vec![{}]", refs.join(", "));
+    if single_line.len() <= 100 {
+        writeln!(out, "{}", single_line).unwrap();
+    } else {
+        writeln!(out, "        vec![").unwrap();
+        for column_ref in &refs {
+            writeln!(out, "            {},", column_ref).unwrap();
+        }
+        writeln!(out, "        ]").unwrap();
+    }
+    writeln!(out, "    }}").unwrap();
+    writeln!(out, "}}").unwrap();
+
+    for (column, (_, column_ident)) in table.columns.iter().zip(&column_idents) {
+        out.push('\n');
+        generate_column(out, table, column, column_ident);
+    }
+    Ok(())
+}
+
+fn generate_column(out: &mut String, table: &TableDef, column: &ColumnDef, column_ident: &str) {
+    writeln!(out, "pub struct {};\n", column_ident).unwrap();
+    writeln!(out, "impl Column for {} {{", column_ident).unwrap();
+    writeln!(out, "    fn column_name(&self) -> &'static str {{").unwrap();
+    writeln!(out, "        {:?}", column.name).unwrap();
+    writeln!(out, "    }}\n").unwrap();
+    writeln!(out, "    fn table_name(&self) -> &'static str {{").unwrap();
+    writeln!(out, "        {:?}", table.name).unwrap();
+    writeln!(out, "    }}\n").unwrap();
+    writeln!(out, "    fn schema_name(&self) -> Option<&'static str> {{").unwrap();
+    writeln!(out, "        {}", option_str(table.schema.as_deref())).unwrap();
+    writeln!(out, "    }}\n").unwrap();
+    writeln!(out, "    fn sql_type(&self) -> Option<PgType> {{").unwrap();
+    writeln!(out, "        Some({})", pg_type_expr(&column.sql_type)).unwrap();
+    writeln!(out, "    }}\n").unwrap();
+    writeln!(out, "    fn is_nullable(&self) -> bool {{").unwrap();
+    writeln!(out, "        {}", column.nullable).unwrap();
+    writeln!(out, "    }}\n").unwrap();
+    writeln!(
+        out,
+        "    fn default_expression(&self) -> Option<&'static str> {{"
+    )
+    .unwrap();
+    writeln!(out, "        {}", option_str(column.default.as_deref())).unwrap();
+    writeln!(out, "    }}\n").unwrap();
+    writeln!(out, "    fn is_primary_key(&self) -> bool {{").unwrap();
+    writeln!(out, "        {}", column.primary_key).unwrap();
+    writeln!(out, "    }}\n").unwrap();
+    writeln!(out, "    fn is_unique(&self) -> bool {{").unwrap();
+    writeln!(out, "        {}", column.unique).unwrap();
+    writeln!(out, "    }}").unwrap();
+    writeln!(out, "}}").unwrap();
+}
+
+/// Maps a PostgreSQL type to the Rust type used for it in generated records.
+/// Types without a native mapping are represented by their text form.
+pub fn rust_type(pg_type: &PgType, enums: &[EnumDef], options: &CodegenOptions) -> String {
+    use DateTimeCrate::{Chrono, Time};
+
+    match (pg_type, options.date_time) {
+        (PgType::Date, Some(Chrono)) => return "chrono::NaiveDate".to_string(),
+        (PgType::Date, Some(Time)) => return "time::Date".to_string(),
+        (PgType::Time, Some(Chrono)) => return "chrono::NaiveTime".to_string(),
+        (PgType::Time, Some(Time)) => return "time::Time".to_string(),
+        (PgType::Timestamp, Some(Chrono)) => return "chrono::NaiveDateTime".to_string(),
+        (PgType::Timestamp, Some(Time)) => return "time::PrimitiveDateTime".to_string(),
+        (PgType::TimestampTz, Some(Chrono)) => return "chrono::DateTime<chrono::Utc>".to_string(),
+        (PgType::TimestampTz, Some(Time)) => return "time::OffsetDateTime".to_string(),
+        (PgType::Interval, Some(Chrono)) => return "chrono::TimeDelta".to_string(),
+        (PgType::Interval, Some(Time)) => return "time::Duration".to_string(),
+        _ => {}
+    }
+
+    match pg_type {
+        PgType::Uuid if options.uuid => "uuid::Uuid".to_string(),
+        PgType::Numeric(_) if options.rust_decimal => "rust_decimal::Decimal".to_string(),
+        PgType::Json | PgType::Jsonb if options.serde_json => "serde_json::Value".to_string(),
+        PgType::SmallInt | PgType::SmallSerial => "i16".to_string(),
+        PgType::Integer | PgType::Serial => "i32".to_string(),
+        PgType::BigInt | PgType::BigSerial => "i64".to_string(),
+        PgType::Real => "f32".to_string(),
+        PgType::DoublePrecision => "f64".to_string(),
+        PgType::Boolean => "bool".to_string(),
+        PgType::Bytea => "Vec<u8>".to_string(),
+        PgType::Array(inner) => format!("Vec<{}>", rust_type(inner, enums, options)),
+        PgType::Custom(name) => match find_enum(name, enums) {
+            Some(enum_def) => rust_type_name(&enum_def.name),
+            None => "String".to_string(),
+        },
+        _ => "String".to_string(),
+    }
+}
+
+fn record_field_type(column: &ColumnDef, enums: &[EnumDef], options: &CodegenOptions) -> String {
+    let rust = rust_type(&column.sql_type, enums, options);
+    if column.nullable {
+        format!("Option<{}>", rust)
+    } else {
+        rust
+    }
+}
+
+/// Finds the enum a type name refers to, accepting schema-qualified and quoted names.
+fn find_enum<'a>(type_name: &str, enums: &'a [EnumDef]) -> Option<&'a EnumDef> {
+    let unquoted = type_name.replace('"', "");
+    let (schema, name) = match unquoted.rsplit_once('.') {
+        Some((schema, name)) => (Some(schema), name),
+        None => (None, unquoted.as_str()),
+    };
+    enums
+        .iter()
+        .find(|e| e.name == name && (schema.is_none() || e.schema.as_deref() == schema))
+}
+
+fn pg_type_expr(pg_type: &PgType) -> String {
+    match pg_type {
+        PgType::Numeric(Some((precision, scale))) => {
+            format!("PgType::Numeric(Some(({}, {})))", precision, scale)
+        }
+        PgType::Numeric(None) => "PgType::Numeric(None)".to_string(),
+        PgType::Varchar(length) => format!("PgType::Varchar({:?})", length),
+        PgType::Char(length) => format!("PgType::Char({:?})", length),
+        PgType::Array(inner) => format!("PgType::Array(Box::new({}))", pg_type_expr(inner)),
+        PgType::Custom(name) => format!("PgType::Custom({:?}.to_string())", name),
+        other => format!("PgType::{:?}", other),
+    }
+}
+
+fn option_str(value: Option<&str>) -> String {
+    match value {
+        Some(value) => format!("Some({:?})", value),
+        None => "None".to_string(),
+    }
+}
+
+fn display_name(schema: &Option<String>, name: &str) -> String {
+    match schema {
+        Some(schema) => format!("{}.{}", schema, name),
+        None => name.to_string(),
+    }
+}
+
+/// Splits an identifier into lowercase words on non-alphanumeric characters
+/// and lower-to-upper case transitions.
+fn words(name: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut current = String::new();
+    let mut previous_lower = false;
+    for ch in name.chars() {
+        if !ch.is_alphanumeric() {
+            if !current.is_empty() {
+                words.push(std::mem::take(&mut current));
+            }
+            previous_lower = false;
+            continue;
+        }
+        if ch.is_uppercase() && previous_lower && !current.is_empty() {
+            words.push(std::mem::take(&mut current));
+        }
+        previous_lower = ch.is_lowercase() || ch.is_ascii_digit();
+        current.extend(ch.to_lowercase());
+    }
+    if !current.is_empty() {
+        words.push(current);
+    }
+    words
+}
+
+fn rust_type_name(name: &str) -> String {
+    let ident: String = words(name)
+        .iter()
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect::<String>(),
+                None => String::new(),
+            }
+        })
+        .collect();
+    match ident.chars().next() {
+        None => "Unnamed".to_string(),
+        Some(first) if first.is_ascii_digit() => format!("_{}", ident),
+        Some(_) => ident,
+    }
+}
+
+fn rust_field_name(name: &str) -> String {
+    let ident = words(name).join("_");
+    match ident.chars().next() {
+        None => "unnamed".to_string(),
+        Some(first) if first.is_ascii_digit() => format!("_{}", ident),
+        Some(_) if ["self", "Self", "super", "crate"].contains(&ident.as_str()) => {
+            format!("{}_", ident)
+        }
+        Some(_) if is_keyword(&ident) => format!("r#{}", ident),
+        Some(_) => ident,
+    }
+}
+
+fn is_keyword(ident: &str) -> bool {
+    const KEYWORDS: &[&str] = &[
+        "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "do",
+        "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "if", "impl", "in", "let",
+        "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref", "return",
+        "static", "struct", "trait", "true", "try", "type", "typeof", "unsafe", "unsized", "use",
+        "virtual", "where", "while", "yield",
+    ];
+    KEYWORDS.contains(&ident)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_schema() -> DatabaseSchema {
+        DatabaseSchema {
+            tables: vec![TableDef {
+                schema: Some("app".to_string()),
+                name: "user_accounts".to_string(),
+                columns: vec![
+                    ColumnDef::new("id", PgType::BigInt)
+                        .not_null()
+                        .primary_key(),
+                    ColumnDef::new("type", PgType::Text).not_null(),
+                    ColumnDef::new("mood", PgType::Custom("app.mood".to_string())),
+                    ColumnDef::new("tags", PgType::Array(Box::new(PgType::Text))),
+                ],
+            }],
+            enums: vec![EnumDef {
+                schema: Some("app".to_string()),
+                name: "mood".to_string(),
+                labels: vec!["happy".to_string(), "very-sad".to_string()],
+            }],
+        }
+    }
+
+    #[test]
+    fn test_identifier_conversion() {
+        assert_eq!(rust_type_name("user_accounts"), "UserAccounts");
+        assert_eq!(rust_type_name("very-sad"), "VerySad");
+        assert_eq!(rust_type_name("HTTPLog"), "Httplog");
+        assert_eq!(rust_field_name("createdAt"), "created_at");
+        assert_eq!(rust_field_name("type"), "r#type");
+        assert_eq!(rust_field_name("2fa"), "_2fa");
+    }
+
+    #[test]
+    fn test_rust_type_mapping() {
+        let schema = sample_schema();
+        assert_eq!(
+            rust_type(
+                &PgType::BigSerial,
+                &schema.enums,
+                &CodegenOptions::default()
+            ),
+            "i64"
+        );
+        assert_eq!(
+            rust_type(
+                &PgType::Array(Box::new(PgType::Integer)),
+                &schema.enums,
+                &CodegenOptions::default()
+            ),
+            "Vec<i32>"
+        );
+        assert_eq!(
+            rust_type(
+                &PgType::Custom("mood".to_string()),
+                &schema.enums,
+                &CodegenOptions::default()
+            ),
+            "Mood"
+        );
+        assert_eq!(
+            rust_type(
+                &PgType::Custom("tsvector".to_string()),
+                &schema.enums,
+                &CodegenOptions::default()
+            ),
+            "String"
+        );
+    }
+
+    #[test]
+    fn test_rust_type_mapping_with_features() {
+        let enums = sample_schema().enums;
+        let options = CodegenOptions::from_features(["uuid", "chrono", "rust_decimal"]).unwrap();
+        assert_eq!(rust_type(&PgType::Uuid, &enums, &options), "uuid::Uuid");
+        assert_eq!(
+            rust_type(&PgType::TimestampTz, &enums, &options),
+            "chrono::DateTime<chrono::Utc>"
+        );
+        assert_eq!(
+            rust_type(&PgType::Numeric(Some((10, 2))), &enums, &options),
+            "rust_decimal::Decimal"
+        );
+        assert_eq!(rust_type(&PgType::Jsonb, &enums, &options), "String");
+
+        let options = CodegenOptions::from_features(["time", "serde_json"]).unwrap();
+        assert_eq!(rust_type(&PgType::Date, &enums, &options), "time::Date");
+        assert_eq!(
+            rust_type(&PgType::Interval, &enums, &options),
+            "time::Duration"
+        );
+        assert_eq!(
+            rust_type(&PgType::Json, &enums, &options),
+            "serde_json::Value"
+        );
+        assert_eq!(rust_type(&PgType::Uuid, &enums, &options), "String");
+
+        assert!(CodegenOptions::from_features(["chrono", "time"]).is_err());
+        assert!(CodegenOptions::from_features(["serde"]).is_err());
+    }
+
+    #[test]
+    fn test_generate_files() {
+        let files = generate(&sample_schema(), &CodegenOptions::default()).unwrap();
+        let paths: Vec<&str> = files.iter().map(|f| f.path.as_str()).collect();
+        assert_eq!(paths, ["app.rs", "mod.rs"]);
+        assert!(files[1].contents.contains("pub mod app;"));
+
+        let module = &files[0].contents;
+        assert!(module.starts_with(HEADER));
+        assert!(module.contains("pub enum Mood {\n    Happy,\n    VerySad,\n}"));
+        assert!(module.contains("Mood::VerySad => \"very-sad\","));
+        assert!(module.contains("impl FromSqlValue for Mood {"));
+        assert!(module.contains("pub struct UserAccounts;"));
+        assert!(module.contains("    pub r#type: UserAccountsType,"));
+        assert!(module.contains("    pub mood: Option<Mood>,"));
+        assert!(module.contains("    pub tags: Option<Vec<String>>,"));
+        assert!(module.contains("Some(PgType::Array(Box::new(PgType::Text)))"));
+        assert!(module.contains("impl Column for UserAccountsId {"));
+    }
+
+    fn collision(schema: &DatabaseSchema) -> String {
+        match generate(schema, &CodegenOptions::default()) {
+            Err(PgRsError::Codegen(message)) => message,
+            other => panic!("Expected Codegen error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_generate_rejects_name_collisions() {
+        let table = |name: &str, columns: &[&str]| TableDef {
+            schema: Some("app".to_string()),
+            name: name.to_string(),
+            columns: columns
+                .iter()
+                .map(|column| ColumnDef::new(*column, PgType::Text))
+                .collect(),
+        };
+        let schema = |tables: Vec<TableDef>, enums: Vec<EnumDef>| DatabaseSchema { tables, enums };
+
+        assert_eq!(
+            collision(&schema(vec![table("users", &["id", "columns"])], vec![])),
+            "table app.users and column app.users.columns both generate `UsersColumns`"
+        );
+        assert_eq!(
+            collision(&schema(vec![table("users", &["record"])], vec![])),
+            "table app.users and column app.users.record both generate `UsersRecord`"
+        );
+        assert_eq!(
+            collision(&schema(
+                vec![table("users", &["user_id", "userId"])],
+                vec![]
+            )),
+            "column app.users.user_id and column app.users.userId both generate `user_id`"
+        );
+        assert_eq!(
+            collision(&schema(
+                vec![
+                    table("user", &["accounts"]),
+                    table("user_accounts", &["id"])
+                ],
+                vec![]
+            )),
+            "column app.user.accounts and table app.user_accounts both generate `UserAccounts`"
+        );
+
+        let mood = EnumDef {
+            schema: Some("app".to_string()),
+            name: "mood".to_string(),
+            labels: vec!["very-sad".to_string(), "very_sad".to_string()],
+        };
+        assert_eq!(
+            collision(&schema(vec![], vec![mood])),
+            "label \"very-sad\" of enum app.mood and label \"very_sad\" of enum app.mood \
+             both generate `VerySad`"
+        );
+
+        let mut schemas = schema(vec![table("a", &["id"])], vec![]);
+        schemas.tables.push(TableDef {
+            schema: Some("App".to_string()),
+            ..table("b", &["id"])
+        });
+        assert_eq!(
+            collision(&schemas),
+            "schema App and schema app both generate `app`"
+        );
+    }
+
+    #[test]
+    fn test_generate_is_deterministic() {
+        let mut reordered = sample_schema();
+        reordered.tables.push(TableDef {
+            schema: Some("app".to_string()),
+            name: "a_first".to_string(),
+            columns: vec![ColumnDef::new("id", PgType::Integer)],
+        });
+        let mut reversed = reordered.clone();
+        reversed.tables.reverse();
+
+        assert_eq!(
+            generate(&reordered, &CodegenOptions::default()).unwrap(),
+            generate(&reversed, &CodegenOptions::default()).unwrap()
+        );
+        let module = &generate(&reordered, &CodegenOptions::default()).unwrap()[0].contents;
+        assert!(module.find("pub struct AFirst;") < module.find("pub struct UserAccounts;"));
+    }
+}
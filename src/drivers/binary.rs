@@ -0,0 +1,647 @@
+//! Text rendering of PostgreSQL's binary formats for values with no exact
+//! Rust counterpart: numerics, dates, times and intervals. The output
+//! matches the server's own text output with `DateStyle` set to ISO and
+//! `TimeZone` set to UTC.
+//!
+//! The inverse, reading such text back into the binary formats, is used to
+//! bind values held as text to placeholders of their own type.
+
+use std::error::Error;
+
+pub(crate) type DecodeResult<T> = std::result::Result<T, Box<dyn Error + Sync + Send>>;
+
+/// Days from 1970-01-01 to 2000-01-01, PostgreSQL's epoch.
+const EPOCH_OFFSET_DAYS: i64 = 10_957;
+const MICROS_PER_SECOND: i64 = 1_000_000;
+const MICROS_PER_DAY: i64 = 86_400 * MICROS_PER_SECOND;
+
+const NUMERIC_NEGATIVE: u16 = 0x4000;
+const NUMERIC_NAN: u16 = 0xC000;
+const NUMERIC_POSITIVE_INFINITY: u16 = 0xD000;
+const NUMERIC_NEGATIVE_INFINITY: u16 = 0xF000;
+
+/// Reads a big-endian integer from the front of `reader`.
+pub(crate) fn read_i16(reader: &mut &[u8]) -> DecodeResult<i16> {
+    Ok(i16::from_be_bytes(take(reader, 2)?.try_into()?))
+}
+
+/// Reads a big-endian integer from the front of `reader`.
+pub(crate) fn read_i32(reader: &mut &[u8]) -> DecodeResult<i32> {
+    Ok(i32::from_be_bytes(take(reader, 4)?.try_into()?))
+}
+
+/// Reads a big-endian integer from the front of `reader`.
+pub(crate) fn read_i64(reader: &mut &[u8]) -> DecodeResult<i64> {
+    Ok(i64::from_be_bytes(take(reader, 8)?.try_into()?))
+}
+
+/// Splits `length` bytes off the front of `reader`.
+pub(crate) fn take<'a>(reader: &mut &'a [u8], length: usize) -> DecodeResult<&'a [u8]> {
+    if reader.len() < length {
+        return Err("truncated value".into());
+    }
+    let (bytes, rest) = reader.split_at(length);
+    *reader = rest;
+    Ok(bytes)
+}
+
+/// Renders a `numeric`: base-10000 digits with a weight and display scale.
+pub(crate) fn numeric_text(raw: &[u8]) -> DecodeResult<String> {
+    let mut reader = raw;
+    let digit_count = usize::try_from(read_i16(&mut reader)?)?;
+    let weight = i64::from(read_i16(&mut reader)?);
+    let sign = read_i16(&mut reader)? as u16;
+    let scale = usize::try_from(read_i16(&mut reader)?)?;
+    let digits = (0..digit_count)
+        .map(|_| read_i16(&mut reader))
+        .collect::<DecodeResult<Vec<i16>>>()?;
+
+    match sign {
+        NUMERIC_NAN => return Ok("NaN".to_string()),
+        NUMERIC_POSITIVE_INFINITY => return Ok("Infinity".to_string()),
+        NUMERIC_NEGATIVE_INFINITY => return Ok("-Infinity".to_string()),
+        _ => {}
+    }
+    // The digit at `index` has weight `weight - index`
+    let digit = |index: i64| {
+        usize::try_from(index)
+            .ok()
+            .and_then(|i| digits.get(i).copied())
+            .unwrap_or(0)
+    };
+
+    let mut text = String::new();
+    if sign == NUMERIC_NEGATIVE {
+        text.push('-');
+    }
+    if weight < 0 {
+        text.push('0');
+    } else {
+        text.push_str(&digit(0).to_string());
+        for index in 1..=weight {
+            text.push_str(&format!("{:04}", digit(index)));
+        }
+    }
+    if scale > 0 {
+        let mut fraction = String::new();
+        let mut index = weight + 1;
+        while fraction.len() < scale {
+            fraction.push_str(&format!("{:04}", digit(index)));
+            index += 1;
+        }
+        fraction.truncate(scale);
+        text.push('.');
+        text.push_str(&fraction);
+    }
+    Ok(text)
+}
+
+/// Renders a `date`, given as days since 2000-01-01.
+pub(crate) fn date_text(days: i32) -> String {
+    match days {
+        i32::MAX => "infinity".to_string(),
+        i32::MIN => "-infinity".to_string(),
+        days => {
+            let (date, before_christ) = civil_date(i64::from(days));
+            if before_christ {
+                format!("{} BC", date)
+            } else {
+                date
+            }
+        }
+    }
+}
+
+/// Renders a `time`, given as microseconds since midnight.
+pub(crate) fn time_text(micros: i64) -> String {
+    let seconds = micros / MICROS_PER_SECOND;
+    format!(
+        "{:02}:{:02}:{:02}{}",
+        seconds / 3600,
+        seconds / 60 % 60,
+        seconds % 60,
+        fraction_text(micros % MICROS_PER_SECOND)
+    )
+}
+
+/// Renders a `timestamp`, given as microseconds since 2000-01-01, followed
+/// by `zone` for a `timestamptz`.
+pub(crate) fn timestamp_text(micros: i64, zone: &str) -> String {
+    match micros {
+        i64::MAX => "infinity".to_string(),
+        i64::MIN => "-infinity".to_string(),
+        micros => {
+            let (date, before_christ) = civil_date(micros.div_euclid(MICROS_PER_DAY));
+            let time = time_text(micros.rem_euclid(MICROS_PER_DAY));
+            let era = if before_christ { " BC" } else { "" };
+            format!("{} {}{}{}", date, time, zone, era)
+        }
+    }
+}
+
+/// Renders an `interval` in PostgreSQL's default style,
+/// e.g. `1 year 2 mons 3 days 04:05:06.5`.
+pub(crate) fn interval_text(micros: i64, days: i32, months: i32) -> String {
+    let mut parts = Vec::new();
+    for (count, unit) in [(months / 12, "year"), (months % 12, "mon"), (days, "day")] {
+        match count {
+            0 => {}
+            1 => parts.push(format!("1 {}", unit)),
+            count => parts.push(format!("{} {}s", count, unit)),
+        }
+    }
+    if micros != 0 || parts.is_empty() {
+        let sign = if micros < 0 { "-" } else { "" };
+        parts.push(format!("{}{}", sign, time_text(micros.abs())));
+    }
+    parts.join(" ")
+}
+
+/// Writes a `numeric` given as decimal text, such as `-12.50` or `NaN`.
+pub(crate) fn write_numeric(text: &str, out: &mut Vec<u8>) -> DecodeResult<()> {
+    let mut header = |digit_count: usize, weight: i64, sign: u16, scale: usize| {
+        out.extend_from_slice(&i16::try_from(digit_count)?.to_be_bytes());
+        out.extend_from_slice(&i16::try_from(weight)?.to_be_bytes());
+        out.extend_from_slice(&sign.to_be_bytes());
+        out.extend_from_slice(&i16::try_from(scale)?.to_be_bytes());
+        DecodeResult::Ok(())
+    };
+    match text {
+        "NaN" => return header(0, 0, NUMERIC_NAN, 0),
+        "Infinity" => return header(0, 0, NUMERIC_POSITIVE_INFINITY, 0),
+        "-Infinity" => return header(0, 0, NUMERIC_NEGATIVE_INFINITY, 0),
+        _ => {}
+    }
+
+    let (negative, unsigned) = split_sign(text);
+    let (whole, fraction) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+    let is_digits = |part: &str| part.bytes().all(|b| b.is_ascii_digit());
+    if (whole.is_empty() && fraction.is_empty()) || !is_digits(whole) || !is_digits(fraction) {
+        return Err(format!("invalid numeric: {:?}", text).into());
+    }
+
+    // Base-10000 digits aligned on the decimal point
+    let padded_whole = format!("{}{}", "0".repeat((4 - whole.len() % 4) % 4), whole);
+    let padded_fraction = format!("{}{}", fraction, "0".repeat((4 - fraction.len() % 4) % 4));
+    let mut digits: Vec<i16> = padded_whole
+        .as_bytes()
+        .chunks(4)
+        .chain(padded_fraction.as_bytes().chunks(4))
+        .map(|chunk| std::str::from_utf8(chunk).map(|group| group.parse().unwrap_or(0)))
+        .collect::<std::result::Result<_, _>>()?;
+    let mut weight = (padded_whole.len() / 4) as i64 - 1;
+    let leading_zeros = digits.iter().take_while(|&&digit| digit == 0).count();
+    digits.drain(..leading_zeros);
+    weight -= leading_zeros as i64;
+    while digits.last() == Some(&0) {
+        digits.pop();
+    }
+
+    let sign = if negative && !digits.is_empty() {
+        NUMERIC_NEGATIVE
+    } else {
+        0
+    };
+    if digits.is_empty() {
+        weight = 0;
+    }
+    header(digits.len(), weight, sign, fraction.len())?;
+    for digit in digits {
+        out.extend_from_slice(&digit.to_be_bytes());
+    }
+    Ok(())
+}
+
+/// Reads a `date` given as `YYYY-MM-DD`, optionally followed by `BC`, as
+/// days since 2000-01-01.
+pub(crate) fn parse_date(text: &str) -> DecodeResult<i32> {
+    match text {
+        "infinity" => return Ok(i32::MAX),
+        "-infinity" => return Ok(i32::MIN),
+        _ => {}
+    }
+    let (date, before_christ) = match text.strip_suffix(" BC") {
+        Some(date) => (date, true),
+        None => (text, false),
+    };
+    Ok(i32::try_from(parse_civil_date(date, before_christ)?)?)
+}
+
+/// Reads a `time` given as `HH:MM:SS.ffffff` as microseconds since midnight.
+pub(crate) fn parse_time(text: &str) -> DecodeResult<i64> {
+    let invalid = || format!("invalid time: {:?}", text);
+    let mut parts = text.split(':');
+    let hours: i64 = parts.next().ok_or_else(invalid)?.parse()?;
+    let minutes: i64 = parts.next().ok_or_else(invalid)?.parse()?;
+    let seconds = match parts.next() {
+        Some(seconds) => parse_seconds(seconds).ok_or_else(invalid)?,
+        None => 0,
+    };
+    let micros = (hours * 60 + minutes) * 60 * MICROS_PER_SECOND + seconds;
+    if parts.next().is_some()
+        || !(0..60).contains(&minutes)
+        || !(0..60 * MICROS_PER_SECOND).contains(&seconds)
+        || !(0..=MICROS_PER_DAY).contains(&micros)
+    {
+        return Err(invalid().into());
+    }
+    Ok(micros)
+}
+
+/// Reads a `timestamp`, or with `zoned` a `timestamptz`, as microseconds
+/// since 2000-01-01 UTC. The date and time may be separated by a space or
+/// `T`; a `timestamptz` needs an offset such as `+02`, `+05:30` or `Z`,
+/// while the offset of a `timestamp` is ignored, as PostgreSQL does.
+pub(crate) fn parse_timestamp(text: &str, zoned: bool) -> DecodeResult<i64> {
+    match text {
+        "infinity" => return Ok(i64::MAX),
+        "-infinity" => return Ok(i64::MIN),
+        _ => {}
+    }
+    let invalid = || format!("invalid timestamp: {:?}", text);
+    let (rest, before_christ) = match text.strip_suffix(" BC") {
+        Some(rest) => (rest, true),
+        None => (text, false),
+    };
+    let (date, rest) = rest.split_once([' ', 'T']).ok_or_else(invalid)?;
+    let (time, offset) = match rest.find(['+', '-', 'Z']) {
+        Some(index) => (rest[..index].trim_end(), Some(&rest[index..])),
+        None => (rest, None),
+    };
+
+    let days = parse_civil_date(date, before_christ)?;
+    let mut micros = days
+        .checked_mul(MICROS_PER_DAY)
+        .and_then(|micros| micros.checked_add(parse_time(time).ok()?))
+        .ok_or_else(invalid)?;
+    match (zoned, offset) {
+        (true, Some(offset)) => {
+            micros -= parse_offset(offset).ok_or_else(invalid)? * MICROS_PER_SECOND
+        }
+        (true, None) => {
+            return Err(format!("timestamp with time zone needs an offset: {:?}", text).into())
+        }
+        (false, _) => {}
+    }
+    Ok(micros)
+}
+
+/// Reads an interval as written by PostgreSQL, such as
+/// `1 year 2 mons -3 days +04:05:06.5`, or as `90.5 seconds`, returning its
+/// months, days and microseconds.
+pub(crate) fn parse_interval(text: &str) -> Option<(i32, i32, i64)> {
+    let (mut months, mut days, mut micros) = (0i32, 0i32, 0i64);
+    let mut tokens = text.split_whitespace();
+    while let Some(token) = tokens.next() {
+        if token.contains(':') {
+            let (negative, clock) = split_sign(token);
+            let clock = parse_clock(clock)?;
+            micros = micros.checked_add(if negative { -clock } else { clock })?;
+            continue;
+        }
+        let unit = tokens.next()?;
+        let unit = unit.strip_suffix('s').unwrap_or(unit);
+        match unit {
+            "year" | "mon" | "month" | "week" | "day" => {
+                let count: i32 = token.parse().ok()?;
+                match unit {
+                    "year" => months = months.checked_add(count.checked_mul(12)?)?,
+                    "mon" | "month" => months = months.checked_add(count)?,
+                    "week" => days = days.checked_add(count.checked_mul(7)?)?,
+                    _ => days = days.checked_add(count)?,
+                }
+            }
+            _ => {
+                let scale = match unit {
+                    "hour" => 3_600,
+                    "min" | "minute" => 60,
+                    "sec" | "second" => 1,
+                    _ => return None,
+                };
+                micros = micros.checked_add(parse_seconds(token)?.checked_mul(scale)?)?;
+            }
+        }
+    }
+    Some((months, days, micros))
+}
+
+/// Reads a UUID in its usual hyphenated form, with or without hyphens.
+pub(crate) fn parse_uuid(text: &str) -> DecodeResult<[u8; 16]> {
+    let hex: Vec<u8> = text.bytes().filter(|&b| b != b'-').collect();
+    if hex.len() != 32 {
+        return Err(format!("invalid uuid: {:?}", text).into());
+    }
+    let mut bytes = [0u8; 16];
+    for (byte, pair) in bytes.iter_mut().zip(hex.chunks(2)) {
+        *byte = u8::from_str_radix(std::str::from_utf8(pair)?, 16)?;
+    }
+    Ok(bytes)
+}
+
+/// Splits a leading `-` or `+` off `text`, returning whether it was `-`.
+fn split_sign(text: &str) -> (bool, &str) {
+    match text.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, text.strip_prefix('+').unwrap_or(text)),
+    }
+}
+
+/// Reads a decimal number of seconds, such as `-1.25`, as microseconds.
+/// Digits beyond microseconds are dropped.
+fn parse_seconds(text: &str) -> Option<i64> {
+    let (negative, digits) = split_sign(text);
+    let (whole, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+    if whole.is_empty() || !fraction.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let fraction = format!("{:0<6}", &fraction[..fraction.len().min(6)]);
+    let micros = whole
+        .parse::<i64>()
+        .ok()?
+        .checked_mul(MICROS_PER_SECOND)?
+        .checked_add(fraction.parse::<i64>().ok()?)?;
+    Some(if negative { -micros } else { micros })
+}
+
+/// Reads the `HH:MM:SS.ffffff` part of an interval, whose hours may exceed a
+/// day, as microseconds.
+fn parse_clock(text: &str) -> Option<i64> {
+    let mut parts = text.split(':');
+    let hours: i64 = parts.next()?.parse().ok()?;
+    let minutes: i64 = parts.next()?.parse().ok()?;
+    let seconds = parse_seconds(parts.next().unwrap_or("0"))?;
+    if parts.next().is_some() || hours < 0 || minutes < 0 || seconds < 0 {
+        return None;
+    }
+    (hours * 60 + minutes)
+        .checked_mul(60 * MICROS_PER_SECOND)?
+        .checked_add(seconds)
+}
+
+/// Reads a UTC offset such as `+02`, `-05:30`, `+0530` or `Z` as seconds.
+fn parse_offset(text: &str) -> Option<i64> {
+    if text == "Z" {
+        return Some(0);
+    }
+    let (negative, digits) = split_sign(text);
+    let (hours, rest) = match digits.split_once(':') {
+        Some((hours, rest)) => (hours, rest),
+        None if digits.len() == 4 => digits.split_at(2),
+        None => (digits, ""),
+    };
+    let mut seconds: i64 = hours.parse::<i64>().ok()? * 3_600;
+    for (part, scale) in rest.split(':').zip([60, 1]) {
+        if !part.is_empty() {
+            seconds += part.parse::<i64>().ok()? * scale;
+        }
+    }
+    Some(if negative { -seconds } else { seconds })
+}
+
+/// Converts `YYYY-MM-DD` to days since 2000-01-01, counting the year
+/// backwards from 1 BC if `before_christ` is set.
+fn parse_civil_date(date: &str, before_christ: bool) -> DecodeResult<i64> {
+    let invalid = || format!("invalid date: {:?}", date);
+    let mut parts = date.splitn(3, '-');
+    let year: i64 = parts.next().ok_or_else(invalid)?.parse()?;
+    let month: i64 = parts.next().ok_or_else(invalid)?.parse()?;
+    let day: i64 = parts.next().ok_or_else(invalid)?.parse()?;
+    let year = if before_christ { 1 - year } else { year };
+    if year < -4713 || !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return Err(invalid().into());
+    }
+
+    // Howard Hinnant's days_from_civil
+    let shifted_year = if month <= 2 { year - 1 } else { year };
+    let era = shifted_year.div_euclid(400);
+    let year_of_era = shifted_year.rem_euclid(400);
+    let shifted_month = (month + 9) % 12;
+    let day_of_year = (153 * shifted_month + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    let days = era * 146_097 + day_of_era - 719_468 - EPOCH_OFFSET_DAYS;
+
+    // Rejects days past the end of the month, such as 2023-02-29
+    if civil_date(days)
+        != (
+            format!("{:04}-{:02}-{:02}", year.max(1 - year), month, day),
+            year <= 0,
+        )
+    {
+        return Err(invalid().into());
+    }
+    Ok(days)
+}
+
+/// Renders `.ffffff` without trailing zeros, or nothing for whole seconds.
+fn fraction_text(micros: i64) -> String {
+    if micros == 0 {
+        return String::new();
+    }
+    let fraction = format!(".{:06}", micros);
+    fraction.trim_end_matches('0').to_string()
+}
+
+/// Converts days since 2000-01-01 to `YYYY-MM-DD` and whether the date is
+/// before the Christian era, which PostgreSQL marks with `BC`.
+fn civil_date(days: i64) -> (String, bool) {
+    // Howard Hinnant's days_from_civil, inverted
+    let z = days + EPOCH_OFFSET_DAYS + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    // There is no year 0: 1 BC precedes 1 AD
+    let (year, before_christ) = if year <= 0 {
+        (1 - year, true)
+    } else {
+        (year, false)
+    };
+    (
+        format!("{:04}-{:02}-{:02}", year, month, day),
+        before_christ,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn numeric(weight: i16, sign: u16, scale: i16, digits: &[i16]) -> Vec<u8> {
+        let mut raw = Vec::new();
+        for field in [digits.len() as i16, weight, sign as i16, scale]
+            .into_iter()
+            .chain(digits.iter().copied())
+        {
+            raw.extend_from_slice(&field.to_be_bytes());
+        }
+        raw
+    }
+
+    #[test]
+    fn test_numeric_text() {
+        // 12345678901.0123456789
+        let raw = numeric(2, 0, 10, &[123, 4567, 8901, 123, 4567, 8900]);
+        assert_eq!(numeric_text(&raw).unwrap(), "12345678901.0123456789");
+        assert_eq!(
+            numeric_text(&numeric(-2, NUMERIC_NEGATIVE, 6, &[1200])).unwrap(),
+            "-0.000012"
+        );
+        assert_eq!(numeric_text(&numeric(1, 0, 0, &[1])).unwrap(), "10000");
+        assert_eq!(numeric_text(&numeric(0, 0, 2, &[])).unwrap(), "0.00");
+        assert_eq!(
+            numeric_text(&numeric(0, NUMERIC_NAN, 0, &[])).unwrap(),
+            "NaN"
+        );
+        assert!(numeric_text(&[0, 1]).is_err());
+    }
+
+    #[test]
+    fn test_date_and_time_text() {
+        assert_eq!(date_text(0), "2000-01-01");
+        assert_eq!(date_text(8796), "2024-01-31");
+        assert_eq!(date_text(-730_119), "0001-01-01");
+        assert_eq!(date_text(-730_120), "0001-12-31 BC");
+        assert_eq!(date_text(i32::MAX), "infinity");
+
+        assert_eq!(time_text(0), "00:00:00");
+        assert_eq!(time_text(52_200_500_000), "14:30:00.5");
+
+        let micros = 8796 * MICROS_PER_DAY + 52_200 * MICROS_PER_SECOND;
+        assert_eq!(timestamp_text(micros, ""), "2024-01-31 14:30:00");
+        assert_eq!(timestamp_text(micros, "+00"), "2024-01-31 14:30:00+00");
+        assert_eq!(timestamp_text(-1, ""), "1999-12-31 23:59:59.999999");
+    }
+
+    #[test]
+    fn test_interval_text() {
+        assert_eq!(interval_text(0, 0, 0), "00:00:00");
+        assert_eq!(
+            interval_text(14_706_500_000, 3, 14),
+            "1 year 2 mons 3 days 04:05:06.5"
+        );
+        assert_eq!(interval_text(-1_250_000, -1, 0), "-1 days -00:00:01.25");
+        assert_eq!(interval_text(0, 1, 1), "1 mon 1 day");
+    }
+
+    #[test]
+    fn test_write_numeric_round_trips() {
+        for text in [
+            "12345678901.0123456789",
+            "-0.000012",
+            "10000",
+            "0.00",
+            "0",
+            "-12.50",
+            "0.5",
+            "NaN",
+            "-Infinity",
+        ] {
+            let mut raw = Vec::new();
+            write_numeric(text, &mut raw).unwrap();
+            assert_eq!(numeric_text(&raw).unwrap(), text);
+        }
+        let mut raw = Vec::new();
+        write_numeric(".5", &mut raw).unwrap();
+        assert_eq!(numeric_text(&raw).unwrap(), "0.5");
+        for invalid in ["", "1e5", "1.2.3", "abc", "-"] {
+            assert!(
+                write_numeric(invalid, &mut Vec::new()).is_err(),
+                "{:?}",
+                invalid
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_date_and_time() {
+        for days in [0, 8796, -730_119, -730_120, 59, 60, i32::MAX] {
+            assert_eq!(parse_date(&date_text(days)).unwrap(), days);
+        }
+        assert!(parse_date("2023-02-29").is_err());
+        assert!(parse_date("2024-13-01").is_err());
+        assert!(parse_date("31/01/2024").is_err());
+
+        assert_eq!(parse_time("14:30:00.5").unwrap(), 52_200_500_000);
+        assert_eq!(parse_time("14:30").unwrap(), 52_200_000_000);
+        assert!(parse_time("14:60:00").is_err());
+        assert!(parse_time("25:00:00").is_err());
+    }
+
+    #[test]
+    fn test_parse_timestamp() {
+        let micros = 8796 * MICROS_PER_DAY + 52_200 * MICROS_PER_SECOND;
+        assert_eq!(
+            parse_timestamp("2024-01-31 14:30:00", false).unwrap(),
+            micros
+        );
+        assert_eq!(
+            parse_timestamp("2024-01-31T14:30:00", false).unwrap(),
+            micros
+        );
+        assert_eq!(
+            parse_timestamp("2024-01-31 14:30:00+00", true).unwrap(),
+            micros
+        );
+        assert_eq!(
+            parse_timestamp("2024-01-31T16:30:00+02:00", true).unwrap(),
+            micros
+        );
+        assert_eq!(
+            parse_timestamp("2024-01-31 09:00:00.0 -05:30:00", true).unwrap(),
+            micros
+        );
+        assert_eq!(
+            parse_timestamp("2024-01-31T14:30:00Z", true).unwrap(),
+            micros
+        );
+        assert_eq!(parse_timestamp(&timestamp_text(-1, ""), false).unwrap(), -1);
+        assert!(parse_timestamp("2024-01-31 14:30:00", true).is_err());
+        assert!(parse_timestamp("2024-01-31", false).is_err());
+    }
+
+    #[test]
+    fn test_parse_interval() {
+        assert_eq!(
+            parse_interval("1 year 2 mons 3 days 04:05:06.5"),
+            Some((14, 3, 14_706_500_000))
+        );
+        assert_eq!(
+            parse_interval("-1 days -00:00:01.25"),
+            Some((0, -1, -1_250_000))
+        );
+        assert_eq!(
+            parse_interval("90.500000 seconds"),
+            Some((0, 0, 90_500_000))
+        );
+        assert_eq!(
+            parse_interval("2 weeks 1 hour"),
+            Some((0, 14, 3_600_000_000))
+        );
+        assert_eq!(parse_interval("3 fortnights"), None);
+        for (micros, days, months) in [(0, 0, 0), (-1_250_000, -1, 0), (14_706_500_000, 3, 14)] {
+            assert_eq!(
+                parse_interval(&interval_text(micros, days, months)),
+                Some((months, days, micros))
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_uuid() {
+        let bytes = parse_uuid("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap();
+        assert_eq!(bytes[..4], [0x67, 0xe5, 0x50, 0x44]);
+        assert_eq!(bytes[15], 0xc8);
+        assert!(parse_uuid("67e55044").is_err());
+        assert!(parse_uuid("zze55044-10b1-426f-9247-bb680e5fe0c8").is_err());
+    }
+}
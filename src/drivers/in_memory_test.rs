@@ -5,7 +5,7 @@ use async_trait::async_trait;
 
 use crate::error::Result;
 use crate::traits::DatabaseDriver;
-use crate::types::{RawQueryResult, SqlValue};
+use crate::types::{RawQueryResult, SqlValue, UNKNOWN_TYPE_OID};
 
 /// A recorded query execution for verification.
 #[derive(Debug, Clone, PartialEq)]
@@ -37,6 +37,7 @@ pub struct InMemoryTestDriver {
     responses: Mutex<VecDeque<RawQueryResult>>,
     recorded_queries: Mutex<Vec<RecordedQuery>>,
     default_response: RawQueryResult,
+    dedicated_count: Mutex<usize>,
 }
 
 impl InMemoryTestDriver {
@@ -46,6 +47,7 @@ impl InMemoryTestDriver {
             responses: Mutex::new(VecDeque::new()),
             recorded_queries: Mutex::new(Vec::new()),
             default_response: RawQueryResult::empty(),
+            dedicated_count: Mutex::new(0),
         }
     }
 
@@ -102,6 +104,12 @@ impl InMemoryTestDriver {
         );
     }
 
+    /// Get the number of dedicated connections opened. Queries on them are
+    /// recorded and answered like any other.
+    pub fn dedicated_count(&self) -> usize {
+        *self.dedicated_count.lock().unwrap()
+    }
+
     /// Assert that exactly n queries were executed.
     pub fn assert_query_count(&self, expected: usize) {
         let actual = self.recorded_queries.lock().unwrap().len();
@@ -138,18 +146,36 @@ impl DatabaseDriver for InMemoryTestDriver {
 
         Ok(response)
     }
+
+    async fn dedicated(&self) -> Result<Box<dyn DatabaseDriver + '_>> {
+        *self.dedicated_count.lock().unwrap() += 1;
+        Ok(Box::new(Dedicated(self)))
+    }
+}
+
+/// A dedicated connection of an `InMemoryTestDriver`, sharing its responses
+/// and recorded queries.
+struct Dedicated<'a>(&'a InMemoryTestDriver);
+
+#[async_trait]
+impl DatabaseDriver for Dedicated<'_> {
+    async fn execute(&self, sql: &str, params: &[SqlValue]) -> Result<RawQueryResult> {
+        self.0.execute(sql, params).await
+    }
 }
 
 /// Builder for creating test responses easily.
 pub struct InMemoryTestResponseBuilder {
     columns: Vec<String>,
-    rows: Vec<Vec<String>>,
+    column_types: Vec<u32>,
+    rows: Vec<Vec<SqlValue>>,
 }
 
 impl InMemoryTestResponseBuilder {
     pub fn new() -> Self {
         Self {
             columns: Vec::new(),
+            column_types: Vec::new(),
             rows: Vec::new(),
         }
     }
@@ -160,16 +186,38 @@ impl InMemoryTestResponseBuilder {
         self
     }
 
+    /// Set the type OIDs of the columns. Columns without one are reported
+    /// as `UNKNOWN_TYPE_OID`.
+    pub fn column_types(mut self, oids: &[u32]) -> Self {
+        self.column_types = oids.to_vec();
+        self
+    }
+
     /// Add a row of string values.
     pub fn row(mut self, values: &[&str]) -> Self {
         self.rows
-            .push(values.iter().map(|s| s.to_string()).collect());
+            .push(values.iter().map(|s| SqlValue::from(*s)).collect());
+        self
+    }
+
+    /// Add a row of string values, where `None` is a NULL cell.
+    pub fn row_opt(mut self, values: &[Option<&str>]) -> Self {
+        self.rows
+            .push(values.iter().map(|s| SqlValue::from(*s)).collect());
+        self
+    }
+
+    /// Add a row of typed values.
+    pub fn typed_row(mut self, values: Vec<SqlValue>) -> Self {
+        self.rows.push(values);
         self
     }
 
     /// Build the RawQueryResult.
-    pub fn build(self) -> RawQueryResult {
-        RawQueryResult::new(self.columns, self.rows)
+    pub fn build(mut self) -> RawQueryResult {
+        self.column_types
+            .resize(self.columns.len(), UNKNOWN_TYPE_OID);
+        RawQueryResult::new(self.columns, self.column_types, self.rows)
     }
 }
 
@@ -1,3 +1,4 @@
+pub(crate) mod binary;
 mod in_memory_test;
 mod tokio_postgres;
 
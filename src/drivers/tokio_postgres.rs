@@ -1,13 +1,21 @@
+use std::error::Error;
+use std::ops::Bound;
+
 use async_trait::async_trait;
-use tokio_postgres::{types::ToSql, Client, NoTls};
+use bytes::BytesMut;
+use tokio_postgres::types::{to_sql_checked, FromSql, IsNull, Kind, ToSql, Type};
+use tokio_postgres::{Client, NoTls};
 
 use crate::error::{PgRsError, Result};
 use crate::traits::DatabaseDriver;
-use crate::types::{RawQueryResult, SqlValue};
+use crate::types::{PgRange, RawQueryResult, SqlValue};
+
+use super::binary::{self, DecodeResult};
 
 /// PostgreSQL driver implementation using tokio-postgres.
 pub struct TokioPostgresDriver {
     client: Client,
+    connection_string: String,
 }
 
 impl TokioPostgresDriver {
@@ -24,7 +32,10 @@ impl TokioPostgresDriver {
             }
         });
 
-        Ok(Self { client })
+        Ok(Self {
+            client,
+            connection_string: connection_string.to_string(),
+        })
     }
 }
 
@@ -40,89 +51,580 @@ impl DatabaseDriver for TokioPostgresDriver {
             .map(|b| b.as_ref() as &(dyn ToSql + Sync))
             .collect();
 
+        // Prepare first, so the columns are known even without rows
+        let statement = self
+            .client
+            .prepare(sql)
+            .await
+            .map_err(|e| PgRsError::QueryFailed(e.to_string()))?;
         let rows = self
             .client
-            .query(sql, &param_refs)
+            .query(&statement, &param_refs)
             .await
             .map_err(|e| PgRsError::QueryFailed(e.to_string()))?;
 
-        // Extract column names
-        let columns: Vec<String> = if rows.is_empty() {
-            Vec::new()
-        } else {
-            rows[0]
-                .columns()
-                .iter()
-                .map(|c| c.name().to_string())
-                .collect()
-        };
+        let columns: Vec<String> = statement
+            .columns()
+            .iter()
+            .map(|c| c.name().to_string())
+            .collect();
+        let column_types: Vec<u32> = statement
+            .columns()
+            .iter()
+            .map(|c| c.type_().oid())
+            .collect();
 
-        // Convert rows to string values
-        let result_rows: Vec<Vec<String>> = rows
+        // Decode each value according to its column's type
+        let result_rows = rows
             .iter()
             .map(|row| {
-                row.columns()
-                    .iter()
-                    .enumerate()
-                    .map(|(i, col)| row_value_to_string(row, i, col.type_()))
-                    .collect()
+                (0..columns.len())
+                    .map(|i| {
+                        row.try_get::<_, Option<Decoded>>(i)
+                            .map(|value| value.map_or(SqlValue::Null, |v| v.0))
+                            .map_err(|e| {
+                                PgRsError::QueryFailed(format!(
+                                    "cannot decode column {}: {}",
+                                    columns[i], e
+                                ))
+                            })
+                    })
+                    .collect::<Result<Vec<SqlValue>>>()
             })
-            .collect();
+            .collect::<Result<Vec<_>>>()?;
 
-        Ok(RawQueryResult::new(columns, result_rows))
+        Ok(RawQueryResult::new(columns, column_types, result_rows))
+    }
+
+    async fn batch_execute(&self, sql: &str) -> Result<()> {
+        self.client
+            .batch_execute(sql)
+            .await
+            .map_err(|e| PgRsError::QueryFailed(e.to_string()))
+    }
+
+    async fn dedicated(&self) -> Result<Box<dyn DatabaseDriver + '_>> {
+        Ok(Box::new(Self::connect(&self.connection_string).await?))
     }
 }
 
 /// Convert a SqlValue to a boxed ToSql trait object.
 fn sql_value_to_tosql(value: &SqlValue) -> Box<dyn ToSql + Sync + Send> {
     match value {
-        SqlValue::Null => Box::new(None::<String>),
-        SqlValue::Text(s) => Box::new(s.clone()),
+        SqlValue::Null => Box::new(NullParam),
+        SqlValue::Text(s) => Box::new(TextParam(s.clone())),
+        SqlValue::Json(s) => Box::new(JsonText(s.clone())),
+        SqlValue::Int16(i) => Box::new(*i),
         SqlValue::Int32(i) => Box::new(*i),
         SqlValue::Int64(i) => Box::new(*i),
+        SqlValue::Float32(f) => Box::new(*f),
+        SqlValue::Float64(f) => Box::new(*f),
         SqlValue::Bool(b) => Box::new(*b),
+        SqlValue::Bytea(b) => Box::new(b.clone()),
+        SqlValue::Numeric(s) => Box::new(LiteralParam::new(s, Type::NUMERIC)),
+        SqlValue::Uuid(s) => Box::new(LiteralParam::new(s, Type::UUID)),
+        SqlValue::Date(s) => Box::new(LiteralParam::new(s, Type::DATE)),
+        SqlValue::Time(s) => Box::new(LiteralParam::new(s, Type::TIME)),
+        SqlValue::Timestamp(s) => Box::new(LiteralParam::new(s, Type::TIMESTAMP)),
+        SqlValue::TimestampTz(s) => Box::new(LiteralParam::new(s, Type::TIMESTAMPTZ)),
+        SqlValue::Interval(s) => Box::new(LiteralParam::new(s, Type::INTERVAL)),
+        SqlValue::Array(values) => Box::new(
+            values
+                .iter()
+                .map(|v| ElementParam(sql_value_to_tosql(v)))
+                .collect::<Vec<_>>(),
+        ),
+        SqlValue::Int4Range(r) => Box::new(RangeParam::new(r, Type::INT4_RANGE, SqlValue::Int32)),
+        SqlValue::Int8Range(r) => Box::new(RangeParam::new(r, Type::INT8_RANGE, SqlValue::Int64)),
+        SqlValue::NumRange(r) => Box::new(RangeParam::new(r, Type::NUM_RANGE, SqlValue::Numeric)),
+        SqlValue::TsRange(r) => Box::new(RangeParam::new(r, Type::TS_RANGE, SqlValue::Timestamp)),
+        SqlValue::TstzRange(r) => {
+            Box::new(RangeParam::new(r, Type::TSTZ_RANGE, SqlValue::TimestampTz))
+        }
+        SqlValue::DateRange(r) => Box::new(RangeParam::new(r, Type::DATE_RANGE, SqlValue::Date)),
+        SqlValue::Raw(bytes) => Box::new(RawParam(bytes.clone())),
     }
 }
 
-/// Convert a row value at a given index to a string.
-fn row_value_to_string(
-    row: &tokio_postgres::Row,
-    index: usize,
-    _type_: &tokio_postgres::types::Type,
-) -> String {
-    // Try common types and convert to string
-    // This is a simplified implementation - a production version would handle more types
+/// A result value decoded according to its column's declared type.
+struct Decoded(SqlValue);
 
-    // Try as i32
-    if let Ok(val) = row.try_get::<_, i32>(index) {
-        return val.to_string();
+impl<'a> FromSql<'a> for Decoded {
+    fn from_sql(ty: &Type, raw: &'a [u8]) -> DecodeResult<Self> {
+        decode(ty, raw).map(Decoded)
     }
 
-    // Try as i64
-    if let Ok(val) = row.try_get::<_, i64>(index) {
-        return val.to_string();
+    fn accepts(_ty: &Type) -> bool {
+        true
+    }
+}
+
+/// Decodes the binary representation of a non-NULL value of type `ty`.
+fn decode(ty: &Type, raw: &[u8]) -> DecodeResult<SqlValue> {
+    match ty.kind() {
+        // Enum values are sent as their label
+        Kind::Enum(_) => return Ok(SqlValue::Text(std::str::from_utf8(raw)?.to_string())),
+        Kind::Array(member) => return decode_array(member, raw),
+        Kind::Range(member) => return decode_range(ty, member, raw),
+        Kind::Domain(base) => return decode(base, raw),
+        _ => {}
     }
 
-    // Try as String
-    if let Ok(val) = row.try_get::<_, String>(index) {
-        return val;
+    let value = match *ty {
+        Type::BOOL => SqlValue::Bool(bool::from_sql(ty, raw)?),
+        Type::INT2 => SqlValue::Int16(i16::from_sql(ty, raw)?),
+        Type::INT4 => SqlValue::Int32(i32::from_sql(ty, raw)?),
+        Type::INT8 => SqlValue::Int64(i64::from_sql(ty, raw)?),
+        Type::OID => SqlValue::Int64(u32::from_sql(ty, raw)?.into()),
+        Type::FLOAT4 => SqlValue::Float32(f32::from_sql(ty, raw)?),
+        Type::FLOAT8 => SqlValue::Float64(f64::from_sql(ty, raw)?),
+        Type::NUMERIC => SqlValue::Numeric(binary::numeric_text(raw)?),
+        Type::BYTEA => SqlValue::Bytea(raw.to_vec()),
+        Type::JSON | Type::JSONB => SqlValue::Json(JsonText::from_sql(ty, raw)?.0),
+        Type::UUID => {
+            let bytes: [u8; 16] = raw.try_into()?;
+            let hex: String = bytes.iter().map(|b| format!("{:02x}", b)).collect();
+            SqlValue::Uuid(format!(
+                "{}-{}-{}-{}-{}",
+                &hex[0..8],
+                &hex[8..12],
+                &hex[12..16],
+                &hex[16..20],
+                &hex[20..32]
+            ))
+        }
+        Type::DATE => SqlValue::Date(binary::date_text(i32::from_sql(ty, raw)?)),
+        Type::TIME => SqlValue::Time(binary::time_text(i64::from_sql(ty, raw)?)),
+        Type::TIMESTAMP => SqlValue::Timestamp(binary::timestamp_text(i64::from_sql(ty, raw)?, "")),
+        // Sent in UTC
+        Type::TIMESTAMPTZ => {
+            SqlValue::TimestampTz(binary::timestamp_text(i64::from_sql(ty, raw)?, "+00"))
+        }
+        Type::INTERVAL => {
+            let mut reader = raw;
+            let micros = binary::read_i64(&mut reader)?;
+            let days = binary::read_i32(&mut reader)?;
+            let months = binary::read_i32(&mut reader)?;
+            SqlValue::Interval(binary::interval_text(micros, days, months))
+        }
+        // The single-byte "char" used by the system catalogs
+        Type::CHAR => SqlValue::Text(std::str::from_utf8(raw)?.to_string()),
+        _ if <String as FromSql>::accepts(ty) => SqlValue::Text(String::from_sql(ty, raw)?),
+        _ => SqlValue::Raw(raw.to_vec()),
+    };
+    Ok(value)
+}
+
+/// Decodes an array, nesting arrays for each dimension after the first.
+fn decode_array(member: &Type, raw: &[u8]) -> DecodeResult<SqlValue> {
+    let mut reader = raw;
+    let dimension_count = binary::read_i32(&mut reader)?;
+    let _has_nulls = binary::read_i32(&mut reader)?;
+    let _element_oid = binary::read_i32(&mut reader)?;
+
+    let mut dimensions = Vec::new();
+    for _ in 0..dimension_count {
+        let length = binary::read_i32(&mut reader)?;
+        let _lower_bound = binary::read_i32(&mut reader)?;
+        dimensions.push(usize::try_from(length)?);
     }
 
-    // Try as bool
-    if let Ok(val) = row.try_get::<_, bool>(index) {
-        return val.to_string();
+    let element_count = if dimensions.is_empty() {
+        0
+    } else {
+        dimensions.iter().product()
+    };
+    let mut elements = Vec::with_capacity(element_count);
+    for _ in 0..element_count {
+        let length = binary::read_i32(&mut reader)?;
+        if length < 0 {
+            elements.push(SqlValue::Null);
+            continue;
+        }
+        let element = binary::take(&mut reader, usize::try_from(length)?)?;
+        elements.push(decode(member, element)?);
     }
 
-    // Try as f64
-    if let Ok(val) = row.try_get::<_, f64>(index) {
-        return val.to_string();
+    Ok(nest_dimensions(&dimensions, elements))
+}
+
+/// Groups elements into nested arrays, one level per dimension.
+fn nest_dimensions(dimensions: &[usize], elements: Vec<SqlValue>) -> SqlValue {
+    match dimensions {
+        [] | [_] => SqlValue::Array(elements),
+        [_, inner @ ..] => {
+            let chunk = inner.iter().product::<usize>().max(1);
+            let mut elements = elements.into_iter();
+            let mut nested = Vec::new();
+            loop {
+                let part: Vec<SqlValue> = elements.by_ref().take(chunk).collect();
+                if part.is_empty() {
+                    break;
+                }
+                nested.push(nest_dimensions(inner, part));
+            }
+            SqlValue::Array(nested)
+        }
+    }
+}
+
+/// Flags that begin the binary representation of a range.
+const RANGE_EMPTY: u8 = 0x01;
+const RANGE_LOWER_INCLUSIVE: u8 = 0x02;
+const RANGE_UPPER_INCLUSIVE: u8 = 0x04;
+const RANGE_LOWER_UNBOUNDED: u8 = 0x08;
+const RANGE_UPPER_UNBOUNDED: u8 = 0x10;
+
+/// Decodes a range of one of the built-in range types. Other ranges are
+/// returned as `SqlValue::Raw`.
+fn decode_range(ty: &Type, member: &Type, raw: &[u8]) -> DecodeResult<SqlValue> {
+    let Some((&flags, mut reader)) = raw.split_first() else {
+        return Err("truncated range".into());
+    };
+
+    let mut read_bound = |unbounded: u8, inclusive: u8| -> DecodeResult<Bound<SqlValue>> {
+        if flags & unbounded != 0 {
+            return Ok(Bound::Unbounded);
+        }
+        let length = usize::try_from(binary::read_i32(&mut reader)?)?;
+        let bound = decode(member, binary::take(&mut reader, length)?)?;
+        Ok(if flags & inclusive != 0 {
+            Bound::Included(bound)
+        } else {
+            Bound::Excluded(bound)
+        })
+    };
+    let bounds = if flags & RANGE_EMPTY != 0 {
+        None
+    } else {
+        let lower = read_bound(RANGE_LOWER_UNBOUNDED, RANGE_LOWER_INCLUSIVE)?;
+        let upper = read_bound(RANGE_UPPER_UNBOUNDED, RANGE_UPPER_INCLUSIVE)?;
+        Some((lower, upper))
+    };
+
+    let value = match *ty {
+        Type::INT4_RANGE => SqlValue::Int4Range(typed_range(bounds, |v| match v {
+            SqlValue::Int32(i) => Some(i),
+            _ => None,
+        })?),
+        Type::INT8_RANGE => SqlValue::Int8Range(typed_range(bounds, |v| match v {
+            SqlValue::Int64(i) => Some(i),
+            _ => None,
+        })?),
+        Type::NUM_RANGE => SqlValue::NumRange(typed_range(bounds, text_bound)?),
+        Type::TS_RANGE => SqlValue::TsRange(typed_range(bounds, text_bound)?),
+        Type::TSTZ_RANGE => SqlValue::TstzRange(typed_range(bounds, text_bound)?),
+        Type::DATE_RANGE => SqlValue::DateRange(typed_range(bounds, text_bound)?),
+        _ => SqlValue::Raw(raw.to_vec()),
+    };
+    Ok(value)
+}
+
+/// Builds a range from decoded bounds, converting each with `convert`.
+fn typed_range<T>(
+    bounds: Option<(Bound<SqlValue>, Bound<SqlValue>)>,
+    convert: impl Fn(SqlValue) -> Option<T>,
+) -> DecodeResult<PgRange<T>> {
+    let Some((lower, upper)) = bounds else {
+        return Ok(PgRange::Empty);
+    };
+    let convert_bound = |bound: Bound<SqlValue>| -> DecodeResult<Bound<T>> {
+        Ok(match bound {
+            Bound::Included(v) => Bound::Included(convert(v).ok_or("unexpected range bound")?),
+            Bound::Excluded(v) => Bound::Excluded(convert(v).ok_or("unexpected range bound")?),
+            Bound::Unbounded => Bound::Unbounded,
+        })
+    };
+    Ok(PgRange::new(convert_bound(lower)?, convert_bound(upper)?))
+}
+
+/// The text of a bound of a range whose bounds are kept as text.
+fn text_bound(value: SqlValue) -> Option<String> {
+    match value {
+        SqlValue::Null | SqlValue::Raw(_) => None,
+        value => Some(value.to_string()),
+    }
+}
+
+/// A text parameter that can also be bound to enum-typed placeholders,
+/// whose binary representation is the label itself.
+#[derive(Debug)]
+struct TextParam(String);
+
+impl ToSql for TextParam {
+    fn to_sql(
+        &self,
+        ty: &Type,
+        out: &mut BytesMut,
+    ) -> std::result::Result<IsNull, Box<dyn Error + Sync + Send>> {
+        match ty.kind() {
+            Kind::Enum(_) => {
+                out.extend_from_slice(self.0.as_bytes());
+                Ok(IsNull::No)
+            }
+            _ => self.0.to_sql(ty, out),
+        }
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        matches!(ty.kind(), Kind::Enum(_)) || <String as ToSql>::accepts(ty)
+    }
+
+    to_sql_checked!();
+}
+
+/// A value held as text, such as a `uuid` or `timestamptz`. It is bound in
+/// the binary format of its type to a placeholder of that type, and as text
+/// to a text placeholder, which builder SQL casts with `$n::text::type`.
+#[derive(Debug)]
+struct LiteralParam {
+    text: String,
+    ty: Type,
+}
+
+impl LiteralParam {
+    fn new(text: &str, ty: Type) -> Self {
+        Self {
+            text: text.to_string(),
+            ty,
+        }
+    }
+}
+
+impl ToSql for LiteralParam {
+    fn to_sql(
+        &self,
+        ty: &Type,
+        out: &mut BytesMut,
+    ) -> std::result::Result<IsNull, Box<dyn Error + Sync + Send>> {
+        if <String as ToSql>::accepts(ty) {
+            return self.text.to_sql(ty, out);
+        }
+        if *ty != self.ty {
+            return Err(format!("cannot bind a {} value to a {} placeholder", self.ty, ty).into());
+        }
+        let text = self.text.trim();
+        match *ty {
+            Type::NUMERIC => {
+                let mut raw = Vec::new();
+                binary::write_numeric(text, &mut raw)?;
+                out.extend_from_slice(&raw);
+            }
+            Type::UUID => out.extend_from_slice(&binary::parse_uuid(text)?),
+            Type::DATE => out.extend_from_slice(&binary::parse_date(text)?.to_be_bytes()),
+            Type::TIME => out.extend_from_slice(&binary::parse_time(text)?.to_be_bytes()),
+            Type::TIMESTAMP => {
+                out.extend_from_slice(&binary::parse_timestamp(text, false)?.to_be_bytes())
+            }
+            Type::TIMESTAMPTZ => {
+                out.extend_from_slice(&binary::parse_timestamp(text, true)?.to_be_bytes())
+            }
+            Type::INTERVAL => {
+                let (months, days, micros) = binary::parse_interval(text)
+                    .ok_or_else(|| format!("invalid interval: {:?}", text))?;
+                out.extend_from_slice(&micros.to_be_bytes());
+                out.extend_from_slice(&days.to_be_bytes());
+                out.extend_from_slice(&months.to_be_bytes());
+            }
+            _ => return Err(format!("cannot bind a {} value", ty).into()),
+        }
+        Ok(IsNull::No)
+    }
+
+    // Checked against the value's own type in `to_sql`
+    fn accepts(_ty: &Type) -> bool {
+        true
+    }
+
+    to_sql_checked!();
+}
+
+/// A range, bound in the binary format of its range type to a placeholder
+/// of that type, and as a literal to a text placeholder.
+#[derive(Debug)]
+struct RangeParam {
+    range: PgRange<SqlValue>,
+    literal: String,
+    ty: Type,
+}
+
+impl RangeParam {
+    fn new<T: Clone + std::fmt::Display>(
+        range: &PgRange<T>,
+        ty: Type,
+        bound: impl Fn(T) -> SqlValue,
+    ) -> Self {
+        let values = match range {
+            PgRange::Empty => PgRange::Empty,
+            PgRange::Bounds(lower, upper) => {
+                PgRange::new(lower.clone().map(&bound), upper.clone().map(&bound))
+            }
+        };
+        Self {
+            range: values,
+            literal: range.to_string(),
+            ty,
+        }
+    }
+}
+
+impl ToSql for RangeParam {
+    fn to_sql(
+        &self,
+        ty: &Type,
+        out: &mut BytesMut,
+    ) -> std::result::Result<IsNull, Box<dyn Error + Sync + Send>> {
+        if <String as ToSql>::accepts(ty) {
+            return self.literal.to_sql(ty, out);
+        }
+        let (true, Kind::Range(member)) = (*ty == self.ty, ty.kind()) else {
+            return Err(format!("cannot bind a {} value to a {} placeholder", self.ty, ty).into());
+        };
+
+        let (lower, upper) = match &self.range {
+            PgRange::Empty => {
+                out.extend_from_slice(&[RANGE_EMPTY]);
+                return Ok(IsNull::No);
+            }
+            PgRange::Bounds(lower, upper) => (lower, upper),
+        };
+        let mut flags = 0;
+        for (bound, unbounded, inclusive) in [
+            (lower, RANGE_LOWER_UNBOUNDED, RANGE_LOWER_INCLUSIVE),
+            (upper, RANGE_UPPER_UNBOUNDED, RANGE_UPPER_INCLUSIVE),
+        ] {
+            flags |= match bound {
+                Bound::Included(_) => inclusive,
+                Bound::Excluded(_) => 0,
+                Bound::Unbounded => unbounded,
+            };
+        }
+        out.extend_from_slice(&[flags]);
+        for bound in [lower, upper] {
+            if let Bound::Included(value) | Bound::Excluded(value) = bound {
+                // Each bound is prefixed with its length
+                let mut element = BytesMut::new();
+                sql_value_to_tosql(value).to_sql_checked(member, &mut element)?;
+                out.extend_from_slice(&i32::try_from(element.len())?.to_be_bytes());
+                out.extend_from_slice(&element);
+            }
+        }
+        Ok(IsNull::No)
+    }
+
+    // Checked against the range's own type in `to_sql`
+    fn accepts(_ty: &Type) -> bool {
+        true
+    }
+
+    to_sql_checked!();
+}
+
+/// The text of a JSON document, bound to and read from `json` and `jsonb`.
+#[derive(Debug)]
+struct JsonText(String);
+
+/// The version byte that prefixes the binary representation of `jsonb`.
+const JSONB_VERSION: u8 = 1;
+
+impl ToSql for JsonText {
+    fn to_sql(
+        &self,
+        ty: &Type,
+        out: &mut BytesMut,
+    ) -> std::result::Result<IsNull, Box<dyn Error + Sync + Send>> {
+        if *ty == Type::JSONB {
+            out.extend_from_slice(&[JSONB_VERSION]);
+        }
+        out.extend_from_slice(self.0.as_bytes());
+        Ok(IsNull::No)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        matches!(*ty, Type::JSON | Type::JSONB)
+    }
+
+    to_sql_checked!();
+}
+
+impl<'a> FromSql<'a> for JsonText {
+    fn from_sql(
+        ty: &Type,
+        mut raw: &'a [u8],
+    ) -> std::result::Result<Self, Box<dyn Error + Sync + Send>> {
+        if *ty == Type::JSONB {
+            match raw.split_first() {
+                Some((&JSONB_VERSION, rest)) => raw = rest,
+                _ => return Err("unsupported jsonb encoding version".into()),
+            }
+        }
+        Ok(JsonText(std::str::from_utf8(raw)?.to_string()))
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        matches!(*ty, Type::JSON | Type::JSONB)
+    }
+}
+
+/// A NULL parameter, accepted for a placeholder of any type.
+#[derive(Debug)]
+struct NullParam;
+
+impl ToSql for NullParam {
+    fn to_sql(
+        &self,
+        _ty: &Type,
+        _out: &mut BytesMut,
+    ) -> std::result::Result<IsNull, Box<dyn Error + Sync + Send>> {
+        Ok(IsNull::Yes)
+    }
+
+    fn accepts(_ty: &Type) -> bool {
+        true
+    }
+
+    to_sql_checked!();
+}
+
+/// An array element. Elements are type-checked individually against the
+/// array's member type, since an array of `SqlValue`s has no single Rust type.
+#[derive(Debug)]
+struct ElementParam(Box<dyn ToSql + Sync + Send>);
+
+impl ToSql for ElementParam {
+    fn to_sql(
+        &self,
+        ty: &Type,
+        out: &mut BytesMut,
+    ) -> std::result::Result<IsNull, Box<dyn Error + Sync + Send>> {
+        self.0.to_sql_checked(ty, out)
+    }
+
+    fn accepts(_ty: &Type) -> bool {
+        true
+    }
+
+    to_sql_checked!();
+}
+
+/// A value of a type with no `SqlValue` variant, sent in the binary
+/// representation it was read in.
+#[derive(Debug)]
+struct RawParam(Vec<u8>);
+
+impl ToSql for RawParam {
+    fn to_sql(
+        &self,
+        _ty: &Type,
+        out: &mut BytesMut,
+    ) -> std::result::Result<IsNull, Box<dyn Error + Sync + Send>> {
+        out.extend_from_slice(&self.0);
+        Ok(IsNull::No)
     }
 
-    // Try as Option<String> for NULL handling
-    if let Ok(val) = row.try_get::<_, Option<String>>(index) {
-        return val.unwrap_or_else(|| "NULL".to_string());
+    fn accepts(_ty: &Type) -> bool {
+        true
     }
 
-    // Fallback
-    "UNKNOWN".to_string()
+    to_sql_checked!();
 }
@@ -0,0 +1,218 @@
+//! Tables and columns known only at runtime.
+//!
+//! `DynTable` and `DynColumn` hold their names as owned strings, so queries
+//! can be built against tables chosen while the program runs. Names are
+//! validated on construction and quoted when rendered, so they can never
+//! alter the shape of the generated SQL. When names come from user input,
+//! build them through `DatabaseSchema::dyn_table` and
+//! `DatabaseSchema::dyn_column` to also restrict them to tables and columns
+//! that exist.
+
+use crate::error::{PgRsError, Result};
+use crate::schema::DatabaseSchema;
+use crate::traits::{Column, TableSource};
+use crate::types::PgType;
+
+/// Longest identifier PostgreSQL keeps; longer names are silently truncated.
+const MAX_IDENTIFIER_LEN: usize = 63;
+
+/// A table identified at runtime. Usable wherever a `TableSource` is accepted.
+///
+/// # Example
+/// ```
+/// use pgrs::dynamic::DynTable;
+/// use pgrs::traits::{Column, TableSource};
+///
+/// let table = DynTable::new("audit_log").unwrap().schema("Reporting").unwrap();
+/// assert_eq!(table.quoted_name(), "\"Reporting\".audit_log");
+///
+/// let column = table.column("user").unwrap();
+/// assert_eq!(column.qualified_name(), "\"Reporting\".audit_log.\"user\"");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct DynTable {
+    schema: Option<String>,
+    name: String,
+}
+
+impl DynTable {
+    /// Creates a table reference, validating the name.
+    pub fn new(name: impl Into<String>) -> Result<Self> {
+        Ok(Self {
+            schema: None,
+            name: validate(name.into())?,
+        })
+    }
+
+    /// Sets the table's schema, validating the name.
+    pub fn schema(mut self, schema: impl Into<String>) -> Result<Self> {
+        self.schema = Some(validate(schema.into())?);
+        Ok(self)
+    }
+
+    /// Creates a reference to a column of this table, validating the name.
+    pub fn column(&self, name: impl Into<String>) -> Result<DynColumn> {
+        Ok(DynColumn {
+            schema: self.schema.clone(),
+            table: self.name.clone(),
+            name: validate(name.into())?,
+            sql_type: None,
+            nullable: true,
+        })
+    }
+}
+
+impl TableSource for DynTable {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn schema_name(&self) -> Option<&str> {
+        self.schema.as_deref()
+    }
+}
+
+/// A column identified at runtime. Usable wherever a `Column` is accepted.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DynColumn {
+    schema: Option<String>,
+    table: String,
+    name: String,
+    sql_type: Option<PgType>,
+    nullable: bool,
+}
+
+impl Column for DynColumn {
+    fn column_name(&self) -> &str {
+        &self.name
+    }
+
+    fn table_name(&self) -> &str {
+        &self.table
+    }
+
+    fn schema_name(&self) -> Option<&str> {
+        self.schema.as_deref()
+    }
+
+    fn sql_type(&self) -> Option<PgType> {
+        self.sql_type.clone()
+    }
+
+    fn is_nullable(&self) -> bool {
+        self.nullable
+    }
+}
+
+impl DatabaseSchema {
+    /// Returns a `DynTable` for a table that exists in this schema.
+    ///
+    /// Use this with introspected metadata as an allowlist for table names
+    /// that come from user input.
+    pub fn dyn_table(&self, schema: Option<&str>, name: &str) -> Result<DynTable> {
+        let table = self.table(schema, name).ok_or_else(|| {
+            PgRsError::TableNotFound(match schema {
+                Some(schema) => format!("{}.{}", schema, name),
+                None => name.to_string(),
+            })
+        })?;
+        let dyn_table = DynTable::new(table.name.clone())?;
+        match &table.schema {
+            Some(schema) => dyn_table.schema(schema.clone()),
+            None => Ok(dyn_table),
+        }
+    }
+
+    /// Returns a `DynColumn` for a column that exists in the given table,
+    /// carrying the column's type and nullability.
+    pub fn dyn_column(&self, table: &DynTable, name: &str) -> Result<DynColumn> {
+        let not_found = || PgRsError::ColumnNotFound(format!("{}.{}", table.name, name));
+        let table_def = self
+            .table(table.schema.as_deref(), &table.name)
+            .ok_or_else(not_found)?;
+        let column_def = table_def.column(name).ok_or_else(not_found)?;
+
+        let mut column = table.column(column_def.name.clone())?;
+        column.sql_type = Some(column_def.sql_type.clone());
+        column.nullable = column_def.nullable;
+        Ok(column)
+    }
+}
+
+fn validate(name: String) -> Result<String> {
+    if name.is_empty() || name.len() > MAX_IDENTIFIER_LEN || name.contains('\0') {
+        return Err(PgRsError::InvalidIdentifier(name));
+    }
+    Ok(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::{ColumnDef, TableDef};
+
+    fn schema() -> DatabaseSchema {
+        DatabaseSchema {
+            tables: vec![TableDef {
+                schema: Some("app".to_string()),
+                name: "users".to_string(),
+                columns: vec![ColumnDef::new("email", PgType::Text).not_null()],
+            }],
+            enums: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_dyn_column_quotes_names() {
+        let table = DynTable::new("users").unwrap();
+        let column = table.column("name\"; DROP TABLE users; --").unwrap();
+        assert_eq!(
+            column.qualified_name(),
+            "users.\"name\"\"; DROP TABLE users; --\""
+        );
+    }
+
+    #[test]
+    fn test_invalid_identifiers() {
+        let too_long = "a".repeat(64);
+        for name in ["", "a\0b", too_long.as_str()] {
+            match DynTable::new(name) {
+                Err(PgRsError::InvalidIdentifier(_)) => {}
+                other => panic!("Expected InvalidIdentifier for {:?}, got {:?}", name, other),
+            }
+        }
+        assert!(DynTable::new("a".repeat(63)).is_ok());
+        assert!(DynTable::new("users").unwrap().column("").is_err());
+        assert!(DynTable::new("users").unwrap().schema("").is_err());
+    }
+
+    #[test]
+    fn test_allowlist() {
+        let schema = schema();
+        let users = schema.dyn_table(Some("app"), "users").unwrap();
+        assert_eq!(users.quoted_name(), "app.users");
+
+        let email = schema.dyn_column(&users, "email").unwrap();
+        assert_eq!(email.qualified_name(), "app.users.email");
+        assert_eq!(email.sql_type(), Some(PgType::Text));
+        assert!(!email.is_nullable());
+
+        assert!(matches!(
+            schema.dyn_table(None, "users"),
+            Err(PgRsError::TableNotFound(_))
+        ));
+        assert!(matches!(
+            schema.dyn_table(Some("app"), "pg_shadow"),
+            Err(PgRsError::TableNotFound(_))
+        ));
+        assert!(matches!(
+            schema.dyn_column(&users, "password"),
+            Err(PgRsError::ColumnNotFound(_))
+        ));
+        let unlisted = DynTable::new("secrets").unwrap();
+        assert!(matches!(
+            schema.dyn_column(&unlisted, "email"),
+            Err(PgRsError::ColumnNotFound(_))
+        ));
+    }
+}
@@ -15,11 +15,50 @@ pub enum PgRsError {
     #[error("Column not found: {0}")]
     ColumnNotFound(String),
 
+    #[error("Column {0} is NULL, use get_opt to read nullable columns")]
+    UnexpectedNull(String),
+
+    #[error("Cannot convert column {column} to {target}")]
+    ConversionFailed {
+        column: String,
+        target: &'static str,
+    },
+
+    #[error("Table not found: {0}")]
+    TableNotFound(String),
+
+    #[error("Invalid identifier: {0}")]
+    InvalidIdentifier(String),
+
+    #[error("Comparison with NULL never matches, use is_null or is_not_null: {0}")]
+    NullComparison(String),
+
     #[error("No columns specified in query")]
     NoColumnsSpecified,
 
     #[error("No table specified in query")]
     NoTableSpecified,
+
+    #[error("No assignments specified in UPDATE")]
+    NoAssignmentsSpecified,
+
+    #[error("Column has no SQL type: {0}")]
+    MissingColumnType(String),
+
+    #[error("Schema drift detected: {0}")]
+    SchemaDrift(String),
+
+    #[error("Invalid schema snapshot: {0}")]
+    InvalidSnapshot(String),
+
+    #[error("Migration error: {0}")]
+    Migration(String),
+
+    #[error("Code generation failed: {0}")]
+    Codegen(String),
+
+    #[error("No actions specified in ALTER TABLE")]
+    NoAlterActionsSpecified,
 }
 
 /// Result type alias for pgrs operations
@@ -0,0 +1,293 @@
+//! Quoting of SQL identifiers.
+//!
+//! Identifiers are quoted only when PostgreSQL would not read them back
+//! unchanged: when they contain anything other than lowercase letters,
+//! digits and underscores, start with a digit, or are keywords that cannot
+//! be used as bare names. This matches PostgreSQL's `quote_ident`, so the
+//! common case of plain lowercase names renders without quotes.
+
+use std::borrow::Cow;
+
+use crate::error::{PgRsError, Result};
+
+/// Keywords PostgreSQL does not accept as bare identifiers in every
+/// position: the reserved, column-name and type/function-name categories of
+/// its keyword list. Sorted for binary search.
+const KEYWORDS: &[&str] = &[
+    "all",
+    "analyse",
+    "analyze",
+    "and",
+    "any",
+    "array",
+    "as",
+    "asc",
+    "asymmetric",
+    "authorization",
+    "between",
+    "bigint",
+    "binary",
+    "bit",
+    "boolean",
+    "both",
+    "case",
+    "cast",
+    "char",
+    "character",
+    "check",
+    "coalesce",
+    "collate",
+    "collation",
+    "column",
+    "concurrently",
+    "constraint",
+    "create",
+    "cross",
+    "current_catalog",
+    "current_date",
+    "current_role",
+    "current_schema",
+    "current_time",
+    "current_timestamp",
+    "current_user",
+    "dec",
+    "decimal",
+    "default",
+    "deferrable",
+    "desc",
+    "distinct",
+    "do",
+    "else",
+    "end",
+    "except",
+    "exists",
+    "extract",
+    "false",
+    "fetch",
+    "float",
+    "for",
+    "foreign",
+    "freeze",
+    "from",
+    "full",
+    "grant",
+    "greatest",
+    "group",
+    "grouping",
+    "having",
+    "ilike",
+    "in",
+    "initially",
+    "inner",
+    "inout",
+    "int",
+    "integer",
+    "intersect",
+    "interval",
+    "into",
+    "is",
+    "isnull",
+    "join",
+    "json",
+    "json_array",
+    "json_arrayagg",
+    "json_exists",
+    "json_object",
+    "json_objectagg",
+    "json_query",
+    "json_scalar",
+    "json_serialize",
+    "json_table",
+    "json_value",
+    "lateral",
+    "leading",
+    "least",
+    "left",
+    "like",
+    "limit",
+    "localtime",
+    "localtimestamp",
+    "merge_action",
+    "national",
+    "natural",
+    "nchar",
+    "none",
+    "normalize",
+    "not",
+    "notnull",
+    "null",
+    "nullif",
+    "numeric",
+    "offset",
+    "on",
+    "only",
+    "or",
+    "order",
+    "out",
+    "outer",
+    "overlaps",
+    "overlay",
+    "placing",
+    "position",
+    "precision",
+    "primary",
+    "real",
+    "references",
+    "returning",
+    "right",
+    "row",
+    "select",
+    "session_user",
+    "setof",
+    "similar",
+    "smallint",
+    "some",
+    "substring",
+    "symmetric",
+    "system_user",
+    "table",
+    "tablesample",
+    "then",
+    "time",
+    "timestamp",
+    "to",
+    "trailing",
+    "treat",
+    "trim",
+    "true",
+    "union",
+    "unique",
+    "user",
+    "using",
+    "values",
+    "varchar",
+    "variadic",
+    "verbose",
+    "when",
+    "where",
+    "window",
+    "with",
+    "xmlattributes",
+    "xmlconcat",
+    "xmlelement",
+    "xmlexists",
+    "xmlforest",
+    "xmlnamespaces",
+    "xmlparse",
+    "xmlpi",
+    "xmlroot",
+    "xmlserialize",
+    "xmltable",
+];
+
+/// Quotes an identifier if PostgreSQL would not read it back unchanged,
+/// doubling any embedded double quotes.
+///
+/// An empty `ident` renders as `""`, which PostgreSQL rejects when the
+/// statement runs. `DynTable`, `ColumnRef` and the DDL builders reject empty
+/// names before rendering.
+///
+/// # Example
+/// ```
+/// use pgrs::ident::quote_ident;
+///
+/// assert_eq!(quote_ident("users"), "users");
+/// assert_eq!(quote_ident("user"), "\"user\"");
+/// assert_eq!(quote_ident("CreatedAt"), "\"CreatedAt\"");
+/// assert_eq!(quote_ident("a\"b"), "\"a\"\"b\"");
+/// ```
+pub fn quote_ident(ident: &str) -> Cow<'_, str> {
+    if is_bare_identifier(ident) {
+        Cow::Borrowed(ident)
+    } else {
+        Cow::Owned(format!("\"{}\"", ident.replace('"', "\"\"")))
+    }
+}
+
+/// Strips the `r#` prefix of a raw Rust identifier, such as a column
+/// declared as `r#type`.
+///
+/// # Example
+/// ```
+/// use pgrs::ident::unraw;
+///
+/// assert_eq!(unraw("r#type"), "type");
+/// assert_eq!(unraw("name"), "name");
+/// ```
+pub fn unraw(ident: &str) -> &str {
+    ident.strip_prefix("r#").unwrap_or(ident)
+}
+
+/// Rejects names PostgreSQL cannot accept as identifiers, for builders that
+/// take names as runtime strings.
+pub(crate) fn check_ident(ident: &str) -> Result<()> {
+    if ident.is_empty() {
+        return Err(PgRsError::InvalidIdentifier("empty name".to_string()));
+    }
+    Ok(())
+}
+
+/// Checks an optionally schema-qualified name with `check_ident`.
+pub(crate) fn check_qualified(schema: Option<&str>, name: &str) -> Result<()> {
+    if let Some(schema) = schema {
+        check_ident(schema)?;
+    }
+    check_ident(name)
+}
+
+/// Quotes an optionally schema-qualified name, e.g. `app."order"`.
+pub fn quote_qualified(schema: Option<&str>, name: &str) -> String {
+    match schema {
+        Some(schema) => format!("{}.{}", quote_ident(schema), quote_ident(name)),
+        None => quote_ident(name).into_owned(),
+    }
+}
+
+fn is_bare_identifier(ident: &str) -> bool {
+    let mut bytes = ident.bytes();
+    let starts_well = matches!(bytes.next(), Some(b'a'..=b'z' | b'_'));
+    starts_well
+        && bytes.all(|b| matches!(b, b'a'..=b'z' | b'0'..=b'9' | b'_'))
+        && KEYWORDS.binary_search(&ident).is_err()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty_identifier() {
+        assert!(matches!(
+            check_ident(""),
+            Err(PgRsError::InvalidIdentifier(_))
+        ));
+        assert!(check_qualified(Some(""), "users").is_err());
+        assert!(check_qualified(None, "users").is_ok());
+    }
+
+    #[test]
+    fn test_keywords_are_sorted() {
+        assert!(KEYWORDS.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    #[test]
+    fn test_quote_ident() {
+        assert_eq!(quote_ident("users"), "users");
+        assert_eq!(quote_ident("_created_at2"), "_created_at2");
+        assert_eq!(quote_ident("name"), "name");
+        assert_eq!(quote_ident("order"), "\"order\"");
+        assert_eq!(quote_ident("user"), "\"user\"");
+        assert_eq!(quote_ident("createdAt"), "\"createdAt\"");
+        assert_eq!(quote_ident("2fa"), "\"2fa\"");
+        assert_eq!(quote_ident("first name"), "\"first name\"");
+        assert_eq!(quote_ident("price$"), "\"price$\"");
+        assert_eq!(quote_ident("say \"hi\""), "\"say \"\"hi\"\"\"");
+    }
+
+    #[test]
+    fn test_quote_qualified() {
+        assert_eq!(quote_qualified(None, "users"), "users");
+        assert_eq!(quote_qualified(Some("app"), "users"), "app.users");
+        assert_eq!(quote_qualified(Some("App"), "order"), "\"App\".\"order\"");
+    }
+}
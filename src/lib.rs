@@ -22,11 +22,19 @@
 //! let name = row.get("name")?;
 //! ```
 
+#[macro_use]
+mod macros;
+
 pub mod builders;
 pub mod clauses;
+pub mod codegen;
 pub mod drivers;
+pub mod dynamic;
 pub mod error;
+pub mod ident;
+pub mod migrations;
 pub mod querier;
+pub mod schema;
 pub mod traits;
 pub mod types;
 
@@ -37,5 +45,8 @@ pub use clauses::WhereClause;
 pub use client::PgRsClient;
 pub use error::{PgRsError, Result};
 pub use querier::Querier;
-pub use traits::{Column, ColumnRef, DatabaseDriver, Table};
-pub use types::{QueryResult, RawQueryResult, Row, SqlValue};
+pub use traits::{Column, ColumnRef, DatabaseDriver, Table, TableSource};
+pub use types::{FromRow, PgType, QueryResult, RawQueryResult, Row, SqlValue};
+
+#[cfg(feature = "derive")]
+pub use pgrs_derive::{FromRow, Table};
@@ -0,0 +1,231 @@
+/// Declares a table and its columns without a proc-macro dependency.
+///
+/// Expands to a module named after the table containing a `table` type
+/// implementing `Table`, a `Columns` accessor struct and one type per column
+/// implementing `Column`. Column types are mapped to PostgreSQL types through
+/// `ToPgType`, and `Option<T>` columns are nullable. Columns may be marked
+/// `#[primary_key]` or `#[unique]`. Raw identifiers such as `r#type` name the
+/// column `type`.
+///
+/// # Example
+/// ```
+/// pgrs::table! {
+///     app.users {
+///         #[primary_key]
+///         id: i64,
+///         name: Option<String>,
+///     }
+/// }
+///
+/// use pgrs::{Column, Table};
+///
+/// assert_eq!(users::table::qualified_name(), "app.users");
+/// assert_eq!(users::table::columns().name.column_name(), "name");
+/// assert!(users::id.is_primary_key());
+/// ```
+///
+/// To declare the items in the enclosing module instead, name the table,
+/// columns and column types with `as`, as a hand-written table would:
+///
+/// ```
+/// pgrs::table! {
+///     app.users as Users, UsersColumns {
+///         #[primary_key]
+///         id as UsersId: i64,
+///         name as UsersName: Option<String>,
+///     }
+/// }
+///
+/// use pgrs::{Column, Table};
+///
+/// assert_eq!(Users::qualified_name(), "app.users");
+/// assert!(UsersId.is_primary_key());
+/// ```
+#[macro_export]
+macro_rules! table {
+    (
+        $schema:ident . $table:ident as $table_ty:ident, $columns_ty:ident {
+            $($(#[$attr:ident])* $column:ident as $column_ty:ident : $ty:ty),* $(,)?
+        }
+    ) => {
+        $crate::table!(@items Some($crate::ident::unraw(stringify!($schema))),
+            $table as $table_ty, $columns_ty {
+                $($(#[$attr])* $column as $column_ty : $ty),*
+            }
+        );
+    };
+    (
+        $table:ident as $table_ty:ident, $columns_ty:ident {
+            $($(#[$attr:ident])* $column:ident as $column_ty:ident : $ty:ty),* $(,)?
+        }
+    ) => {
+        $crate::table!(@items None, $table as $table_ty, $columns_ty {
+            $($(#[$attr])* $column as $column_ty : $ty),*
+        });
+    };
+    (
+        $schema:ident . $table:ident {
+            $($(#[$attr:ident])* $column:ident : $ty:ty),* $(,)?
+        }
+    ) => {
+        $crate::table!(@module Some($crate::ident::unraw(stringify!($schema))), $table {
+            $($(#[$attr])* $column : $ty),*
+        });
+    };
+    (
+        $table:ident {
+            $($(#[$attr:ident])* $column:ident : $ty:ty),* $(,)?
+        }
+    ) => {
+        $crate::table!(@module None, $table {
+            $($(#[$attr])* $column : $ty),*
+        });
+    };
+    (
+        @module $schema:expr, $table:ident {
+            $($(#[$attr:ident])* $column:ident : $ty:ty),*
+        }
+    ) => {
+        // Only the types live in the module; the impls stay outside so
+        // column types resolve at the call site, even if a column shares
+        // its name with a crate such as `uuid`.
+        #[allow(non_camel_case_types)]
+        pub mod $table {
+            /// The table type, usable wherever a `Table` is accepted.
+            #[derive(Debug, Clone, Copy, Default)]
+            pub struct table;
+
+            /// Column accessors for this table.
+            #[derive(Debug, Clone, Copy, Default)]
+            pub struct Columns {
+                $(pub $column: $column,)*
+            }
+
+            $(
+                #[derive(Debug, Clone, Copy, Default)]
+                pub struct $column;
+            )*
+        }
+
+        $crate::table!(@impls $schema, $table, $table::table, $table::Columns {
+            $($(#[$attr])* $column => $table::$column : $ty),*
+        });
+    };
+    (
+        @items $schema:expr, $table:ident as $table_ty:ident, $columns_ty:ident {
+            $($(#[$attr:ident])* $column:ident as $column_ty:ident : $ty:ty),*
+        }
+    ) => {
+        #[derive(Debug, Clone, Copy, Default)]
+        pub struct $table_ty;
+
+        #[derive(Debug, Clone, Copy, Default)]
+        pub struct $columns_ty {
+            $(pub $column: $column_ty,)*
+        }
+
+        $(
+            #[derive(Debug, Clone, Copy, Default)]
+            pub struct $column_ty;
+        )*
+
+        $crate::table!(@impls $schema, $table, $table_ty, $columns_ty {
+            $($(#[$attr])* $column => $column_ty : $ty),*
+        });
+    };
+    (
+        @impls $schema:expr, $table:ident, $table_ty:path, $columns_ty:path {
+            $($(#[$attr:ident])* $column:ident => $column_ty:path : $ty:ty),*
+        }
+    ) => {
+        impl $crate::traits::Table for $table_ty {
+            type Columns = $columns_ty;
+
+            fn table_name() -> &'static str {
+                $crate::ident::unraw(stringify!($table))
+            }
+
+            fn schema() -> Option<&'static str> {
+                $schema
+            }
+
+            fn columns() -> Self::Columns {
+                $columns_ty {
+                    $($column: $column_ty,)*
+                }
+            }
+
+            fn all_columns() -> Vec<&'static dyn $crate::traits::Column> {
+                vec![$(&$column_ty),*]
+            }
+        }
+
+        $(
+            impl $crate::traits::Column for $column_ty {
+                fn column_name(&self) -> &'static str {
+                    $crate::ident::unraw(stringify!($column))
+                }
+
+                fn table_name(&self) -> &'static str {
+                    $crate::ident::unraw(stringify!($table))
+                }
+
+                fn schema_name(&self) -> Option<&'static str> {
+                    $schema
+                }
+
+                fn sql_type(&self) -> Option<$crate::types::PgType> {
+                    Some(<$ty as $crate::types::ToPgType>::pg_type())
+                }
+
+                fn is_nullable(&self) -> bool {
+                    <$ty as $crate::types::ToPgType>::nullable()
+                }
+
+                fn is_primary_key(&self) -> bool {
+                    false $(|| $crate::__table_column_attr!($attr) == "primary_key")*
+                }
+
+                fn is_unique(&self) -> bool {
+                    false $(|| $crate::__table_column_attr!($attr) == "unique")*
+                }
+            }
+        )*
+    };
+}
+
+/// Validates a column attribute of `table!`, expanding to its name.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __table_column_attr {
+    (primary_key) => {
+        "primary_key"
+    };
+    (unique) => {
+        "unique"
+    };
+}
+
+/// Embeds migration files in the binary.
+///
+/// Paths are relative to the file invoking the macro, like `include_str!`,
+/// and follow the naming rules of `Migrations::from_files`. Expands to a
+/// `Result<Migrations>`.
+///
+/// # Example
+/// ```ignore
+/// let migrations = pgrs::embed_migrations![
+///     "../migrations/V1__create_users.sql",
+///     "../migrations/U1__create_users.sql",
+///     "../migrations/V2__add_email.sql",
+/// ]?;
+/// client.migrator(migrations).up().await?;
+/// ```
+#[macro_export]
+macro_rules! embed_migrations {
+    ($($path:literal),* $(,)?) => {
+        $crate::migrations::Migrations::from_files([
+            $(($path, include_str!($path)),)*
+        ])
+    };
+}
@@ -0,0 +1,252 @@
+use std::fs;
+use std::path::Path;
+
+use crate::error::{PgRsError, Result};
+
+/// A versioned schema change, with optional SQL to revert it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Migration {
+    pub version: i64,
+    pub name: String,
+    pub up: String,
+    pub down: Option<String>,
+}
+
+impl Migration {
+    pub fn new(version: i64, name: impl Into<String>, up: impl Into<String>) -> Self {
+        Self {
+            version,
+            name: name.into(),
+            up: up.into(),
+            down: None,
+        }
+    }
+
+    /// Sets the SQL that reverts this migration.
+    pub fn down(mut self, sql: impl Into<String>) -> Self {
+        self.down = Some(sql.into());
+        self
+    }
+
+    /// CRC-32 of the up SQL, used to detect migrations edited after they
+    /// were applied. Line endings are normalized first so checkouts with
+    /// CRLF endings produce the same checksum.
+    pub fn checksum(&self) -> u32 {
+        crc32(self.up.replace("\r\n", "\n").as_bytes())
+    }
+}
+
+/// An ordered set of migrations with unique versions.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Migrations {
+    migrations: Vec<Migration>,
+}
+
+impl Migrations {
+    /// Sorts the migrations by version, rejecting duplicate versions.
+    pub fn new(mut migrations: Vec<Migration>) -> Result<Self> {
+        migrations.sort_by_key(|m| m.version);
+        for pair in migrations.windows(2) {
+            if pair[0].version == pair[1].version {
+                return Err(PgRsError::Migration(format!(
+                    "duplicate migration version {}",
+                    pair[0].version
+                )));
+            }
+        }
+        Ok(Self { migrations })
+    }
+
+    /// Builds migrations from `(file name, contents)` pairs. Any directories
+    /// before the file name are ignored.
+    ///
+    /// `V{n}__{name}.sql` files hold the SQL that applies version `n`, and
+    /// optional `U{n}__{name}.sql` files hold the SQL that reverts it.
+    pub fn from_files<'a>(files: impl IntoIterator<Item = (&'a str, &'a str)>) -> Result<Self> {
+        let mut migrations: Vec<Migration> = Vec::new();
+        let mut undo: Vec<(i64, &str, &str)> = Vec::new();
+
+        for (path, sql) in files {
+            let file_name = path.rsplit(['/', '\\']).next().unwrap_or(path);
+            let (prefix, version, name) = parse_file_name(file_name)?;
+            if prefix == 'V' {
+                migrations.push(Migration::new(version, name, sql));
+            } else {
+                undo.push((version, file_name, sql));
+            }
+        }
+
+        let mut migrations = Self::new(migrations)?;
+        for (version, file_name, sql) in undo {
+            let migration = migrations
+                .migrations
+                .iter_mut()
+                .find(|m| m.version == version)
+                .ok_or_else(|| {
+                    PgRsError::Migration(format!("{} has no matching V{} file", file_name, version))
+                })?;
+            if migration.down.is_some() {
+                return Err(PgRsError::Migration(format!(
+                    "duplicate undo migration for version {}",
+                    version
+                )));
+            }
+            migration.down = Some(sql.to_string());
+        }
+        Ok(migrations)
+    }
+
+    /// Reads the `.sql` migration files in a directory.
+    pub fn from_dir(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let io_error =
+            |e: std::io::Error| PgRsError::Migration(format!("{}: {}", path.display(), e));
+
+        let mut files = Vec::new();
+        for entry in fs::read_dir(path).map_err(io_error)? {
+            let entry = entry.map_err(io_error)?;
+            let file_name = entry.file_name().to_string_lossy().into_owned();
+            if !file_name.ends_with(".sql") {
+                continue;
+            }
+            let sql = fs::read_to_string(entry.path()).map_err(io_error)?;
+            files.push((file_name, sql));
+        }
+
+        Self::from_files(
+            files
+                .iter()
+                .map(|(name, sql)| (name.as_str(), sql.as_str())),
+        )
+    }
+
+    /// Returns the migration with the given version, if any.
+    pub fn get(&self, version: i64) -> Option<&Migration> {
+        self.migrations.iter().find(|m| m.version == version)
+    }
+
+    /// Iterates over the migrations in version order.
+    pub fn iter(&self) -> impl Iterator<Item = &Migration> {
+        self.migrations.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.migrations.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.migrations.is_empty()
+    }
+}
+
+/// Splits `V12__create_users.sql` into `('V', 12, "create_users")`.
+fn parse_file_name(file_name: &str) -> Result<(char, i64, &str)> {
+    let invalid = || {
+        PgRsError::Migration(format!(
+            "invalid migration file name {:?}, expected V{{n}}__{{name}}.sql or U{{n}}__{{name}}.sql",
+            file_name
+        ))
+    };
+
+    let stem = file_name.strip_suffix(".sql").ok_or_else(invalid)?;
+    let prefix = stem
+        .chars()
+        .next()
+        .filter(|c| matches!(c, 'V' | 'U'))
+        .ok_or_else(invalid)?;
+    let (version, name) = stem[1..].split_once("__").ok_or_else(invalid)?;
+    if version.is_empty() || !version.bytes().all(|b| b.is_ascii_digit()) || name.is_empty() {
+        return Err(invalid());
+    }
+    let version = version.parse().map_err(|_| invalid())?;
+    Ok((prefix, version, name))
+}
+
+/// CRC-32 (IEEE 802.3), as used by zlib and PNG.
+fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in bytes {
+        crc ^= u32::from(byte);
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xEDB8_8320 & mask);
+        }
+    }
+    !crc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_crc32() {
+        assert_eq!(crc32(b""), 0);
+        assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+    }
+
+    #[test]
+    fn test_checksum_ignores_line_endings() {
+        let unix = Migration::new(1, "a", "CREATE TABLE a ();\nCREATE TABLE b ();\n");
+        let windows = Migration::new(1, "a", "CREATE TABLE a ();\r\nCREATE TABLE b ();\r\n");
+        assert_eq!(unix.checksum(), windows.checksum());
+        assert_ne!(
+            unix.checksum(),
+            Migration::new(1, "a", "CREATE TABLE c ();").checksum()
+        );
+    }
+
+    #[test]
+    fn test_from_files() {
+        let migrations = Migrations::from_files([
+            (
+                "V2__add_email.sql",
+                "ALTER TABLE users ADD COLUMN email text;",
+            ),
+            ("U2__add_email.sql", "ALTER TABLE users DROP COLUMN email;"),
+            ("V1__create_users.sql", "CREATE TABLE users (id bigint);"),
+        ])
+        .unwrap();
+
+        let versions: Vec<i64> = migrations.iter().map(|m| m.version).collect();
+        assert_eq!(versions, [1, 2]);
+        assert_eq!(migrations.get(1).unwrap().name, "create_users");
+        assert_eq!(migrations.get(1).unwrap().down, None);
+        assert_eq!(
+            migrations.get(2).unwrap().down.as_deref(),
+            Some("ALTER TABLE users DROP COLUMN email;")
+        );
+    }
+
+    #[test]
+    fn test_from_files_rejects_invalid_sets() {
+        for files in [
+            vec![("V1_create_users.sql", "")],
+            vec![("v1__create_users.sql", "")],
+            vec![("Vx__create_users.sql", "")],
+            vec![("V1__.sql", "")],
+            vec![("V1__a.sql", ""), ("V1__b.sql", "")],
+            vec![("U1__a.sql", "")],
+            vec![("V1__a.sql", ""), ("U1__a.sql", ""), ("U1__b.sql", "")],
+        ] {
+            match Migrations::from_files(files.clone()) {
+                Err(PgRsError::Migration(_)) => {}
+                other => panic!("Expected Migration error for {:?}, got {:?}", files, other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_from_dir() {
+        let dir = std::env::temp_dir().join(format!("pgrs_migrations_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("V1__create_users.sql"), "CREATE TABLE users ();").unwrap();
+        fs::write(dir.join("README.md"), "not a migration").unwrap();
+
+        let migrations = Migrations::from_dir(&dir).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(migrations.len(), 1);
+        assert_eq!(migrations.get(1).unwrap().up, "CREATE TABLE users ();");
+    }
+}
@@ -0,0 +1,555 @@
+use std::sync::Arc;
+
+use crate::error::{PgRsError, Result};
+use crate::schema::{ColumnDef, CreateTableBuilder, DdlStatement};
+use crate::traits::DatabaseDriver;
+use crate::types::{FromSqlValue, PgType, SqlValue};
+
+use super::migration::{Migration, Migrations};
+
+/// Table recording applied migrations.
+pub const MIGRATIONS_TABLE: &str = "pgrs_migrations";
+
+/// Advisory lock key held while migrating ("pgrs" in ASCII).
+const LOCK_KEY: i64 = 0x7067_7273;
+
+/// Where a migration stands relative to the database.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MigrationState {
+    /// Not yet applied.
+    Pending,
+    /// Applied with the same checksum.
+    Applied,
+    /// Applied, but the SQL has changed since.
+    Modified,
+    /// Applied, but no longer present in the migration set.
+    Missing,
+}
+
+/// The state of a single migration version.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MigrationStatus {
+    pub version: i64,
+    pub name: String,
+    pub state: MigrationState,
+}
+
+/// A migration recorded in the migrations table.
+struct AppliedMigration {
+    version: i64,
+    name: String,
+    checksum: u32,
+}
+
+/// Applies and reverts migrations, recording them in `pgrs_migrations`.
+///
+/// `up` and `down` run on a dedicated connection from
+/// `DatabaseDriver::dedicated`, so other users of the driver never share
+/// their transactions. Each migration runs in its own transaction, and the
+/// connection holds a PostgreSQL advisory lock so concurrent deploys apply
+/// migrations once.
+pub struct Migrator {
+    driver: Arc<dyn DatabaseDriver>,
+    migrations: Migrations,
+}
+
+impl Migrator {
+    pub fn new(driver: Arc<dyn DatabaseDriver>, migrations: Migrations) -> Self {
+        Self { driver, migrations }
+    }
+
+    /// Applies all pending migrations in version order, returning the
+    /// versions applied.
+    ///
+    /// Fails without applying anything if an applied migration was modified
+    /// or is missing, or if a pending migration is older than the latest
+    /// applied one.
+    pub async fn up(&self) -> Result<Vec<i64>> {
+        let conn = self.driver.dedicated().await?;
+        let conn = conn.as_ref();
+        lock(conn).await?;
+        let result = self.up_locked(conn).await;
+        let unlocked = unlock(conn).await;
+        let applied = result?;
+        unlocked?;
+        Ok(applied)
+    }
+
+    /// Reverts the latest applied migration, returning its version, or
+    /// `None` if nothing is applied.
+    pub async fn down(&self) -> Result<Option<i64>> {
+        let conn = self.driver.dedicated().await?;
+        let conn = conn.as_ref();
+        lock(conn).await?;
+        let result = self.down_locked(conn).await;
+        let unlocked = unlock(conn).await;
+        let reverted = result?;
+        unlocked?;
+        Ok(reverted)
+    }
+
+    /// Returns the state of every known or applied migration, in version
+    /// order.
+    ///
+    /// Does not create the migrations table; if it does not exist yet,
+    /// every migration is pending.
+    pub async fn status(&self) -> Result<Vec<MigrationStatus>> {
+        let conn = self.driver.as_ref();
+        let applied = if table_exists(conn).await? {
+            applied(conn).await?
+        } else {
+            Vec::new()
+        };
+        Ok(self.statuses(&applied))
+    }
+
+    /// Checks that every applied migration is present and unchanged.
+    pub async fn verify(&self) -> Result<()> {
+        let statuses = self.status().await?;
+        verify_statuses(&statuses)
+    }
+
+    async fn up_locked(&self, conn: &dyn DatabaseDriver) -> Result<Vec<i64>> {
+        ensure_table(conn).await?;
+        let applied = applied(conn).await?;
+        verify_statuses(&self.statuses(&applied))?;
+
+        let latest = applied.iter().map(|a| a.version).max();
+        let pending: Vec<&Migration> = self
+            .migrations
+            .iter()
+            .filter(|m| !applied.iter().any(|a| a.version == m.version))
+            .collect();
+
+        if let (Some(latest), Some(first)) = (latest, pending.first()) {
+            if first.version < latest {
+                return Err(PgRsError::Migration(format!(
+                    "migration V{} ({}) is older than the latest applied version {}",
+                    first.version, first.name, latest
+                )));
+            }
+        }
+
+        let mut versions = Vec::new();
+        for migration in pending {
+            let record = format!(
+                "INSERT INTO {} (version, name, checksum) VALUES ($1, $2, $3)",
+                MIGRATIONS_TABLE
+            );
+            let params = [
+                SqlValue::Int64(migration.version),
+                SqlValue::Text(migration.name.clone()),
+                SqlValue::Int64(i64::from(migration.checksum())),
+            ];
+            in_transaction(conn, migration, &migration.up, &record, &params).await?;
+            versions.push(migration.version);
+        }
+        Ok(versions)
+    }
+
+    async fn down_locked(&self, conn: &dyn DatabaseDriver) -> Result<Option<i64>> {
+        ensure_table(conn).await?;
+        let applied = applied(conn).await?;
+        let Some(latest) = applied.last() else {
+            return Ok(None);
+        };
+
+        let migration = self.migrations.get(latest.version).ok_or_else(|| {
+            PgRsError::Migration(format!(
+                "applied migration V{} ({}) is missing",
+                latest.version, latest.name
+            ))
+        })?;
+        let down = migration.down.as_deref().ok_or_else(|| {
+            PgRsError::Migration(format!(
+                "migration V{} ({}) has no undo SQL",
+                migration.version, migration.name
+            ))
+        })?;
+
+        let record = format!("DELETE FROM {} WHERE version = $1", MIGRATIONS_TABLE);
+        in_transaction(
+            conn,
+            migration,
+            down,
+            &record,
+            &[SqlValue::Int64(migration.version)],
+        )
+        .await?;
+        Ok(Some(migration.version))
+    }
+
+    fn statuses(&self, applied: &[AppliedMigration]) -> Vec<MigrationStatus> {
+        let mut statuses: Vec<MigrationStatus> = self
+            .migrations
+            .iter()
+            .map(|m| {
+                let state = match applied.iter().find(|a| a.version == m.version) {
+                    None => MigrationState::Pending,
+                    Some(a) if a.checksum == m.checksum() => MigrationState::Applied,
+                    Some(_) => MigrationState::Modified,
+                };
+                MigrationStatus {
+                    version: m.version,
+                    name: m.name.clone(),
+                    state,
+                }
+            })
+            .collect();
+
+        statuses.extend(
+            applied
+                .iter()
+                .filter(|a| self.migrations.get(a.version).is_none())
+                .map(|a| MigrationStatus {
+                    version: a.version,
+                    name: a.name.clone(),
+                    state: MigrationState::Missing,
+                }),
+        );
+        statuses.sort_by_key(|s| s.version);
+        statuses
+    }
+}
+
+/// Runs migration SQL and the statement recording it in one transaction.
+async fn in_transaction(
+    conn: &dyn DatabaseDriver,
+    migration: &Migration,
+    sql: &str,
+    record: &str,
+    params: &[SqlValue],
+) -> Result<()> {
+    conn.batch_execute("BEGIN").await?;
+
+    let result = match conn.batch_execute(sql).await {
+        Ok(()) => conn.execute(record, params).await.map(|_| ()),
+        Err(e) => Err(e),
+    };
+    if let Err(e) = result {
+        // The original error is more useful than a failed rollback
+        let _ = conn.batch_execute("ROLLBACK").await;
+        return Err(PgRsError::Migration(format!(
+            "migration V{} ({}) failed: {}",
+            migration.version, migration.name, e
+        )));
+    }
+
+    conn.batch_execute("COMMIT").await
+}
+
+async fn lock(conn: &dyn DatabaseDriver) -> Result<()> {
+    conn.execute("SELECT pg_advisory_lock($1)", &[SqlValue::Int64(LOCK_KEY)])
+        .await
+        .map(|_| ())
+}
+
+async fn unlock(conn: &dyn DatabaseDriver) -> Result<()> {
+    conn.execute(
+        "SELECT pg_advisory_unlock($1)",
+        &[SqlValue::Int64(LOCK_KEY)],
+    )
+    .await
+    .map(|_| ())
+}
+
+async fn ensure_table(conn: &dyn DatabaseDriver) -> Result<()> {
+    let sql = CreateTableBuilder::new(MIGRATIONS_TABLE)
+        .if_not_exists()
+        .column(ColumnDef::new("version", PgType::BigInt).primary_key())
+        .column(ColumnDef::new("name", PgType::Text).not_null())
+        .column(ColumnDef::new("checksum", PgType::BigInt).not_null())
+        .column(
+            ColumnDef::new("applied_at", PgType::TimestampTz)
+                .not_null()
+                .default("now()"),
+        )
+        .build_sql()?;
+    conn.batch_execute(&sql).await
+}
+
+async fn table_exists(conn: &dyn DatabaseDriver) -> Result<bool> {
+    let result = conn
+        .execute(
+            "SELECT 1 WHERE to_regclass($1) IS NOT NULL",
+            &[SqlValue::Text(MIGRATIONS_TABLE.to_string())],
+        )
+        .await?;
+    Ok(!result.rows.is_empty())
+}
+
+async fn applied(conn: &dyn DatabaseDriver) -> Result<Vec<AppliedMigration>> {
+    let result = conn
+        .execute(
+            &format!(
+                "SELECT version, name, checksum FROM {} ORDER BY version",
+                MIGRATIONS_TABLE
+            ),
+            &[],
+        )
+        .await?;
+    // Drivers may omit column names when no rows are returned
+    if result.rows.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let column = |name: &str| {
+        result
+            .columns
+            .iter()
+            .position(|c| c == name)
+            .ok_or_else(|| PgRsError::ColumnNotFound(name.to_string()))
+    };
+    let (version, name, checksum) = (column("version")?, column("name")?, column("checksum")?);
+    let invalid = |value: &SqlValue| {
+        PgRsError::Migration(format!("invalid value in {}: {}", MIGRATIONS_TABLE, value))
+    };
+
+    result
+        .rows
+        .iter()
+        .map(|row| {
+            Ok(AppliedMigration {
+                version: i64::from_sql_value(&row[version])
+                    .ok_or_else(|| invalid(&row[version]))?,
+                name: String::from_sql_value(&row[name]).ok_or_else(|| invalid(&row[name]))?,
+                checksum: u32::from_sql_value(&row[checksum])
+                    .ok_or_else(|| invalid(&row[checksum]))?,
+            })
+        })
+        .collect()
+}
+
+fn verify_statuses(statuses: &[MigrationStatus]) -> Result<()> {
+    let problems: Vec<String> = statuses
+        .iter()
+        .filter_map(|s| match s.state {
+            MigrationState::Modified => Some(format!(
+                "migration V{} ({}) was modified after it was applied",
+                s.version, s.name
+            )),
+            MigrationState::Missing => Some(format!(
+                "applied migration V{} ({}) is missing",
+                s.version, s.name
+            )),
+            MigrationState::Pending | MigrationState::Applied => None,
+        })
+        .collect();
+
+    if problems.is_empty() {
+        Ok(())
+    } else {
+        Err(PgRsError::Migration(problems.join("; ")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::drivers::{InMemoryTestDriver, InMemoryTestResponseBuilder};
+    use crate::types::RawQueryResult;
+
+    fn migrations() -> Migrations {
+        Migrations::new(vec![
+            Migration::new(1, "create_users", "CREATE TABLE users (id bigint);")
+                .down("DROP TABLE users;"),
+            Migration::new(2, "add_email", "ALTER TABLE users ADD COLUMN email text;"),
+        ])
+        .unwrap()
+    }
+
+    fn applied(rows: &[(i64, &str, u32)]) -> RawQueryResult {
+        let rows: Vec<[String; 3]> = rows
+            .iter()
+            .map(|(v, n, c)| [v.to_string(), n.to_string(), c.to_string()])
+            .collect();
+        let mut builder =
+            InMemoryTestResponseBuilder::new().columns(&["version", "name", "checksum"]);
+        for row in &rows {
+            builder = builder.row(&[&row[0], &row[1], &row[2]]);
+        }
+        builder.build()
+    }
+
+    /// A driver whose migrations table holds the given rows.
+    fn driver(rows: &[(i64, &str, u32)]) -> Arc<InMemoryTestDriver> {
+        Arc::new(InMemoryTestDriver::new().with_responses([
+            RawQueryResult::empty(),
+            RawQueryResult::empty(),
+            applied(rows),
+        ]))
+    }
+
+    fn sqls(driver: &InMemoryTestDriver) -> Vec<String> {
+        driver
+            .recorded_queries()
+            .into_iter()
+            .map(|q| q.sql)
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_up_applies_pending_migrations() {
+        let checksum = migrations().get(1).unwrap().checksum();
+        let driver = driver(&[(1, "create_users", checksum)]);
+        let migrator = Migrator::new(driver.clone(), migrations());
+
+        assert_eq!(migrator.up().await.unwrap(), [2]);
+        assert_eq!(driver.dedicated_count(), 1);
+
+        let queries = driver.recorded_queries();
+        assert_eq!(queries[0].sql, "SELECT pg_advisory_lock($1)");
+        assert!(queries[1]
+            .sql
+            .starts_with("CREATE TABLE IF NOT EXISTS pgrs_migrations ("));
+        assert_eq!(
+            sqls(&driver)[3..],
+            [
+                "BEGIN",
+                "ALTER TABLE users ADD COLUMN email text;",
+                "INSERT INTO pgrs_migrations (version, name, checksum) VALUES ($1, $2, $3)",
+                "COMMIT",
+                "SELECT pg_advisory_unlock($1)",
+            ]
+        );
+        assert_eq!(
+            queries[5].params,
+            [
+                SqlValue::Int64(2),
+                SqlValue::Text("add_email".to_string()),
+                SqlValue::Int64(i64::from(migrations().get(2).unwrap().checksum())),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_up_refuses_modified_migrations() {
+        let driver = driver(&[(1, "create_users", 42)]);
+        let migrator = Migrator::new(driver.clone(), migrations());
+
+        match migrator.up().await {
+            Err(PgRsError::Migration(message)) => {
+                assert!(message.contains("V1 (create_users) was modified"))
+            }
+            other => panic!("Expected Migration error, got {:?}", other),
+        }
+        assert!(!sqls(&driver).contains(&"BEGIN".to_string()));
+        assert_eq!(
+            driver.last_query().unwrap().sql,
+            "SELECT pg_advisory_unlock($1)"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_up_refuses_out_of_order_migrations() {
+        let checksum = migrations().get(2).unwrap().checksum();
+        let driver = driver(&[(2, "add_email", checksum)]);
+        let migrator = Migrator::new(driver, migrations());
+
+        match migrator.up().await {
+            Err(PgRsError::Migration(message)) => {
+                assert!(message.contains("older than the latest applied version 2"))
+            }
+            other => panic!("Expected Migration error, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_down_reverts_latest_migration() {
+        let checksum = migrations().get(1).unwrap().checksum();
+        let driver = driver(&[(1, "create_users", checksum)]);
+        let migrator = Migrator::new(driver.clone(), migrations());
+
+        assert_eq!(migrator.down().await.unwrap(), Some(1));
+        assert_eq!(
+            sqls(&driver)[3..],
+            [
+                "BEGIN",
+                "DROP TABLE users;",
+                "DELETE FROM pgrs_migrations WHERE version = $1",
+                "COMMIT",
+                "SELECT pg_advisory_unlock($1)",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_down_requires_undo_sql() {
+        let migrations = migrations();
+        let driver = driver(&[
+            (1, "create_users", migrations.get(1).unwrap().checksum()),
+            (2, "add_email", migrations.get(2).unwrap().checksum()),
+        ]);
+        let migrator = Migrator::new(driver, migrations);
+
+        match migrator.down().await {
+            Err(PgRsError::Migration(message)) => assert!(message.contains("has no undo SQL")),
+            other => panic!("Expected Migration error, got {:?}", other),
+        }
+    }
+
+    fn table_exists() -> RawQueryResult {
+        InMemoryTestResponseBuilder::new()
+            .columns(&["?column?"])
+            .row(&["1"])
+            .build()
+    }
+
+    #[tokio::test]
+    async fn test_status_and_verify() {
+        let rows = [(1, "create_users", 42), (3, "dropped", 7)];
+        let driver = Arc::new(
+            InMemoryTestDriver::new()
+                .with_responses([table_exists(), applied(&rows)])
+                .with_response(table_exists())
+                .with_response(applied(&rows)),
+        );
+        let migrator = Migrator::new(driver, migrations());
+
+        let states: Vec<(i64, MigrationState)> = migrator
+            .status()
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|s| (s.version, s.state))
+            .collect();
+        assert_eq!(
+            states,
+            [
+                (1, MigrationState::Modified),
+                (2, MigrationState::Pending),
+                (3, MigrationState::Missing),
+            ]
+        );
+
+        match migrator.verify().await {
+            Err(PgRsError::Migration(message)) => {
+                assert!(message.contains("V1 (create_users) was modified"));
+                assert!(message.contains("V3 (dropped) is missing"));
+            }
+            other => panic!("Expected Migration error, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_status_without_migrations_table() {
+        let driver = Arc::new(InMemoryTestDriver::new().with_response(RawQueryResult::empty()));
+        let migrator = Migrator::new(driver.clone(), migrations());
+
+        let states: Vec<(i64, MigrationState)> = migrator
+            .status()
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|s| (s.version, s.state))
+            .collect();
+        assert_eq!(
+            states,
+            [(1, MigrationState::Pending), (2, MigrationState::Pending)]
+        );
+        assert_eq!(
+            sqls(&driver),
+            ["SELECT 1 WHERE to_regclass($1) IS NOT NULL"]
+        );
+    }
+}
@@ -0,0 +1,5 @@
+mod migration;
+mod migrator;
+
+pub use migration::{Migration, Migrations};
+pub use migrator::{MigrationState, MigrationStatus, Migrator, MIGRATIONS_TABLE};
@@ -1,6 +1,8 @@
 use std::sync::Arc;
 
-use crate::builders::SelectBuilder;
+use crate::builders::{SelectBuilder, UpdateBuilder};
+use crate::error::Result;
+use crate::schema::DdlStatement;
 use crate::traits::DatabaseDriver;
 
 /// Query builder factory.
@@ -18,4 +20,16 @@ impl Querier {
     pub fn select(&self) -> SelectBuilder {
         SelectBuilder::new(Arc::clone(&self.driver))
     }
+
+    /// Start building an UPDATE query.
+    pub fn update(&self) -> UpdateBuilder {
+        UpdateBuilder::new(Arc::clone(&self.driver))
+    }
+
+    /// Execute a DDL statement such as CREATE TABLE or CREATE INDEX.
+    pub async fn execute_ddl<S: DdlStatement + ?Sized>(&self, statement: &S) -> Result<()> {
+        let sql = statement.build_sql()?;
+        self.driver.execute(&sql, &[]).await?;
+        Ok(())
+    }
 }
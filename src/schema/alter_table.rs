@@ -0,0 +1,272 @@
+use crate::error::{PgRsError, Result};
+use crate::ident::{check_ident, check_qualified, quote_ident, quote_qualified};
+use crate::traits::{Column, TableSource};
+use crate::types::PgType;
+
+use super::column_def::ColumnDef;
+use super::constraint::Constraint;
+use super::statement::DdlStatement;
+
+/// A change applied to an existing column by ALTER COLUMN.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ColumnChange {
+    /// SET DATA TYPE type
+    SetType(PgType),
+    /// SET DATA TYPE type USING expression, for conversions without an
+    /// implicit cast. The expression is rendered verbatim.
+    SetTypeUsing { sql_type: PgType, using: String },
+    /// SET NOT NULL
+    SetNotNull,
+    /// DROP NOT NULL
+    DropNotNull,
+    /// SET DEFAULT expression
+    SetDefault(String),
+    /// DROP DEFAULT
+    DropDefault,
+}
+
+/// A single action of an ALTER TABLE statement.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AlterAction {
+    AddColumn(ColumnDef),
+    DropColumn { name: String, cascade: bool },
+    AlterColumn { name: String, change: ColumnChange },
+    AddConstraint(Constraint),
+    DropConstraint(String),
+}
+
+impl AlterAction {
+    fn check_names(&self) -> Result<()> {
+        match self {
+            AlterAction::AddColumn(column) => column.check_names(),
+            AlterAction::DropColumn { name, .. }
+            | AlterAction::AlterColumn { name, .. }
+            | AlterAction::DropConstraint(name) => check_ident(name),
+            AlterAction::AddConstraint(constraint) => constraint.check_names(),
+        }
+    }
+
+    fn build_sql(&self) -> String {
+        match self {
+            AlterAction::AddColumn(column) => format!("ADD COLUMN {}", column.build_sql()),
+            AlterAction::DropColumn { name, cascade } => {
+                if *cascade {
+                    format!("DROP COLUMN {} CASCADE", quote_ident(name))
+                } else {
+                    format!("DROP COLUMN {}", quote_ident(name))
+                }
+            }
+            AlterAction::AlterColumn { name, change } => {
+                let change_sql = match change {
+                    ColumnChange::SetType(sql_type) => format!("SET DATA TYPE {}", sql_type),
+                    ColumnChange::SetTypeUsing { sql_type, using } => {
+                        format!("SET DATA TYPE {} USING {}", sql_type, using)
+                    }
+                    ColumnChange::SetNotNull => "SET NOT NULL".to_string(),
+                    ColumnChange::DropNotNull => "DROP NOT NULL".to_string(),
+                    ColumnChange::SetDefault(expression) => format!("SET DEFAULT {}", expression),
+                    ColumnChange::DropDefault => "DROP DEFAULT".to_string(),
+                };
+                format!("ALTER COLUMN {} {}", quote_ident(name), change_sql)
+            }
+            AlterAction::AddConstraint(constraint) => format!("ADD {}", constraint.build_sql()),
+            AlterAction::DropConstraint(name) => {
+                format!("DROP CONSTRAINT {}", quote_ident(name))
+            }
+        }
+    }
+}
+
+/// Builder for ALTER TABLE statements.
+/// Multiple actions are rendered as a comma-separated list in a single statement.
+#[derive(Debug, Clone)]
+pub struct AlterTableBuilder {
+    schema: Option<String>,
+    name: String,
+    actions: Vec<AlterAction>,
+}
+
+impl AlterTableBuilder {
+    /// Start an ALTER TABLE statement for a table with the given name.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            schema: None,
+            name: name.into(),
+            actions: Vec::new(),
+        }
+    }
+
+    /// Start an ALTER TABLE statement for a `Table` or `DynTable`.
+    pub fn for_table<T: TableSource>(table: T) -> Self {
+        let builder = Self::new(table.name());
+        match table.schema_name() {
+            Some(schema) => builder.schema(schema),
+            None => builder,
+        }
+    }
+
+    /// Set the schema of the table to alter.
+    pub fn schema(mut self, schema: impl Into<String>) -> Self {
+        self.schema = Some(schema.into());
+        self
+    }
+
+    /// Add an action to the statement.
+    pub fn action(mut self, action: AlterAction) -> Self {
+        self.actions.push(action);
+        self
+    }
+
+    /// ADD COLUMN definition
+    pub fn add_column(self, column: ColumnDef) -> Self {
+        self.action(AlterAction::AddColumn(column))
+    }
+
+    /// DROP COLUMN column
+    pub fn drop_column<C: Column + ?Sized>(self, column: &C) -> Self {
+        self.action(AlterAction::DropColumn {
+            name: column.column_name().to_string(),
+            cascade: false,
+        })
+    }
+
+    /// ALTER COLUMN column change
+    pub fn alter_column<C: Column + ?Sized>(self, column: &C, change: ColumnChange) -> Self {
+        self.action(AlterAction::AlterColumn {
+            name: column.column_name().to_string(),
+            change,
+        })
+    }
+
+    /// ADD constraint
+    pub fn add_constraint(self, constraint: Constraint) -> Self {
+        self.action(AlterAction::AddConstraint(constraint))
+    }
+
+    /// DROP CONSTRAINT name
+    pub fn drop_constraint(self, name: impl Into<String>) -> Self {
+        self.action(AlterAction::DropConstraint(name.into()))
+    }
+}
+
+impl DdlStatement for AlterTableBuilder {
+    fn build_sql(&self) -> Result<String> {
+        if self.actions.is_empty() {
+            return Err(PgRsError::NoAlterActionsSpecified);
+        }
+        check_qualified(self.schema.as_deref(), &self.name)?;
+        for action in &self.actions {
+            action.check_names()?;
+        }
+
+        let mut sql = String::from("ALTER TABLE ");
+        sql.push_str(&quote_qualified(self.schema.as_deref(), &self.name));
+        sql.push(' ');
+        for (i, action) in self.actions.iter().enumerate() {
+            if i > 0 {
+                sql.push_str(", ");
+            }
+            sql.push_str(&action.build_sql());
+        }
+        Ok(sql)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct UsersName;
+
+    impl Column for UsersName {
+        fn column_name(&self) -> &'static str {
+            "name"
+        }
+        fn table_name(&self) -> &'static str {
+            "users"
+        }
+    }
+
+    #[test]
+    fn test_add_and_drop_column() {
+        let sql = AlterTableBuilder::new("users")
+            .add_column(
+                ColumnDef::new("email", PgType::Text)
+                    .not_null()
+                    .default("''"),
+            )
+            .drop_column(&UsersName)
+            .build_sql()
+            .unwrap();
+        assert_eq!(
+            sql,
+            "ALTER TABLE users ADD COLUMN email text NOT NULL DEFAULT '', DROP COLUMN name"
+        );
+    }
+
+    #[test]
+    fn test_alter_column() {
+        let sql = AlterTableBuilder::new("users")
+            .schema("app")
+            .alter_column(
+                &UsersName,
+                ColumnChange::SetType(PgType::Varchar(Some(100))),
+            )
+            .alter_column(&UsersName, ColumnChange::SetNotNull)
+            .build_sql()
+            .unwrap();
+        assert_eq!(
+            sql,
+            "ALTER TABLE app.users ALTER COLUMN name SET DATA TYPE character varying(100), \
+             ALTER COLUMN name SET NOT NULL"
+        );
+
+        let sql = AlterTableBuilder::new("users")
+            .alter_column(
+                &UsersName,
+                ColumnChange::SetTypeUsing {
+                    sql_type: PgType::Integer,
+                    using: "name::integer".to_string(),
+                },
+            )
+            .build_sql()
+            .unwrap();
+        assert_eq!(
+            sql,
+            "ALTER TABLE users ALTER COLUMN name SET DATA TYPE integer USING name::integer"
+        );
+    }
+
+    #[test]
+    fn test_constraints() {
+        let sql = AlterTableBuilder::new("users")
+            .add_constraint(Constraint::unique(&[&UsersName]).named("users_name_key"))
+            .drop_constraint("users_old_key")
+            .build_sql()
+            .unwrap();
+        assert_eq!(
+            sql,
+            "ALTER TABLE users ADD CONSTRAINT users_name_key UNIQUE (name), \
+             DROP CONSTRAINT users_old_key"
+        );
+    }
+
+    #[test]
+    fn test_alter_fails_without_actions() {
+        let err = AlterTableBuilder::new("users").build_sql().unwrap_err();
+        assert!(matches!(err, PgRsError::NoAlterActionsSpecified));
+    }
+
+    #[test]
+    fn test_alter_rejects_empty_names() {
+        let err = AlterTableBuilder::new("users")
+            .drop_constraint("")
+            .build_sql()
+            .unwrap_err();
+        assert!(matches!(err, PgRsError::InvalidIdentifier(_)));
+        assert!(AlterTableBuilder::new("")
+            .drop_column(&UsersName)
+            .build_sql()
+            .is_err());
+    }
+}
@@ -0,0 +1,259 @@
+use crate::error::{PgRsError, Result};
+use crate::ident::{check_ident, check_qualified, quote_ident, quote_qualified};
+use crate::traits::Column;
+use crate::types::PgType;
+
+use super::constraint::ReferentialAction;
+
+/// A foreign key reference attached to a single column.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColumnReference {
+    pub schema: Option<String>,
+    pub table: String,
+    pub column: String,
+    pub on_delete: Option<ReferentialAction>,
+    pub on_update: Option<ReferentialAction>,
+}
+
+/// Definition of a column as used in CREATE TABLE and ALTER TABLE ADD COLUMN.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColumnDef {
+    pub name: String,
+    pub sql_type: PgType,
+    pub nullable: bool,
+    pub default: Option<String>,
+    pub primary_key: bool,
+    pub unique: bool,
+    pub check: Option<String>,
+    pub references: Option<ColumnReference>,
+}
+
+impl ColumnDef {
+    /// Creates a nullable column definition with the given name and type.
+    pub fn new(name: impl Into<String>, sql_type: PgType) -> Self {
+        Self {
+            name: name.into(),
+            sql_type,
+            nullable: true,
+            default: None,
+            primary_key: false,
+            unique: false,
+            check: None,
+            references: None,
+        }
+    }
+
+    /// Creates a column definition named after an existing Column.
+    pub fn for_column<C: Column + ?Sized>(column: &C, sql_type: PgType) -> Self {
+        Self::new(column.column_name(), sql_type)
+    }
+
+    /// Creates a column definition from a Column's metadata.
+    /// Returns an error if the column does not declare its SQL type.
+    pub fn from_column<C: Column + ?Sized>(column: &C) -> Result<Self> {
+        let sql_type = column
+            .sql_type()
+            .ok_or_else(|| PgRsError::MissingColumnType(column.qualified_name()))?;
+        Ok(Self {
+            name: column.column_name().to_string(),
+            sql_type,
+            nullable: column.is_nullable(),
+            default: column.default_expression().map(str::to_string),
+            primary_key: column.is_primary_key(),
+            unique: column.is_unique(),
+            check: None,
+            references: None,
+        })
+    }
+
+    /// Rejects the definition if it or its reference has an empty name.
+    pub(crate) fn check_names(&self) -> Result<()> {
+        check_ident(&self.name)?;
+        if let Some(reference) = &self.references {
+            check_qualified(reference.schema.as_deref(), &reference.table)?;
+            check_ident(&reference.column)?;
+        }
+        Ok(())
+    }
+
+    /// Marks the column NOT NULL.
+    pub fn not_null(mut self) -> Self {
+        self.nullable = false;
+        self
+    }
+
+    /// Sets the DEFAULT expression, rendered verbatim.
+    pub fn default(mut self, expression: impl Into<String>) -> Self {
+        self.default = Some(expression.into());
+        self
+    }
+
+    /// Marks the column as the table's PRIMARY KEY.
+    pub fn primary_key(mut self) -> Self {
+        self.primary_key = true;
+        self
+    }
+
+    /// Adds a UNIQUE constraint to the column.
+    pub fn unique(mut self) -> Self {
+        self.unique = true;
+        self
+    }
+
+    /// Adds a CHECK constraint to the column, rendered verbatim.
+    pub fn check(mut self, expression: impl Into<String>) -> Self {
+        self.check = Some(expression.into());
+        self
+    }
+
+    /// Adds a REFERENCES constraint pointing at another table's column.
+    pub fn references<C: Column + ?Sized>(mut self, column: &C) -> Self {
+        self.references = Some(ColumnReference {
+            schema: column.schema_name().map(str::to_string),
+            table: column.table_name().to_string(),
+            column: column.column_name().to_string(),
+            on_delete: None,
+            on_update: None,
+        });
+        self
+    }
+
+    /// Sets the ON DELETE action of the column's REFERENCES constraint.
+    /// Has no effect unless `references` was called first.
+    pub fn on_delete(mut self, action: ReferentialAction) -> Self {
+        if let Some(ref mut reference) = self.references {
+            reference.on_delete = Some(action);
+        }
+        self
+    }
+
+    /// Sets the ON UPDATE action of the column's REFERENCES constraint.
+    /// Has no effect unless `references` was called first.
+    pub fn on_update(mut self, action: ReferentialAction) -> Self {
+        if let Some(ref mut reference) = self.references {
+            reference.on_update = Some(action);
+        }
+        self
+    }
+
+    /// Builds the column definition fragment, e.g. `id bigint NOT NULL`.
+    pub fn build_sql(&self) -> String {
+        let mut sql = format!("{} {}", quote_ident(&self.name), self.sql_type);
+
+        if self.primary_key {
+            sql.push_str(" PRIMARY KEY");
+        } else if !self.nullable {
+            sql.push_str(" NOT NULL");
+        }
+
+        if let Some(ref default) = self.default {
+            sql.push_str(" DEFAULT ");
+            sql.push_str(default);
+        }
+
+        if self.unique && !self.primary_key {
+            sql.push_str(" UNIQUE");
+        }
+
+        if let Some(ref check) = self.check {
+            sql.push_str(" CHECK (");
+            sql.push_str(check);
+            sql.push(')');
+        }
+
+        if let Some(ref reference) = self.references {
+            sql.push_str(&format!(
+                " REFERENCES {} ({})",
+                quote_qualified(reference.schema.as_deref(), &reference.table),
+                quote_ident(&reference.column)
+            ));
+            if let Some(action) = reference.on_delete {
+                sql.push_str(" ON DELETE ");
+                sql.push_str(action.as_sql());
+            }
+            if let Some(action) = reference.on_update {
+                sql.push_str(" ON UPDATE ");
+                sql.push_str(action.as_sql());
+            }
+        }
+
+        sql
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct UsersEmail;
+    struct UsersUntyped;
+
+    impl Column for UsersEmail {
+        fn column_name(&self) -> &'static str {
+            "email"
+        }
+        fn table_name(&self) -> &'static str {
+            "users"
+        }
+        fn sql_type(&self) -> Option<PgType> {
+            Some(PgType::Text)
+        }
+        fn is_nullable(&self) -> bool {
+            false
+        }
+        fn default_expression(&self) -> Option<&'static str> {
+            Some("''")
+        }
+        fn is_unique(&self) -> bool {
+            true
+        }
+    }
+
+    impl Column for UsersUntyped {
+        fn column_name(&self) -> &'static str {
+            "untyped"
+        }
+        fn table_name(&self) -> &'static str {
+            "users"
+        }
+    }
+
+    #[test]
+    fn test_from_column_metadata() {
+        let def = ColumnDef::from_column(&UsersEmail).unwrap();
+        assert_eq!(def.build_sql(), "email text NOT NULL DEFAULT '' UNIQUE");
+    }
+
+    #[test]
+    fn test_from_column_without_type() {
+        let err = ColumnDef::from_column(&UsersUntyped).unwrap_err();
+        assert!(matches!(err, PgRsError::MissingColumnType(ref name) if name == "users.untyped"));
+    }
+
+    #[test]
+    fn test_simple_column() {
+        let def = ColumnDef::new("name", PgType::Text);
+        assert_eq!(def.build_sql(), "name text");
+    }
+
+    #[test]
+    fn test_column_with_constraints() {
+        let def = ColumnDef::new("email", PgType::Varchar(Some(255)))
+            .not_null()
+            .unique()
+            .default("''")
+            .check("email <> 'root'");
+        assert_eq!(
+            def.build_sql(),
+            "email character varying(255) NOT NULL DEFAULT '' UNIQUE CHECK (email <> 'root')"
+        );
+    }
+
+    #[test]
+    fn test_primary_key_column() {
+        let def = ColumnDef::new("id", PgType::BigSerial)
+            .not_null()
+            .primary_key();
+        assert_eq!(def.build_sql(), "id bigserial PRIMARY KEY");
+    }
+}
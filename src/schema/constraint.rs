@@ -0,0 +1,288 @@
+use crate::error::Result;
+use crate::ident::{check_ident, check_qualified, quote_ident, quote_qualified};
+use crate::traits::{Column, TableSource};
+
+use super::statement::quote_list;
+
+/// Action taken on referencing rows when a referenced row changes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReferentialAction {
+    NoAction,
+    Restrict,
+    Cascade,
+    SetNull,
+    SetDefault,
+}
+
+impl ReferentialAction {
+    pub fn as_sql(&self) -> &'static str {
+        match self {
+            ReferentialAction::NoAction => "NO ACTION",
+            ReferentialAction::Restrict => "RESTRICT",
+            ReferentialAction::Cascade => "CASCADE",
+            ReferentialAction::SetNull => "SET NULL",
+            ReferentialAction::SetDefault => "SET DEFAULT",
+        }
+    }
+}
+
+/// The kind of a table-level constraint.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConstraintKind {
+    /// PRIMARY KEY (columns)
+    PrimaryKey(Vec<String>),
+    /// UNIQUE (columns)
+    Unique(Vec<String>),
+    /// FOREIGN KEY (columns) REFERENCES table (ref_columns)
+    ForeignKey {
+        columns: Vec<String>,
+        ref_schema: Option<String>,
+        ref_table: String,
+        ref_columns: Vec<String>,
+        on_delete: Option<ReferentialAction>,
+        on_update: Option<ReferentialAction>,
+    },
+    /// CHECK (expression)
+    Check(String),
+}
+
+/// A table-level constraint, optionally named.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Constraint {
+    pub name: Option<String>,
+    pub kind: ConstraintKind,
+}
+
+fn column_names(columns: &[&dyn Column]) -> Vec<String> {
+    columns
+        .iter()
+        .map(|c| c.column_name().to_string())
+        .collect()
+}
+
+impl Constraint {
+    /// Creates a PRIMARY KEY constraint over the given columns.
+    pub fn primary_key(columns: &[&dyn Column]) -> Self {
+        Self {
+            name: None,
+            kind: ConstraintKind::PrimaryKey(column_names(columns)),
+        }
+    }
+
+    /// Creates a UNIQUE constraint over the given columns.
+    pub fn unique(columns: &[&dyn Column]) -> Self {
+        Self {
+            name: None,
+            kind: ConstraintKind::Unique(column_names(columns)),
+        }
+    }
+
+    /// Creates a FOREIGN KEY constraint from `columns` to `ref_columns` of table `T`.
+    pub fn foreign_key<T: TableSource>(
+        columns: &[&dyn Column],
+        table: T,
+        ref_columns: &[&dyn Column],
+    ) -> Self {
+        Self {
+            name: None,
+            kind: ConstraintKind::ForeignKey {
+                columns: column_names(columns),
+                ref_schema: table.schema_name().map(str::to_string),
+                ref_table: table.name().to_string(),
+                ref_columns: column_names(ref_columns),
+                on_delete: None,
+                on_update: None,
+            },
+        }
+    }
+
+    /// Creates a CHECK constraint. The expression is rendered verbatim.
+    pub fn check(expression: impl Into<String>) -> Self {
+        Self {
+            name: None,
+            kind: ConstraintKind::Check(expression.into()),
+        }
+    }
+
+    /// Rejects the constraint if its name or any column or table it names
+    /// is empty.
+    pub(crate) fn check_names(&self) -> Result<()> {
+        if let Some(name) = &self.name {
+            check_ident(name)?;
+        }
+        match &self.kind {
+            ConstraintKind::PrimaryKey(columns) | ConstraintKind::Unique(columns) => {
+                columns.iter().try_for_each(|c| check_ident(c))
+            }
+            ConstraintKind::ForeignKey {
+                columns,
+                ref_schema,
+                ref_table,
+                ref_columns,
+                ..
+            } => {
+                check_qualified(ref_schema.as_deref(), ref_table)?;
+                columns
+                    .iter()
+                    .chain(ref_columns)
+                    .try_for_each(|c| check_ident(c))
+            }
+            ConstraintKind::Check(_) => Ok(()),
+        }
+    }
+
+    /// Names the constraint (CONSTRAINT name ...).
+    pub fn named(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Sets the ON DELETE action. Only applies to foreign keys.
+    pub fn on_delete(mut self, action: ReferentialAction) -> Self {
+        if let ConstraintKind::ForeignKey {
+            ref mut on_delete, ..
+        } = self.kind
+        {
+            *on_delete = Some(action);
+        }
+        self
+    }
+
+    /// Sets the ON UPDATE action. Only applies to foreign keys.
+    pub fn on_update(mut self, action: ReferentialAction) -> Self {
+        if let ConstraintKind::ForeignKey {
+            ref mut on_update, ..
+        } = self.kind
+        {
+            *on_update = Some(action);
+        }
+        self
+    }
+
+    /// Builds the constraint fragment used in CREATE TABLE and ALTER TABLE ADD.
+    pub fn build_sql(&self) -> String {
+        let mut sql = String::new();
+
+        if let Some(ref name) = self.name {
+            sql.push_str("CONSTRAINT ");
+            sql.push_str(&quote_ident(name));
+            sql.push(' ');
+        }
+
+        match &self.kind {
+            ConstraintKind::PrimaryKey(columns) => {
+                sql.push_str(&format!("PRIMARY KEY ({})", quote_list(columns)));
+            }
+            ConstraintKind::Unique(columns) => {
+                sql.push_str(&format!("UNIQUE ({})", quote_list(columns)));
+            }
+            ConstraintKind::ForeignKey {
+                columns,
+                ref_schema,
+                ref_table,
+                ref_columns,
+                on_delete,
+                on_update,
+            } => {
+                sql.push_str(&format!(
+                    "FOREIGN KEY ({}) REFERENCES {} ({})",
+                    quote_list(columns),
+                    quote_qualified(ref_schema.as_deref(), ref_table),
+                    quote_list(ref_columns)
+                ));
+                if let Some(action) = on_delete {
+                    sql.push_str(" ON DELETE ");
+                    sql.push_str(action.as_sql());
+                }
+                if let Some(action) = on_update {
+                    sql.push_str(" ON UPDATE ");
+                    sql.push_str(action.as_sql());
+                }
+            }
+            ConstraintKind::Check(expression) => {
+                sql.push_str(&format!("CHECK ({})", expression));
+            }
+        }
+
+        sql
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dynamic::DynTable;
+    use crate::traits::Table;
+
+    struct Users;
+    struct UsersId;
+    struct OrdersUserId;
+
+    impl Table for Users {
+        type Columns = ();
+        fn table_name() -> &'static str {
+            "users"
+        }
+        fn schema() -> Option<&'static str> {
+            Some("app")
+        }
+        fn columns() -> Self::Columns {}
+    }
+
+    impl Column for UsersId {
+        fn column_name(&self) -> &'static str {
+            "id"
+        }
+        fn table_name(&self) -> &'static str {
+            "users"
+        }
+    }
+
+    impl Column for OrdersUserId {
+        fn column_name(&self) -> &'static str {
+            "user_id"
+        }
+        fn table_name(&self) -> &'static str {
+            "orders"
+        }
+    }
+
+    #[test]
+    fn test_named_primary_key() {
+        let constraint = Constraint::primary_key(&[&UsersId]).named("users_pkey");
+        assert_eq!(
+            constraint.build_sql(),
+            "CONSTRAINT users_pkey PRIMARY KEY (id)"
+        );
+    }
+
+    #[test]
+    fn test_foreign_key_with_actions() {
+        let constraint = Constraint::foreign_key(&[&OrdersUserId], Users, &[&UsersId])
+            .on_delete(ReferentialAction::Cascade)
+            .on_update(ReferentialAction::NoAction);
+        assert_eq!(
+            constraint.build_sql(),
+            "FOREIGN KEY (user_id) REFERENCES app.users (id) ON DELETE CASCADE ON UPDATE NO ACTION"
+        );
+    }
+
+    #[test]
+    fn test_foreign_key_to_dyn_table() {
+        let users = DynTable::new("users").unwrap().schema("app").unwrap();
+        let constraint = Constraint::foreign_key(&[&OrdersUserId], users, &[&UsersId]);
+        assert_eq!(
+            constraint.build_sql(),
+            "FOREIGN KEY (user_id) REFERENCES app.users (id)"
+        );
+    }
+
+    #[test]
+    fn test_check() {
+        let constraint = Constraint::check("price > 0").named("positive_price");
+        assert_eq!(
+            constraint.build_sql(),
+            "CONSTRAINT positive_price CHECK (price > 0)"
+        );
+    }
+}
@@ -0,0 +1,190 @@
+use crate::error::{PgRsError, Result};
+use crate::ident::{check_ident, check_qualified, quote_ident, quote_qualified};
+use crate::traits::{Column, TableSource};
+
+use super::statement::{quote_list, DdlStatement};
+
+/// Builder for CREATE INDEX statements.
+#[derive(Debug, Clone)]
+pub struct CreateIndexBuilder {
+    name: String,
+    table_schema: Option<String>,
+    table: Option<String>,
+    columns: Vec<String>,
+    unique: bool,
+    concurrently: bool,
+    if_not_exists: bool,
+    method: Option<String>,
+}
+
+impl CreateIndexBuilder {
+    /// Start a CREATE INDEX statement for an index with the given name.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            table_schema: None,
+            table: None,
+            columns: Vec::new(),
+            unique: false,
+            concurrently: false,
+            if_not_exists: false,
+            method: None,
+        }
+    }
+
+    /// Specify the table the index is created on.
+    pub fn on<T: TableSource>(mut self, table: T) -> Self {
+        self.table_schema = table.schema_name().map(str::to_string);
+        self.table = Some(table.name().to_string());
+        self
+    }
+
+    /// Specify the indexed columns.
+    pub fn columns(mut self, cols: &[&dyn Column]) -> Self {
+        self.columns = cols.iter().map(|c| c.column_name().to_string()).collect();
+        self
+    }
+
+    /// Create a UNIQUE index.
+    pub fn unique(mut self) -> Self {
+        self.unique = true;
+        self
+    }
+
+    /// Build the index without locking out writes (CONCURRENTLY).
+    /// Note that PostgreSQL does not allow this inside a transaction block.
+    pub fn concurrently(mut self) -> Self {
+        self.concurrently = true;
+        self
+    }
+
+    /// Add IF NOT EXISTS to the statement.
+    pub fn if_not_exists(mut self) -> Self {
+        self.if_not_exists = true;
+        self
+    }
+
+    /// Set the index method (USING btree, gin, gist, ...).
+    pub fn using(mut self, method: impl Into<String>) -> Self {
+        self.method = Some(method.into());
+        self
+    }
+}
+
+impl DdlStatement for CreateIndexBuilder {
+    fn build_sql(&self) -> Result<String> {
+        let table = self.table.as_ref().ok_or(PgRsError::NoTableSpecified)?;
+        if self.columns.is_empty() {
+            return Err(PgRsError::NoColumnsSpecified);
+        }
+        check_ident(&self.name)?;
+        check_qualified(self.table_schema.as_deref(), table)?;
+        self.columns.iter().try_for_each(|c| check_ident(c))?;
+
+        let mut sql = String::from("CREATE ");
+        if self.unique {
+            sql.push_str("UNIQUE ");
+        }
+        sql.push_str("INDEX ");
+        if self.concurrently {
+            sql.push_str("CONCURRENTLY ");
+        }
+        if self.if_not_exists {
+            sql.push_str("IF NOT EXISTS ");
+        }
+        sql.push_str(&quote_ident(&self.name));
+        sql.push_str(" ON ");
+        sql.push_str(&quote_qualified(self.table_schema.as_deref(), table));
+        if let Some(ref method) = self.method {
+            sql.push_str(" USING ");
+            sql.push_str(method);
+        }
+        sql.push_str(" (");
+        sql.push_str(&quote_list(&self.columns));
+        sql.push(')');
+        Ok(sql)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::traits::Table;
+
+    struct Users;
+    struct UsersId;
+    struct UsersName;
+
+    impl Table for Users {
+        type Columns = ();
+        fn table_name() -> &'static str {
+            "users"
+        }
+        fn columns() -> Self::Columns {}
+    }
+
+    impl Column for UsersId {
+        fn column_name(&self) -> &'static str {
+            "id"
+        }
+        fn table_name(&self) -> &'static str {
+            "users"
+        }
+    }
+
+    impl Column for UsersName {
+        fn column_name(&self) -> &'static str {
+            "name"
+        }
+        fn table_name(&self) -> &'static str {
+            "users"
+        }
+    }
+
+    #[test]
+    fn test_create_index() {
+        let sql = CreateIndexBuilder::new("users_name_idx")
+            .on(Users)
+            .columns(&[&UsersName])
+            .build_sql()
+            .unwrap();
+        assert_eq!(sql, "CREATE INDEX users_name_idx ON users (name)");
+    }
+
+    #[test]
+    fn test_create_unique_index_concurrently() {
+        let sql = CreateIndexBuilder::new("users_id_name_idx")
+            .on(Users)
+            .columns(&[&UsersId, &UsersName])
+            .unique()
+            .concurrently()
+            .if_not_exists()
+            .using("btree")
+            .build_sql()
+            .unwrap();
+        assert_eq!(
+            sql,
+            "CREATE UNIQUE INDEX CONCURRENTLY IF NOT EXISTS users_id_name_idx \
+             ON users USING btree (id, name)"
+        );
+    }
+
+    #[test]
+    fn test_create_index_fails_without_table() {
+        let err = CreateIndexBuilder::new("idx")
+            .columns(&[&UsersId])
+            .build_sql()
+            .unwrap_err();
+        assert!(matches!(err, PgRsError::NoTableSpecified));
+    }
+
+    #[test]
+    fn test_create_index_rejects_empty_names() {
+        let err = CreateIndexBuilder::new("")
+            .on(Users)
+            .columns(&[&UsersId])
+            .build_sql()
+            .unwrap_err();
+        assert!(matches!(err, PgRsError::InvalidIdentifier(_)));
+    }
+}
@@ -0,0 +1,367 @@
+use crate::error::{PgRsError, Result};
+use crate::ident::{check_qualified, quote_qualified};
+use crate::traits::TableSource;
+
+use super::column_def::ColumnDef;
+use super::constraint::{Constraint, ConstraintKind};
+use super::statement::DdlStatement;
+use super::table_def::TableDef;
+
+/// Builder for CREATE TABLE statements.
+///
+/// # Example
+/// ```ignore
+/// let sql = CreateTableBuilder::for_table(Users)
+///     .if_not_exists()
+///     .column(ColumnDef::for_column(&Users::columns().id, PgType::BigSerial).primary_key())
+///     .column(ColumnDef::for_column(&Users::columns().name, PgType::Text).not_null())
+///     .build_sql()?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct CreateTableBuilder {
+    schema: Option<String>,
+    name: String,
+    if_not_exists: bool,
+    columns: Vec<ColumnDef>,
+    constraints: Vec<Constraint>,
+}
+
+impl CreateTableBuilder {
+    /// Start a CREATE TABLE statement for a table with the given name.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            schema: None,
+            name: name.into(),
+            if_not_exists: false,
+            columns: Vec::new(),
+            constraints: Vec::new(),
+        }
+    }
+
+    /// Start a CREATE TABLE statement named after a `Table` or `DynTable`.
+    pub fn for_table<T: TableSource>(table: T) -> Self {
+        let builder = Self::new(table.name());
+        match table.schema_name() {
+            Some(schema) => builder.schema(schema),
+            None => builder,
+        }
+    }
+
+    /// Start a CREATE TABLE statement with every column of a table,
+    /// generated from the columns' type and constraint metadata.
+    pub fn from_table<T: TableSource>(table: T) -> Result<Self> {
+        Ok(Self::from_def(&TableDef::from_source(&table)?))
+    }
+
+    /// Start a CREATE TABLE statement from a table definition.
+    ///
+    /// A single primary key column is declared inline; a composite primary
+    /// key is emitted as a table-level constraint.
+    pub fn from_def(def: &TableDef) -> Self {
+        let mut builder = Self::new(def.name.clone());
+        builder.schema = def.schema.clone();
+        builder.columns = def.columns.clone();
+
+        let primary_key: Vec<String> = def
+            .columns
+            .iter()
+            .filter(|c| c.primary_key)
+            .map(|c| c.name.clone())
+            .collect();
+
+        if primary_key.len() > 1 {
+            for column in builder.columns.iter_mut().filter(|c| c.primary_key) {
+                column.primary_key = false;
+                column.nullable = false;
+            }
+            builder.constraints.push(Constraint {
+                name: None,
+                kind: ConstraintKind::PrimaryKey(primary_key),
+            });
+        }
+
+        builder
+    }
+
+    /// Set the schema the table is created in.
+    pub fn schema(mut self, schema: impl Into<String>) -> Self {
+        self.schema = Some(schema.into());
+        self
+    }
+
+    /// Add IF NOT EXISTS to the statement.
+    pub fn if_not_exists(mut self) -> Self {
+        self.if_not_exists = true;
+        self
+    }
+
+    /// Add a column definition.
+    pub fn column(mut self, column: ColumnDef) -> Self {
+        self.columns.push(column);
+        self
+    }
+
+    /// Add a table-level constraint.
+    pub fn constraint(mut self, constraint: Constraint) -> Self {
+        self.constraints.push(constraint);
+        self
+    }
+}
+
+impl DdlStatement for CreateTableBuilder {
+    fn build_sql(&self) -> Result<String> {
+        if self.columns.is_empty() {
+            return Err(PgRsError::NoColumnsSpecified);
+        }
+        check_qualified(self.schema.as_deref(), &self.name)?;
+        for column in &self.columns {
+            column.check_names()?;
+        }
+        for constraint in &self.constraints {
+            constraint.check_names()?;
+        }
+
+        let mut sql = String::with_capacity(256);
+        sql.push_str("CREATE TABLE ");
+        if self.if_not_exists {
+            sql.push_str("IF NOT EXISTS ");
+        }
+        sql.push_str(&quote_qualified(self.schema.as_deref(), &self.name));
+        sql.push_str(" (");
+
+        let definitions = self
+            .columns
+            .iter()
+            .map(ColumnDef::build_sql)
+            .chain(self.constraints.iter().map(Constraint::build_sql));
+        for (i, definition) in definitions.enumerate() {
+            if i > 0 {
+                sql.push_str(", ");
+            }
+            sql.push_str(&definition);
+        }
+
+        sql.push(')');
+        Ok(sql)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dynamic::DynTable;
+    use crate::traits::{Column, Table};
+    use crate::types::PgType;
+
+    struct Users;
+    struct UsersColumns {
+        pub id: UsersId,
+        pub name: UsersName,
+    }
+    struct UsersId;
+    struct UsersName;
+
+    impl Table for Users {
+        type Columns = UsersColumns;
+        fn table_name() -> &'static str {
+            "users"
+        }
+        fn schema() -> Option<&'static str> {
+            Some("app")
+        }
+        fn columns() -> Self::Columns {
+            UsersColumns {
+                id: UsersId,
+                name: UsersName,
+            }
+        }
+        fn all_columns() -> Vec<&'static dyn Column> {
+            vec![&UsersId, &UsersName]
+        }
+    }
+
+    impl Column for UsersId {
+        fn column_name(&self) -> &'static str {
+            "id"
+        }
+        fn table_name(&self) -> &'static str {
+            "users"
+        }
+        fn sql_type(&self) -> Option<PgType> {
+            Some(PgType::BigSerial)
+        }
+        fn is_nullable(&self) -> bool {
+            false
+        }
+        fn is_primary_key(&self) -> bool {
+            true
+        }
+    }
+
+    impl Column for UsersName {
+        fn column_name(&self) -> &'static str {
+            "name"
+        }
+        fn table_name(&self) -> &'static str {
+            "users"
+        }
+        fn sql_type(&self) -> Option<PgType> {
+            Some(PgType::Text)
+        }
+    }
+
+    // Join table with a composite primary key
+    struct Memberships;
+    struct MembershipsUserId;
+    struct MembershipsGroupId;
+
+    impl Table for Memberships {
+        type Columns = ();
+        fn table_name() -> &'static str {
+            "memberships"
+        }
+        fn columns() -> Self::Columns {}
+        fn all_columns() -> Vec<&'static dyn Column> {
+            vec![&MembershipsUserId, &MembershipsGroupId]
+        }
+    }
+
+    impl Column for MembershipsUserId {
+        fn column_name(&self) -> &'static str {
+            "user_id"
+        }
+        fn table_name(&self) -> &'static str {
+            "memberships"
+        }
+        fn sql_type(&self) -> Option<PgType> {
+            Some(PgType::BigInt)
+        }
+        fn is_primary_key(&self) -> bool {
+            true
+        }
+    }
+
+    impl Column for MembershipsGroupId {
+        fn column_name(&self) -> &'static str {
+            "group_id"
+        }
+        fn table_name(&self) -> &'static str {
+            "memberships"
+        }
+        fn sql_type(&self) -> Option<PgType> {
+            Some(PgType::BigInt)
+        }
+        fn is_primary_key(&self) -> bool {
+            true
+        }
+    }
+
+    #[test]
+    fn test_create_table() {
+        let sql = CreateTableBuilder::for_table(Users)
+            .column(ColumnDef::for_column(&Users::columns().id, PgType::BigSerial).primary_key())
+            .column(ColumnDef::for_column(&Users::columns().name, PgType::Text).not_null())
+            .build_sql()
+            .unwrap();
+        assert_eq!(
+            sql,
+            "CREATE TABLE app.users (id bigserial PRIMARY KEY, name text NOT NULL)"
+        );
+    }
+
+    #[test]
+    fn test_create_table_for_dyn_table() {
+        let table = DynTable::new("audit").unwrap().schema("Reporting").unwrap();
+        let sql = CreateTableBuilder::for_table(table)
+            .column(ColumnDef::new("entry", PgType::Text).not_null())
+            .build_sql()
+            .unwrap();
+        assert_eq!(
+            sql,
+            "CREATE TABLE \"Reporting\".audit (entry text NOT NULL)"
+        );
+    }
+
+    #[test]
+    fn test_create_table_with_constraints() {
+        let sql = CreateTableBuilder::new("memberships")
+            .if_not_exists()
+            .column(ColumnDef::new("user_id", PgType::BigInt).not_null())
+            .column(ColumnDef::new("name", PgType::Text))
+            .constraint(Constraint::unique(&[&Users::columns().name]))
+            .constraint(Constraint::check("length(name) > 0").named("name_not_empty"))
+            .build_sql()
+            .unwrap();
+        assert_eq!(
+            sql,
+            "CREATE TABLE IF NOT EXISTS memberships (user_id bigint NOT NULL, name text, \
+             UNIQUE (name), CONSTRAINT name_not_empty CHECK (length(name) > 0))"
+        );
+    }
+
+    #[test]
+    fn test_create_table_from_metadata() {
+        let sql = CreateTableBuilder::from_table(Users)
+            .unwrap()
+            .build_sql()
+            .unwrap();
+        assert_eq!(
+            sql,
+            "CREATE TABLE app.users (id bigserial PRIMARY KEY, name text)"
+        );
+    }
+
+    #[test]
+    fn test_create_table_from_metadata_composite_key() {
+        let sql = CreateTableBuilder::from_table(Memberships)
+            .unwrap()
+            .build_sql()
+            .unwrap();
+        assert_eq!(
+            sql,
+            "CREATE TABLE memberships (user_id bigint NOT NULL, group_id bigint NOT NULL, \
+             PRIMARY KEY (user_id, group_id))"
+        );
+    }
+
+    #[test]
+    fn test_create_table_quotes_identifiers() {
+        let sql = CreateTableBuilder::new("order")
+            .schema("Sales")
+            .column(ColumnDef::new("user", PgType::Text).not_null())
+            .column(ColumnDef::new("Total", PgType::Numeric(None)))
+            .constraint(Constraint {
+                name: Some("order_user_key".to_string()),
+                kind: ConstraintKind::Unique(vec!["user".to_string()]),
+            })
+            .build_sql()
+            .unwrap();
+        assert_eq!(
+            sql,
+            "CREATE TABLE \"Sales\".\"order\" (\"user\" text NOT NULL, \"Total\" numeric, \
+             CONSTRAINT order_user_key UNIQUE (\"user\"))"
+        );
+    }
+
+    #[test]
+    fn test_create_table_rejects_empty_names() {
+        let column = || ColumnDef::new("entry", PgType::Text);
+        for builder in [
+            CreateTableBuilder::new("").column(column()),
+            CreateTableBuilder::new("audit").schema("").column(column()),
+            CreateTableBuilder::new("audit").column(ColumnDef::new("", PgType::Text)),
+            CreateTableBuilder::new("audit")
+                .column(column())
+                .constraint(Constraint::check("true").named("")),
+        ] {
+            let err = builder.build_sql().unwrap_err();
+            assert!(matches!(err, PgRsError::InvalidIdentifier(_)));
+        }
+    }
+
+    #[test]
+    fn test_create_table_fails_without_columns() {
+        let err = CreateTableBuilder::new("empty").build_sql().unwrap_err();
+        assert!(matches!(err, PgRsError::NoColumnsSpecified));
+    }
+}
@@ -0,0 +1,407 @@
+use crate::error::Result;
+use crate::ident::quote_ident;
+use crate::migrations::MIGRATIONS_TABLE;
+use crate::types::PgType;
+
+use super::alter_table::{AlterAction, AlterTableBuilder, ColumnChange};
+use super::column_def::ColumnDef;
+use super::create_table::CreateTableBuilder;
+use super::drift::{same_type, DEFAULT_SCHEMA};
+use super::drop_table::DropTableBuilder;
+use super::statement::DdlStatement;
+use super::table_def::{DatabaseSchema, TableDef};
+
+/// A single DDL statement needed to bring a database in line with the
+/// declared tables.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SchemaChange {
+    pub sql: String,
+    /// Why the statement needs manual review, if it may lose data.
+    pub review: Option<String>,
+}
+
+/// The statements needed to bring a database in line with the declared
+/// tables, in the order they should run.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SchemaDiff {
+    pub changes: Vec<SchemaChange>,
+}
+
+impl SchemaDiff {
+    /// Returns true if no changes are needed.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Returns true if any change was flagged for manual review.
+    pub fn needs_review(&self) -> bool {
+        self.changes.iter().any(|c| c.review.is_some())
+    }
+
+    /// Renders the changes as a migration file.
+    ///
+    /// Changes flagged for review are commented out behind a
+    /// `-- MANUAL REVIEW:` line so they only run once someone enables them.
+    pub fn to_migration_sql(&self) -> String {
+        let mut sql = String::from("-- Generated by pgrs diff.\n");
+        for change in &self.changes {
+            sql.push('\n');
+            match &change.review {
+                Some(reason) => {
+                    sql.push_str(&format!("-- MANUAL REVIEW: {}\n", reason));
+                    sql.push_str(&format!("-- {};\n", change.sql));
+                }
+                None => sql.push_str(&format!("{};\n", change.sql)),
+            }
+        }
+        sql
+    }
+
+    fn push(&mut self, statement: &dyn DdlStatement, review: Option<String>) -> Result<()> {
+        self.changes.push(SchemaChange {
+            sql: statement.build_sql()?,
+            review,
+        });
+        Ok(())
+    }
+}
+
+/// Compares declared tables against a database schema and returns the DDL
+/// that makes the database match.
+///
+/// Missing tables and columns are created, column types and nullability are
+/// altered, and columns and tables the declarations no longer mention are
+/// dropped. Drops, type changes that are not plain widenings, new NOT NULL
+/// constraints and new NOT NULL columns without a default are flagged for
+/// review. Type changes that are not widenings convert existing values with
+/// a `USING` cast. Tables without a schema are looked up
+/// in `public`, and only the schemas of declared tables are checked for
+/// dropped tables. Defaults, keys and constraints of existing columns are not
+/// compared.
+pub fn diff(declared: &[TableDef], actual: &DatabaseSchema) -> Result<SchemaDiff> {
+    let mut diff = SchemaDiff::default();
+
+    for table in declared {
+        let schema = table.schema.as_deref().unwrap_or(DEFAULT_SCHEMA);
+        let Some(actual_table) = find_table(actual, schema, &table.name) else {
+            diff.push(&CreateTableBuilder::from_def(table), None)?;
+            continue;
+        };
+        let display = format!("{}.{}", schema, table.name);
+
+        for column in &table.columns {
+            let Some(actual_column) = actual_table.column(&column.name) else {
+                let review = needs_value(column).then(|| {
+                    format!(
+                        "adds {}.{} as NOT NULL without a default, which fails if the table has rows",
+                        display, column.name
+                    )
+                });
+                diff.push(
+                    &alter(table, AlterAction::AddColumn(column.clone())),
+                    review,
+                )?;
+                continue;
+            };
+            let alter_column = |change| {
+                alter(
+                    table,
+                    AlterAction::AlterColumn {
+                        name: column.name.clone(),
+                        change,
+                    },
+                )
+            };
+
+            if !same_type(&column.sql_type, &actual_column.sql_type) {
+                let sql_type = column.sql_type.normalized();
+                if is_widening(&actual_column.sql_type, &column.sql_type) {
+                    diff.push(&alter_column(ColumnChange::SetType(sql_type)), None)?;
+                } else {
+                    let review = format!(
+                        "changes {}.{} from {} to {}, which may fail or lose data",
+                        display, column.name, actual_column.sql_type, column.sql_type
+                    );
+                    let using = format!("{}::{}", quote_ident(&column.name), sql_type);
+                    let change = ColumnChange::SetTypeUsing { sql_type, using };
+                    diff.push(&alter_column(change), Some(review))?;
+                }
+            }
+
+            let nullable = column.nullable && !column.primary_key;
+            if nullable && !actual_column.nullable {
+                diff.push(&alter_column(ColumnChange::DropNotNull), None)?;
+            } else if !nullable && actual_column.nullable {
+                let review = format!(
+                    "sets {}.{} NOT NULL, which fails if it contains NULLs",
+                    display, column.name
+                );
+                diff.push(&alter_column(ColumnChange::SetNotNull), Some(review))?;
+            }
+        }
+
+        for actual_column in &actual_table.columns {
+            if table.column(&actual_column.name).is_none() {
+                let review = format!(
+                    "drops column {}.{} and its data",
+                    display, actual_column.name
+                );
+                let drop = AlterAction::DropColumn {
+                    name: actual_column.name.clone(),
+                    cascade: false,
+                };
+                diff.push(&alter(table, drop), Some(review))?;
+            }
+        }
+    }
+
+    let mut schemas: Vec<&str> = declared
+        .iter()
+        .map(|t| t.schema.as_deref().unwrap_or(DEFAULT_SCHEMA))
+        .collect();
+    schemas.sort_unstable();
+    schemas.dedup();
+
+    for table in &actual.tables {
+        let schema = table.schema.as_deref().unwrap_or(DEFAULT_SCHEMA);
+        let is_declared = declared.iter().any(|t| {
+            t.schema.as_deref().unwrap_or(DEFAULT_SCHEMA) == schema && t.name == table.name
+        });
+        if is_declared || !schemas.contains(&schema) || table.name == MIGRATIONS_TABLE {
+            continue;
+        }
+
+        let mut drop = DropTableBuilder::new(table.name.clone());
+        if let Some(schema) = &table.schema {
+            drop = drop.schema(schema.clone());
+        }
+        let review = format!("drops table {}.{} and its data", schema, table.name);
+        diff.push(&drop, Some(review))?;
+    }
+
+    Ok(diff)
+}
+
+fn find_table<'a>(
+    schema: &'a DatabaseSchema,
+    schema_name: &str,
+    name: &str,
+) -> Option<&'a TableDef> {
+    schema
+        .tables
+        .iter()
+        .find(|t| t.schema.as_deref().unwrap_or(DEFAULT_SCHEMA) == schema_name && t.name == name)
+}
+
+fn alter(table: &TableDef, action: AlterAction) -> AlterTableBuilder {
+    let builder = AlterTableBuilder::new(table.name.clone()).action(action);
+    match &table.schema {
+        Some(schema) => builder.schema(schema.clone()),
+        None => builder,
+    }
+}
+
+/// Returns true if adding the column needs a value for every existing row:
+/// it is NOT NULL and has no default to fill it with.
+fn needs_value(column: &ColumnDef) -> bool {
+    let serial = matches!(
+        column.sql_type,
+        PgType::SmallSerial | PgType::Serial | PgType::BigSerial
+    );
+    (!column.nullable || column.primary_key) && column.default.is_none() && !serial
+}
+
+/// Returns true if every value of type `from` converts to type `to` without
+/// loss.
+fn is_widening(from: &PgType, to: &PgType) -> bool {
+    use PgType::*;
+
+    match (from.normalized(), to.normalized()) {
+        (SmallInt, Integer | BigInt | Numeric(None)) => true,
+        (Integer, BigInt | Numeric(None)) => true,
+        (BigInt, Numeric(None)) => true,
+        (Real, DoublePrecision) => true,
+        (Numeric(Some(_)), Numeric(None)) => true,
+        (Numeric(Some((p1, s1))), Numeric(Some((p2, s2)))) => {
+            // Both the fractional and the integer digits must not shrink
+            s2 >= s1 && i64::from(p2) - i64::from(s2) >= i64::from(p1) - i64::from(s1)
+        }
+        (Varchar(Some(n1)), Varchar(Some(n2))) => n2 >= n1,
+        (Varchar(_), Varchar(None) | Text) => true,
+        (Char(_), Text) => true,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::ColumnDef;
+
+    fn users(columns: Vec<ColumnDef>) -> TableDef {
+        TableDef {
+            schema: Some("app".to_string()),
+            name: "users".to_string(),
+            columns,
+        }
+    }
+
+    fn sqls(diff: &SchemaDiff) -> Vec<(&str, bool)> {
+        diff.changes
+            .iter()
+            .map(|c| (c.sql.as_str(), c.review.is_some()))
+            .collect()
+    }
+
+    #[test]
+    fn test_diff_creates_missing_tables() {
+        let declared = [users(vec![
+            ColumnDef::new("id", PgType::BigInt).primary_key()
+        ])];
+        let diff = diff(&declared, &DatabaseSchema::default()).unwrap();
+        assert_eq!(
+            sqls(&diff),
+            [("CREATE TABLE app.users (id bigint PRIMARY KEY)", false)]
+        );
+        assert!(!diff.needs_review());
+    }
+
+    #[test]
+    fn test_diff_alters_existing_tables() {
+        let declared = [users(vec![
+            ColumnDef::new("id", PgType::BigInt).primary_key(),
+            ColumnDef::new("email", PgType::Varchar(Some(255))).not_null(),
+            ColumnDef::new("age", PgType::SmallInt),
+            ColumnDef::new("bio", PgType::Text),
+            ColumnDef::new("nickname", PgType::Text),
+            ColumnDef::new("status", PgType::Text).not_null(),
+            ColumnDef::new("joined_at", PgType::TimestampTz)
+                .not_null()
+                .default("now()"),
+        ])];
+        let actual = DatabaseSchema {
+            tables: vec![
+                users(vec![
+                    ColumnDef::new("id", PgType::Integer)
+                        .not_null()
+                        .primary_key(),
+                    ColumnDef::new("email", PgType::Varchar(Some(100))),
+                    ColumnDef::new("age", PgType::Integer),
+                    ColumnDef::new("bio", PgType::Text).not_null(),
+                    ColumnDef::new("legacy", PgType::Text),
+                ]),
+                TableDef {
+                    schema: Some("app".to_string()),
+                    name: "old_sessions".to_string(),
+                    columns: Vec::new(),
+                },
+                TableDef {
+                    schema: Some("app".to_string()),
+                    name: MIGRATIONS_TABLE.to_string(),
+                    columns: Vec::new(),
+                },
+                TableDef {
+                    schema: Some("other".to_string()),
+                    name: "unrelated".to_string(),
+                    columns: Vec::new(),
+                },
+            ],
+            enums: Vec::new(),
+        };
+
+        let diff = diff(&declared, &actual).unwrap();
+        assert_eq!(
+            sqls(&diff),
+            [
+                (
+                    "ALTER TABLE app.users ALTER COLUMN id SET DATA TYPE bigint",
+                    false
+                ),
+                (
+                    "ALTER TABLE app.users ALTER COLUMN email SET DATA TYPE character varying(255)",
+                    false
+                ),
+                (
+                    "ALTER TABLE app.users ALTER COLUMN email SET NOT NULL",
+                    true
+                ),
+                (
+                    "ALTER TABLE app.users ALTER COLUMN age SET DATA TYPE smallint USING age::smallint",
+                    true
+                ),
+                (
+                    "ALTER TABLE app.users ALTER COLUMN bio DROP NOT NULL",
+                    false
+                ),
+                ("ALTER TABLE app.users ADD COLUMN nickname text", false),
+                ("ALTER TABLE app.users ADD COLUMN status text NOT NULL", true),
+                (
+                    "ALTER TABLE app.users ADD COLUMN joined_at timestamp with time zone NOT NULL DEFAULT now()",
+                    false
+                ),
+                ("ALTER TABLE app.users DROP COLUMN legacy", true),
+                ("DROP TABLE app.old_sessions", true),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_diff_without_changes() {
+        let declared = [users(vec![
+            ColumnDef::new("id", PgType::BigSerial).primary_key(),
+            ColumnDef::new("name", PgType::Text),
+        ])];
+        let actual = DatabaseSchema {
+            tables: vec![users(vec![
+                ColumnDef::new("id", PgType::BigInt)
+                    .not_null()
+                    .primary_key(),
+                ColumnDef::new("name", PgType::Text),
+            ])],
+            enums: Vec::new(),
+        };
+        assert!(diff(&declared, &actual).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_to_migration_sql() {
+        let diff = SchemaDiff {
+            changes: vec![
+                SchemaChange {
+                    sql: "ALTER TABLE users ADD COLUMN bio text".to_string(),
+                    review: None,
+                },
+                SchemaChange {
+                    sql: "ALTER TABLE users DROP COLUMN legacy".to_string(),
+                    review: Some("drops column public.users.legacy and its data".to_string()),
+                },
+            ],
+        };
+        assert_eq!(
+            diff.to_migration_sql(),
+            "-- Generated by pgrs diff.\n\
+             \n\
+             ALTER TABLE users ADD COLUMN bio text;\n\
+             \n\
+             -- MANUAL REVIEW: drops column public.users.legacy and its data\n\
+             -- ALTER TABLE users DROP COLUMN legacy;\n"
+        );
+    }
+
+    #[test]
+    fn test_is_widening() {
+        assert!(is_widening(&PgType::Integer, &PgType::BigInt));
+        assert!(is_widening(&PgType::Varchar(Some(10)), &PgType::Text));
+        assert!(is_widening(
+            &PgType::Numeric(Some((10, 2))),
+            &PgType::Numeric(Some((12, 2)))
+        ));
+        assert!(!is_widening(&PgType::BigInt, &PgType::Integer));
+        assert!(!is_widening(&PgType::Text, &PgType::Varchar(Some(10))));
+        assert!(!is_widening(
+            &PgType::Numeric(Some((10, 2))),
+            &PgType::Numeric(Some((10, 4)))
+        ));
+        assert!(!is_widening(&PgType::Text, &PgType::Integer));
+    }
+}
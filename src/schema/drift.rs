@@ -0,0 +1,292 @@
+use std::fmt;
+
+use crate::error::{PgRsError, Result};
+use crate::traits::DatabaseDriver;
+use crate::types::PgType;
+
+use super::introspect::introspect;
+use super::table_def::{DatabaseSchema, TableDef};
+
+/// Schema assumed for tables that do not declare one.
+pub(super) const DEFAULT_SCHEMA: &str = "public";
+
+/// A single difference between a declared table and the database.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DriftIssue {
+    /// The table does not exist in the database.
+    MissingTable { table: String },
+    /// The column does not exist in the database table.
+    MissingColumn { table: String, column: String },
+    /// The column exists with a different type.
+    TypeMismatch {
+        table: String,
+        column: String,
+        expected: PgType,
+        actual: PgType,
+    },
+    /// The column exists with different nullability.
+    NullabilityMismatch {
+        table: String,
+        column: String,
+        expected_nullable: bool,
+        actual_nullable: bool,
+    },
+}
+
+impl fmt::Display for DriftIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DriftIssue::MissingTable { table } => write!(f, "missing table {}", table),
+            DriftIssue::MissingColumn { table, column } => {
+                write!(f, "missing column {}.{}", table, column)
+            }
+            DriftIssue::TypeMismatch {
+                table,
+                column,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "type mismatch on {}.{}: expected {}, found {}",
+                table, column, expected, actual
+            ),
+            DriftIssue::NullabilityMismatch {
+                table,
+                column,
+                expected_nullable,
+                ..
+            } => {
+                let (expected, actual) = if *expected_nullable {
+                    ("NULL", "NOT NULL")
+                } else {
+                    ("NOT NULL", "NULL")
+                };
+                write!(
+                    f,
+                    "nullability mismatch on {}.{}: expected {}, found {}",
+                    table, column, expected, actual
+                )
+            }
+        }
+    }
+}
+
+/// The result of comparing declared tables against a database.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DriftReport {
+    pub issues: Vec<DriftIssue>,
+}
+
+impl DriftReport {
+    /// Returns true if no drift was found.
+    pub fn is_empty(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    /// Converts the report into an error if any drift was found.
+    pub fn into_result(self) -> Result<()> {
+        if self.is_empty() {
+            Ok(())
+        } else {
+            Err(PgRsError::SchemaDrift(self.to_string()))
+        }
+    }
+}
+
+impl fmt::Display for DriftReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, issue) in self.issues.iter().enumerate() {
+            if i > 0 {
+                f.write_str("; ")?;
+            }
+            write!(f, "{}", issue)?;
+        }
+        Ok(())
+    }
+}
+
+/// Compares declared tables against a database schema.
+///
+/// Tables without a schema are looked up in `public`. Columns that exist
+/// only in the database are not reported, since they do not break queries
+/// issued by the declared code.
+pub fn compare(declared: &[TableDef], actual: &DatabaseSchema) -> DriftReport {
+    let mut report = DriftReport::default();
+
+    for table in declared {
+        let schema = table.schema.as_deref().unwrap_or(DEFAULT_SCHEMA);
+        let display = format!("{}.{}", schema, table.name);
+
+        let Some(actual_table) = actual.table(Some(schema), &table.name) else {
+            report
+                .issues
+                .push(DriftIssue::MissingTable { table: display });
+            continue;
+        };
+
+        for column in &table.columns {
+            let Some(actual_column) = actual_table.column(&column.name) else {
+                report.issues.push(DriftIssue::MissingColumn {
+                    table: display.clone(),
+                    column: column.name.clone(),
+                });
+                continue;
+            };
+
+            if !same_type(&column.sql_type, &actual_column.sql_type) {
+                report.issues.push(DriftIssue::TypeMismatch {
+                    table: display.clone(),
+                    column: column.name.clone(),
+                    expected: column.sql_type.clone(),
+                    actual: actual_column.sql_type.clone(),
+                });
+            }
+
+            // Primary key columns are implicitly NOT NULL
+            let expected_nullable = column.nullable && !column.primary_key;
+            if expected_nullable != actual_column.nullable {
+                report.issues.push(DriftIssue::NullabilityMismatch {
+                    table: display.clone(),
+                    column: column.name.clone(),
+                    expected_nullable,
+                    actual_nullable: actual_column.nullable,
+                });
+            }
+        }
+    }
+
+    report
+}
+
+/// Reads the database catalog and compares the declared tables against it.
+pub async fn check_drift(
+    driver: &dyn DatabaseDriver,
+    declared: &[TableDef],
+) -> Result<DriftReport> {
+    let mut schemas: Vec<&str> = declared
+        .iter()
+        .map(|t| t.schema.as_deref().unwrap_or(DEFAULT_SCHEMA))
+        .collect();
+    schemas.sort_unstable();
+    schemas.dedup();
+
+    let actual = introspect(driver, &schemas).await?;
+    Ok(compare(declared, &actual))
+}
+
+/// Compares types, treating serial types as their storage type and
+/// user-defined type names as equal regardless of schema qualification.
+pub(super) fn same_type(expected: &PgType, actual: &PgType) -> bool {
+    match (expected.normalized(), actual.normalized()) {
+        (PgType::Custom(expected), PgType::Custom(actual)) => {
+            unqualified(&expected) == unqualified(&actual)
+        }
+        (PgType::Array(expected), PgType::Array(actual)) => same_type(&expected, &actual),
+        (expected, actual) => expected == actual,
+    }
+}
+
+fn unqualified(name: &str) -> String {
+    let name = name.rsplit_once('.').map_or(name, |(_, name)| name);
+    name.replace('"', "")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::ColumnDef;
+
+    fn declared() -> Vec<TableDef> {
+        vec![
+            TableDef {
+                schema: Some("app".to_string()),
+                name: "users".to_string(),
+                columns: vec![
+                    ColumnDef::new("id", PgType::BigSerial).primary_key(),
+                    ColumnDef::new("email", PgType::Text).not_null(),
+                    ColumnDef::new("age", PgType::Integer),
+                    ColumnDef::new("mood", PgType::Custom("mood".to_string())),
+                    ColumnDef::new("nickname", PgType::Text),
+                ],
+            },
+            TableDef {
+                schema: None,
+                name: "audit_log".to_string(),
+                columns: vec![ColumnDef::new("id", PgType::BigInt)],
+            },
+        ]
+    }
+
+    fn actual() -> DatabaseSchema {
+        DatabaseSchema {
+            tables: vec![TableDef {
+                schema: Some("app".to_string()),
+                name: "users".to_string(),
+                columns: vec![
+                    ColumnDef::new("id", PgType::BigInt).not_null(),
+                    ColumnDef::new("email", PgType::Text),
+                    ColumnDef::new("age", PgType::BigInt),
+                    ColumnDef::new("mood", PgType::Custom("app.mood".to_string())),
+                    ColumnDef::new("legacy", PgType::Text),
+                ],
+            }],
+            enums: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_compare_reports_drift() {
+        let report = compare(&declared(), &actual());
+        assert_eq!(
+            report.issues,
+            vec![
+                DriftIssue::NullabilityMismatch {
+                    table: "app.users".to_string(),
+                    column: "email".to_string(),
+                    expected_nullable: false,
+                    actual_nullable: true,
+                },
+                DriftIssue::TypeMismatch {
+                    table: "app.users".to_string(),
+                    column: "age".to_string(),
+                    expected: PgType::Integer,
+                    actual: PgType::BigInt,
+                },
+                DriftIssue::MissingColumn {
+                    table: "app.users".to_string(),
+                    column: "nickname".to_string(),
+                },
+                DriftIssue::MissingTable {
+                    table: "public.audit_log".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_compare_without_drift() {
+        let declared = &declared()[..1];
+        let mut actual = actual();
+        let users = &mut actual.tables[0];
+        users.columns[1].nullable = false;
+        users.columns[2].sql_type = PgType::Integer;
+        users.columns.push(ColumnDef::new("nickname", PgType::Text));
+
+        let report = compare(declared, &actual);
+        assert!(report.is_empty(), "{}", report);
+        assert!(report.into_result().is_ok());
+    }
+
+    #[test]
+    fn test_report_into_result() {
+        let err = compare(&declared(), &actual()).into_result().unwrap_err();
+        match err {
+            PgRsError::SchemaDrift(message) => {
+                assert!(message
+                    .contains("type mismatch on app.users.age: expected integer, found bigint"));
+                assert!(message.contains("missing table public.audit_log"));
+            }
+            _ => panic!("Expected SchemaDrift error"),
+        }
+    }
+}
@@ -0,0 +1,99 @@
+use crate::error::Result;
+use crate::ident::{check_qualified, quote_qualified};
+use crate::traits::TableSource;
+
+use super::statement::DdlStatement;
+
+/// Builder for DROP TABLE statements.
+#[derive(Debug, Clone)]
+pub struct DropTableBuilder {
+    schema: Option<String>,
+    name: String,
+    if_exists: bool,
+    cascade: bool,
+}
+
+impl DropTableBuilder {
+    /// Start a DROP TABLE statement for a table with the given name.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            schema: None,
+            name: name.into(),
+            if_exists: false,
+            cascade: false,
+        }
+    }
+
+    /// Start a DROP TABLE statement for a `Table` or `DynTable`.
+    pub fn for_table<T: TableSource>(table: T) -> Self {
+        let builder = Self::new(table.name());
+        match table.schema_name() {
+            Some(schema) => builder.schema(schema),
+            None => builder,
+        }
+    }
+
+    /// Set the schema of the table to drop.
+    pub fn schema(mut self, schema: impl Into<String>) -> Self {
+        self.schema = Some(schema.into());
+        self
+    }
+
+    /// Add IF EXISTS to the statement.
+    pub fn if_exists(mut self) -> Self {
+        self.if_exists = true;
+        self
+    }
+
+    /// Add CASCADE to drop dependent objects as well.
+    pub fn cascade(mut self) -> Self {
+        self.cascade = true;
+        self
+    }
+}
+
+impl DdlStatement for DropTableBuilder {
+    fn build_sql(&self) -> Result<String> {
+        check_qualified(self.schema.as_deref(), &self.name)?;
+        let mut sql = String::from("DROP TABLE ");
+        if self.if_exists {
+            sql.push_str("IF EXISTS ");
+        }
+        sql.push_str(&quote_qualified(self.schema.as_deref(), &self.name));
+        if self.cascade {
+            sql.push_str(" CASCADE");
+        }
+        Ok(sql)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_drop_table() {
+        let sql = DropTableBuilder::new("users").build_sql().unwrap();
+        assert_eq!(sql, "DROP TABLE users");
+    }
+
+    #[test]
+    fn test_drop_table_if_exists_cascade() {
+        let sql = DropTableBuilder::new("users")
+            .schema("app")
+            .if_exists()
+            .cascade()
+            .build_sql()
+            .unwrap();
+        assert_eq!(sql, "DROP TABLE IF EXISTS app.users CASCADE");
+    }
+
+    #[test]
+    fn test_drop_table_rejects_empty_names() {
+        assert!(DropTableBuilder::new("").build_sql().is_err());
+        assert!(DropTableBuilder::new("users")
+            .schema("")
+            .build_sql()
+            .is_err());
+    }
+}
@@ -0,0 +1,301 @@
+use crate::error::{PgRsError, Result};
+use crate::traits::DatabaseDriver;
+use crate::types::{PgType, RawQueryResult, SqlValue};
+
+use super::column_def::ColumnDef;
+use super::table_def::{DatabaseSchema, EnumDef, TableDef};
+
+/// Reads the tables, columns, keys and enum types of the given schemas from
+/// `information_schema` and `pg_catalog`.
+///
+/// Tables are ordered by schema and name, columns by their position in the
+/// table, and enum labels by their sort order, so the result is stable.
+pub async fn introspect(driver: &dyn DatabaseDriver, schemas: &[&str]) -> Result<DatabaseSchema> {
+    if schemas.is_empty() {
+        return Ok(DatabaseSchema::default());
+    }
+
+    let params: Vec<SqlValue> = schemas.iter().map(|s| SqlValue::from(*s)).collect();
+    let placeholders = (1..=schemas.len())
+        .map(|i| format!("${}", i))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let columns = driver
+        .execute(&columns_query(&placeholders), &params)
+        .await?;
+    let keys = driver.execute(&keys_query(&placeholders), &params).await?;
+    let enums = driver.execute(&enums_query(&placeholders), &params).await?;
+
+    let mut schema = DatabaseSchema::default();
+
+    for row in ResultRows::new(&columns) {
+        let table_schema = row.get("table_schema")?;
+        let table_name = row.get("table_name")?;
+
+        let needs_new_table = match schema.tables.last() {
+            Some(last) => last.schema.as_deref() != Some(table_schema) || last.name != table_name,
+            None => true,
+        };
+        if needs_new_table {
+            schema.tables.push(TableDef {
+                schema: Some(table_schema.to_string()),
+                name: table_name.to_string(),
+                columns: Vec::new(),
+            });
+        }
+
+        let mut column = ColumnDef::new(
+            row.get("column_name")?,
+            row.get("data_type")?.parse::<PgType>().unwrap(),
+        );
+        column.nullable = row.get("is_nullable")? == "YES";
+        let default = row.get("column_default")?;
+        if !default.is_empty() {
+            column.default = Some(default.to_string());
+        }
+        schema.tables.last_mut().unwrap().columns.push(column);
+    }
+
+    for row in ResultRows::new(&keys) {
+        let table_schema = row.get("table_schema")?;
+        let table_name = row.get("table_name")?;
+        let table = schema
+            .tables
+            .iter_mut()
+            .find(|t| t.schema.as_deref() == Some(table_schema) && t.name == table_name);
+        let Some(table) = table else {
+            continue;
+        };
+        let column_name = row.get("column_name")?;
+        let Some(column) = table.columns.iter_mut().find(|c| c.name == column_name) else {
+            continue;
+        };
+        match row.get("constraint_type")? {
+            "PRIMARY KEY" => column.primary_key = true,
+            // Multi-column unique constraints cannot be expressed per column
+            "UNIQUE" if row.get("column_count")? == "1" => column.unique = true,
+            _ => {}
+        }
+    }
+
+    for row in ResultRows::new(&enums) {
+        let enum_schema = row.get("enum_schema")?;
+        let enum_name = row.get("enum_name")?;
+
+        let needs_new_enum = match schema.enums.last() {
+            Some(last) => last.schema.as_deref() != Some(enum_schema) || last.name != enum_name,
+            None => true,
+        };
+        if needs_new_enum {
+            schema.enums.push(EnumDef {
+                schema: Some(enum_schema.to_string()),
+                name: enum_name.to_string(),
+                labels: Vec::new(),
+            });
+        }
+        schema
+            .enums
+            .last_mut()
+            .unwrap()
+            .labels
+            .push(row.get("label")?.to_string());
+    }
+
+    Ok(schema)
+}
+
+fn columns_query(placeholders: &str) -> String {
+    format!(
+        "SELECT c.table_schema::text AS table_schema, c.table_name::text AS table_name, \
+         c.column_name::text AS column_name, \
+         format_type(a.atttypid, a.atttypmod) AS data_type, \
+         c.is_nullable::text AS is_nullable, \
+         COALESCE(c.column_default::text, '') AS column_default \
+         FROM information_schema.columns c \
+         JOIN information_schema.tables t \
+         ON t.table_schema = c.table_schema AND t.table_name = c.table_name \
+         JOIN pg_catalog.pg_namespace n ON n.nspname = c.table_schema \
+         JOIN pg_catalog.pg_class cl ON cl.relnamespace = n.oid AND cl.relname = c.table_name \
+         JOIN pg_catalog.pg_attribute a ON a.attrelid = cl.oid AND a.attname = c.column_name \
+         WHERE t.table_type = 'BASE TABLE' AND c.table_schema IN ({}) \
+         ORDER BY c.table_schema, c.table_name, c.ordinal_position",
+        placeholders
+    )
+}
+
+fn keys_query(placeholders: &str) -> String {
+    format!(
+        "SELECT tc.table_schema::text AS table_schema, tc.table_name::text AS table_name, \
+         kcu.column_name::text AS column_name, tc.constraint_type::text AS constraint_type, \
+         (SELECT count(*) FROM information_schema.key_column_usage k \
+         WHERE k.constraint_schema = tc.constraint_schema \
+         AND k.constraint_name = tc.constraint_name)::text AS column_count \
+         FROM information_schema.table_constraints tc \
+         JOIN information_schema.key_column_usage kcu \
+         ON kcu.constraint_schema = tc.constraint_schema \
+         AND kcu.constraint_name = tc.constraint_name \
+         WHERE tc.constraint_type IN ('PRIMARY KEY', 'UNIQUE') AND tc.table_schema IN ({}) \
+         ORDER BY tc.table_schema, tc.table_name, tc.constraint_name, kcu.ordinal_position",
+        placeholders
+    )
+}
+
+fn enums_query(placeholders: &str) -> String {
+    format!(
+        "SELECT n.nspname::text AS enum_schema, t.typname::text AS enum_name, \
+         e.enumlabel::text AS label \
+         FROM pg_catalog.pg_type t \
+         JOIN pg_catalog.pg_enum e ON e.enumtypid = t.oid \
+         JOIN pg_catalog.pg_namespace n ON n.oid = t.typnamespace \
+         WHERE n.nspname IN ({}) \
+         ORDER BY n.nspname, t.typname, e.enumsortorder",
+        placeholders
+    )
+}
+
+/// Name-based access to the rows of a catalog query.
+struct ResultRows<'a> {
+    result: &'a RawQueryResult,
+    index: usize,
+}
+
+impl<'a> ResultRows<'a> {
+    fn new(result: &'a RawQueryResult) -> Self {
+        Self { result, index: 0 }
+    }
+}
+
+impl<'a> Iterator for ResultRows<'a> {
+    type Item = ResultRow<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let values = self.result.rows.get(self.index)?;
+        self.index += 1;
+        Some(ResultRow {
+            columns: &self.result.columns,
+            values,
+        })
+    }
+}
+
+struct ResultRow<'a> {
+    columns: &'a [String],
+    values: &'a [SqlValue],
+}
+
+impl<'a> ResultRow<'a> {
+    /// Gets a text column; the catalog queries cast every column to text.
+    fn get(&self, name: &str) -> Result<&'a str> {
+        let value = self
+            .columns
+            .iter()
+            .position(|c| c == name)
+            .and_then(|i| self.values.get(i))
+            .ok_or_else(|| PgRsError::ColumnNotFound(name.to_string()))?;
+        match value {
+            SqlValue::Text(text) => Ok(text),
+            _ => Err(PgRsError::ConversionFailed {
+                column: name.to_string(),
+                target: "text",
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::drivers::{InMemoryTestDriver, InMemoryTestResponseBuilder};
+
+    fn catalog_driver() -> InMemoryTestDriver {
+        InMemoryTestDriver::new()
+            .with_response(
+                InMemoryTestResponseBuilder::new()
+                    .columns(&[
+                        "table_schema",
+                        "table_name",
+                        "column_name",
+                        "data_type",
+                        "is_nullable",
+                        "column_default",
+                    ])
+                    .row(&[
+                        "app",
+                        "users",
+                        "id",
+                        "bigint",
+                        "NO",
+                        "nextval('app.users_id_seq'::regclass)",
+                    ])
+                    .row(&["app", "users", "email", "text", "NO", ""])
+                    .row(&["app", "users", "mood", "app.mood", "YES", ""])
+                    .row(&["app", "tags", "label", "character varying(32)", "YES", ""])
+                    .build(),
+            )
+            .with_response(
+                InMemoryTestResponseBuilder::new()
+                    .columns(&[
+                        "table_schema",
+                        "table_name",
+                        "column_name",
+                        "constraint_type",
+                        "column_count",
+                    ])
+                    .row(&["app", "users", "id", "PRIMARY KEY", "1"])
+                    .row(&["app", "users", "email", "UNIQUE", "1"])
+                    .build(),
+            )
+            .with_response(
+                InMemoryTestResponseBuilder::new()
+                    .columns(&["enum_schema", "enum_name", "label"])
+                    .row(&["app", "mood", "happy"])
+                    .row(&["app", "mood", "sad"])
+                    .build(),
+            )
+    }
+
+    #[tokio::test]
+    async fn test_introspect() {
+        let driver = catalog_driver();
+        let schema = introspect(&driver, &["app"]).await.unwrap();
+
+        assert_eq!(schema.tables.len(), 2);
+        let users = schema.table(Some("app"), "users").unwrap();
+        assert_eq!(users.columns.len(), 3);
+
+        let id = users.column("id").unwrap();
+        assert_eq!(id.sql_type, PgType::BigInt);
+        assert!(!id.nullable);
+        assert!(id.primary_key);
+        assert_eq!(
+            id.default.as_deref(),
+            Some("nextval('app.users_id_seq'::regclass)")
+        );
+
+        assert!(users.column("email").unwrap().unique);
+        assert_eq!(
+            users.column("mood").unwrap().sql_type,
+            PgType::Custom("app.mood".to_string())
+        );
+
+        let tags = schema.table(Some("app"), "tags").unwrap();
+        assert_eq!(tags.columns[0].sql_type, PgType::Varchar(Some(32)));
+
+        let mood = schema.enum_type(Some("app"), "mood").unwrap();
+        assert_eq!(mood.labels, ["happy", "sad"]);
+
+        let queries = driver.recorded_queries();
+        assert_eq!(queries.len(), 3);
+        assert_eq!(queries[0].params, [SqlValue::Text("app".to_string())]);
+        assert!(queries[0].sql.contains("IN ($1)"));
+    }
+
+    #[tokio::test]
+    async fn test_introspect_without_schemas() {
+        let driver = InMemoryTestDriver::new();
+        let schema = introspect(&driver, &[]).await.unwrap();
+        assert_eq!(schema, DatabaseSchema::default());
+        driver.assert_query_count(0);
+    }
+}
@@ -0,0 +1,24 @@
+mod alter_table;
+mod column_def;
+mod constraint;
+mod create_index;
+mod create_table;
+mod diff;
+mod drift;
+mod drop_table;
+mod introspect;
+mod snapshot;
+mod statement;
+mod table_def;
+
+pub use alter_table::{AlterAction, AlterTableBuilder, ColumnChange};
+pub use column_def::{ColumnDef, ColumnReference};
+pub use constraint::{Constraint, ConstraintKind, ReferentialAction};
+pub use create_index::CreateIndexBuilder;
+pub use create_table::CreateTableBuilder;
+pub use diff::{diff, SchemaChange, SchemaDiff};
+pub use drift::{check_drift, compare, DriftIssue, DriftReport};
+pub use drop_table::DropTableBuilder;
+pub use introspect::introspect;
+pub use statement::DdlStatement;
+pub use table_def::{DatabaseSchema, EnumDef, TableDef};
@@ -0,0 +1,219 @@
+use crate::error::{PgRsError, Result};
+use crate::types::PgType;
+
+use super::column_def::ColumnDef;
+use super::table_def::{DatabaseSchema, EnumDef, TableDef};
+
+const HEADER: &str = "# pgrs schema snapshot v1";
+
+impl DatabaseSchema {
+    /// Renders the schema as a snapshot that `from_snapshot` reads back.
+    ///
+    /// The format is line-based and tab-separated so snapshots diff cleanly
+    /// in version control. It records what `diff` compares: tables, column
+    /// types, nullability, keys and defaults, and enum labels.
+    pub fn to_snapshot(&self) -> String {
+        let mut out = format!("{}\n", HEADER);
+
+        for table in &self.tables {
+            push_line(
+                &mut out,
+                &["table", table.schema.as_deref().unwrap_or(""), &table.name],
+            );
+            for column in &table.columns {
+                let mut flags = Vec::new();
+                if !column.nullable {
+                    flags.push("not_null");
+                }
+                if column.primary_key {
+                    flags.push("primary_key");
+                }
+                if column.unique {
+                    flags.push("unique");
+                }
+                push_line(
+                    &mut out,
+                    &[
+                        "column",
+                        &column.name,
+                        &column.sql_type.to_string(),
+                        &flags.join(" "),
+                        column.default.as_deref().unwrap_or(""),
+                    ],
+                );
+            }
+        }
+
+        for enum_def in &self.enums {
+            let mut fields = vec![
+                "enum",
+                enum_def.schema.as_deref().unwrap_or(""),
+                &enum_def.name,
+            ];
+            fields.extend(enum_def.labels.iter().map(String::as_str));
+            push_line(&mut out, &fields);
+        }
+
+        out
+    }
+
+    /// Reads a snapshot written by `to_snapshot`.
+    pub fn from_snapshot(snapshot: &str) -> Result<Self> {
+        let mut lines = snapshot.lines().enumerate();
+        match lines.next() {
+            Some((_, HEADER)) => {}
+            _ => return Err(PgRsError::InvalidSnapshot("missing header".to_string())),
+        }
+
+        let mut schema = DatabaseSchema::default();
+        for (index, line) in lines {
+            let invalid = || PgRsError::InvalidSnapshot(format!("line {}: {}", index + 1, line));
+            if line.is_empty() {
+                continue;
+            }
+
+            let fields: Vec<String> = line.split('\t').map(unescape).collect();
+            let optional = |field: &String| (!field.is_empty()).then(|| field.clone());
+
+            match fields[0].as_str() {
+                "table" if fields.len() == 3 => schema.tables.push(TableDef {
+                    schema: optional(&fields[1]),
+                    name: fields[2].clone(),
+                    columns: Vec::new(),
+                }),
+                "column" if fields.len() == 5 => {
+                    let table = schema.tables.last_mut().ok_or_else(invalid)?;
+                    let mut column =
+                        ColumnDef::new(fields[1].clone(), fields[2].parse::<PgType>().unwrap());
+                    for flag in fields[3].split_whitespace() {
+                        match flag {
+                            "not_null" => column.nullable = false,
+                            "primary_key" => column.primary_key = true,
+                            "unique" => column.unique = true,
+                            _ => return Err(invalid()),
+                        }
+                    }
+                    column.default = optional(&fields[4]);
+                    table.columns.push(column);
+                }
+                "enum" if fields.len() >= 3 => schema.enums.push(EnumDef {
+                    schema: optional(&fields[1]),
+                    name: fields[2].clone(),
+                    labels: fields[3..].to_vec(),
+                }),
+                _ => return Err(invalid()),
+            }
+        }
+
+        Ok(schema)
+    }
+}
+
+fn push_line(out: &mut String, fields: &[&str]) {
+    let fields: Vec<String> = fields.iter().map(|f| escape(f)).collect();
+    out.push_str(&fields.join("\t"));
+    out.push('\n');
+}
+
+fn escape(field: &str) -> String {
+    field
+        .replace('\\', "\\\\")
+        .replace('\t', "\\t")
+        .replace('\n', "\\n")
+}
+
+fn unescape(field: &str) -> String {
+    let mut out = String::with_capacity(field.len());
+    let mut chars = field.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('t') => out.push('\t'),
+            Some('n') => out.push('\n'),
+            Some(other) => out.push(other),
+            None => out.push('\\'),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn schema() -> DatabaseSchema {
+        DatabaseSchema {
+            tables: vec![
+                TableDef {
+                    schema: Some("app".to_string()),
+                    name: "users".to_string(),
+                    columns: vec![
+                        ColumnDef::new("id", PgType::BigSerial).primary_key(),
+                        ColumnDef::new("email", PgType::Varchar(Some(255)))
+                            .not_null()
+                            .unique(),
+                        ColumnDef::new("tags", PgType::Array(Box::new(PgType::Text)))
+                            .default("'{}'::text[]"),
+                        ColumnDef::new("mood", PgType::Custom("app.mood".to_string())),
+                        ColumnDef::new("note", PgType::Text).default("E'a\\tb\\nc'"),
+                    ],
+                },
+                TableDef {
+                    schema: None,
+                    name: "tags".to_string(),
+                    columns: vec![ColumnDef::new("label", PgType::Text).not_null()],
+                },
+            ],
+            enums: vec![EnumDef {
+                schema: Some("app".to_string()),
+                name: "mood".to_string(),
+                labels: vec!["happy".to_string(), "very sad".to_string()],
+            }],
+        }
+    }
+
+    #[test]
+    fn test_snapshot_round_trip() {
+        let schema = schema();
+        let snapshot = schema.to_snapshot();
+        assert_eq!(DatabaseSchema::from_snapshot(&snapshot).unwrap(), schema);
+    }
+
+    #[test]
+    fn test_snapshot_format() {
+        let snapshot = schema().to_snapshot();
+        let lines: Vec<&str> = snapshot.lines().collect();
+        assert_eq!(lines[0], HEADER);
+        assert_eq!(lines[1], "table\tapp\tusers");
+        assert_eq!(lines[2], "column\tid\tbigserial\tprimary_key\t");
+        assert_eq!(
+            lines[3],
+            "column\temail\tcharacter varying(255)\tnot_null unique\t"
+        );
+        assert_eq!(lines[6], "column\tnote\ttext\t\tE'a\\\\tb\\\\nc'");
+        assert_eq!(lines[7], "table\t\ttags");
+        assert_eq!(lines[9], "enum\tapp\tmood\thappy\tvery sad");
+    }
+
+    #[test]
+    fn test_invalid_snapshots() {
+        for snapshot in [
+            "",
+            "table\tapp\tusers\n",
+            "# pgrs schema snapshot v1\ncolumn\tid\tbigint\t\t\n",
+            "# pgrs schema snapshot v1\ntable\tapp\n",
+            "# pgrs schema snapshot v1\ntable\tapp\tusers\ncolumn\tid\tbigint\tnullable\t\n",
+        ] {
+            match DatabaseSchema::from_snapshot(snapshot) {
+                Err(PgRsError::InvalidSnapshot(_)) => {}
+                other => panic!(
+                    "Expected InvalidSnapshot for {:?}, got {:?}",
+                    snapshot, other
+                ),
+            }
+        }
+    }
+}
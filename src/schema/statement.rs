@@ -0,0 +1,20 @@
+use crate::error::Result;
+use crate::ident::quote_ident;
+
+/// A DDL statement that can be rendered to SQL.
+///
+/// DDL statements take no parameters, so the rendered SQL can be executed
+/// directly or written to a migration file.
+pub trait DdlStatement {
+    /// Builds the SQL for this statement.
+    fn build_sql(&self) -> Result<String>;
+}
+
+/// Renders a comma-separated list of quoted identifiers.
+pub(crate) fn quote_list(names: &[String]) -> String {
+    names
+        .iter()
+        .map(|name| quote_ident(name))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
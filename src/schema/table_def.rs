@@ -0,0 +1,81 @@
+use crate::error::Result;
+use crate::traits::{Column, Table, TableSource};
+
+use super::column_def::ColumnDef;
+use super::create_table::CreateTableBuilder;
+
+/// A runtime description of a table: its name and column definitions.
+///
+/// Produced either from a compiled `Table` or by introspecting a database,
+/// so the two can be compared or turned into DDL.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TableDef {
+    pub schema: Option<String>,
+    pub name: String,
+    pub columns: Vec<ColumnDef>,
+}
+
+impl TableDef {
+    /// Describes a compiled Table from its column metadata.
+    pub fn of<T: Table>() -> Result<Self> {
+        Self::from_parts(T::schema(), T::table_name(), T::all_columns())
+    }
+
+    /// Describes a `Table` or `DynTable` from its declared columns.
+    pub fn from_source<T: TableSource>(table: &T) -> Result<Self> {
+        Self::from_parts(table.schema_name(), table.name(), table.declared_columns())
+    }
+
+    fn from_parts(schema: Option<&str>, name: &str, columns: Vec<&dyn Column>) -> Result<Self> {
+        let columns = columns
+            .into_iter()
+            .map(ColumnDef::from_column)
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self {
+            schema: schema.map(str::to_string),
+            name: name.to_string(),
+            columns,
+        })
+    }
+
+    /// Returns the column with the given name, if any.
+    pub fn column(&self, name: &str) -> Option<&ColumnDef> {
+        self.columns.iter().find(|c| c.name == name)
+    }
+
+    /// Returns a CREATE TABLE statement for this table.
+    pub fn create_table(&self) -> CreateTableBuilder {
+        CreateTableBuilder::from_def(self)
+    }
+}
+
+/// A PostgreSQL enum type and its labels in sort order.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EnumDef {
+    pub schema: Option<String>,
+    pub name: String,
+    pub labels: Vec<String>,
+}
+
+/// The tables and enum types of one or more database schemas.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DatabaseSchema {
+    pub tables: Vec<TableDef>,
+    pub enums: Vec<EnumDef>,
+}
+
+impl DatabaseSchema {
+    /// Returns the table with the given schema and name, if any.
+    pub fn table(&self, schema: Option<&str>, name: &str) -> Option<&TableDef> {
+        self.tables
+            .iter()
+            .find(|t| t.schema.as_deref() == schema && t.name == name)
+    }
+
+    /// Returns the enum type with the given schema and name, if any.
+    pub fn enum_type(&self, schema: Option<&str>, name: &str) -> Option<&EnumDef> {
+        self.enums
+            .iter()
+            .find(|e| e.schema.as_deref() == schema && e.name == name)
+    }
+}
@@ -1,15 +1,59 @@
+use crate::error::Result;
+use crate::ident::{check_ident, quote_ident, quote_qualified};
+use crate::types::PgType;
+
 /// Trait representing a database column.
-/// Implementations are typically generated from schema definitions.
+/// Implementations are typically generated from schema definitions, or built
+/// at runtime with `DynColumn`.
+///
+/// Only `column_name` and `table_name` are required. The remaining methods
+/// describe the column's schema and default to a nullable column of unknown
+/// type; implementations should override them when the metadata is known.
 pub trait Column {
     /// Returns the column name as it appears in the database.
-    fn column_name(&self) -> &'static str;
+    fn column_name(&self) -> &str;
 
     /// Returns the table name this column belongs to.
-    fn table_name(&self) -> &'static str;
+    fn table_name(&self) -> &str;
+
+    /// Returns the schema of the column's table, if any.
+    fn schema_name(&self) -> Option<&str> {
+        None
+    }
 
-    /// Returns the fully qualified column name (table.column).
+    /// Returns the fully qualified, quoted column name
+    /// (schema.table.column or table.column).
     fn qualified_name(&self) -> String {
-        format!("{}.{}", self.table_name(), self.column_name())
+        format!(
+            "{}.{}",
+            quote_qualified(self.schema_name(), self.table_name()),
+            quote_ident(self.column_name())
+        )
+    }
+
+    /// Returns the PostgreSQL type of the column, if known.
+    fn sql_type(&self) -> Option<PgType> {
+        None
+    }
+
+    /// Returns true if the column accepts NULL values.
+    fn is_nullable(&self) -> bool {
+        true
+    }
+
+    /// Returns the column's DEFAULT expression, if any.
+    fn default_expression(&self) -> Option<&str> {
+        None
+    }
+
+    /// Returns true if the column is part of the table's primary key.
+    fn is_primary_key(&self) -> bool {
+        false
+    }
+
+    /// Returns true if the column has a UNIQUE constraint of its own.
+    fn is_unique(&self) -> bool {
+        false
     }
 }
 
@@ -17,26 +61,72 @@ pub trait Column {
 /// This allows storing column information without requiring the original Column type.
 #[derive(Debug, Clone, PartialEq)]
 pub struct ColumnRef {
+    pub schema: Option<String>,
     pub table: String,
     pub column: String,
 }
 
 impl ColumnRef {
-    pub fn new(table: impl Into<String>, column: impl Into<String>) -> Self {
-        Self {
-            table: table.into(),
-            column: column.into(),
-        }
+    /// Creates a column reference, rejecting empty names.
+    pub fn new(table: impl Into<String>, column: impl Into<String>) -> Result<Self> {
+        let (table, column) = (table.into(), column.into());
+        check_ident(&table)?;
+        check_ident(&column)?;
+        Ok(Self {
+            schema: None,
+            table,
+            column,
+        })
+    }
+
+    /// Sets the schema of the column's table, rejecting an empty name.
+    pub fn schema(mut self, schema: impl Into<String>) -> Result<Self> {
+        let schema = schema.into();
+        check_ident(&schema)?;
+        self.schema = Some(schema);
+        Ok(self)
     }
 
     pub fn from_column<C: Column + ?Sized>(col: &C) -> Self {
         Self {
+            schema: col.schema_name().map(str::to_string),
             table: col.table_name().to_string(),
             column: col.column_name().to_string(),
         }
     }
 
+    /// Returns the fully qualified, quoted column name.
     pub fn qualified_name(&self) -> String {
-        format!("{}.{}", self.table, self.column)
+        format!(
+            "{}.{}",
+            quote_qualified(self.schema.as_deref(), &self.table),
+            quote_ident(&self.column)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::PgRsError;
+
+    #[test]
+    fn test_column_ref_rejects_empty_names() {
+        let column = ColumnRef::new("users", "order")
+            .unwrap()
+            .schema("app")
+            .unwrap();
+        assert_eq!(column.qualified_name(), "app.users.\"order\"");
+
+        for err in [
+            ColumnRef::new("", "id").unwrap_err(),
+            ColumnRef::new("users", "").unwrap_err(),
+            ColumnRef::new("users", "id")
+                .unwrap()
+                .schema("")
+                .unwrap_err(),
+        ] {
+            assert!(matches!(err, PgRsError::InvalidIdentifier(_)));
+        }
     }
 }
@@ -1,16 +1,34 @@
 use async_trait::async_trait;
 
-use crate::error::Result;
+use crate::error::{PgRsError, Result};
 use crate::types::{RawQueryResult, SqlValue};
 
 /// Trait for database driver implementations.
 /// Drivers are responsible for:
 /// - Connecting to the database
 /// - Converting SqlValue parameters to native types
-/// - Executing queries and converting results to RawQueryResult
+/// - Executing queries and decoding results into RawQueryResult, according
+///   to each column's declared type
 #[async_trait]
 pub trait DatabaseDriver: Send + Sync {
     /// Execute a SQL query with the given parameters.
     /// Parameters use PostgreSQL-style placeholders ($1, $2, etc.)
     async fn execute(&self, sql: &str, params: &[SqlValue]) -> Result<RawQueryResult>;
+
+    /// Execute one or more semicolon-separated statements without parameters.
+    /// Drivers should override this if `execute` only accepts a single statement.
+    async fn batch_execute(&self, sql: &str) -> Result<()> {
+        self.execute(sql, &[]).await.map(|_| ())
+    }
+
+    /// Opens a connection for the caller alone, for work that spans several
+    /// statements of one session, such as a transaction or an advisory lock.
+    /// The connection is closed when the returned driver is dropped.
+    ///
+    /// Fails by default, for drivers that cannot open separate connections.
+    async fn dedicated(&self) -> Result<Box<dyn DatabaseDriver + '_>> {
+        Err(PgRsError::ConnectionFailed(
+            "this driver cannot open dedicated connections".to_string(),
+        ))
+    }
 }
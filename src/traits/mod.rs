@@ -4,4 +4,4 @@ mod table;
 
 pub use column::{Column, ColumnRef};
 pub use driver::DatabaseDriver;
-pub use table::Table;
+pub use table::{Table, TableSource};
@@ -1,3 +1,7 @@
+use crate::ident::quote_qualified;
+
+use super::Column;
+
 /// Trait representing a database table.
 /// Implementations are typically generated from schema definitions.
 pub trait Table {
@@ -12,14 +16,54 @@ pub trait Table {
         None
     }
 
-    /// Returns the fully qualified table name (schema.table or just table).
+    /// Returns the fully qualified, quoted table name (schema.table or just table).
     fn qualified_name() -> String {
-        match Self::schema() {
-            Some(schema) => format!("{}.{}", schema, Self::table_name()),
-            None => Self::table_name().to_string(),
-        }
+        quote_qualified(Self::schema(), Self::table_name())
     }
 
     /// Returns an instance of the columns accessor for this table.
     fn columns() -> Self::Columns;
+
+    /// Returns every column of this table in declaration order.
+    /// Defaults to an empty list for tables without column metadata.
+    fn all_columns() -> Vec<&'static dyn Column> {
+        Vec::new()
+    }
+}
+
+/// Anything a query can read from: a compiled `Table` or a runtime `DynTable`.
+///
+/// Implemented for every `Table`, so builders accepting a `TableSource`
+/// accept compiled tables unchanged.
+pub trait TableSource {
+    /// Returns the table name as it appears in the database.
+    fn name(&self) -> &str;
+
+    /// Returns the schema name, if any.
+    fn schema_name(&self) -> Option<&str>;
+
+    /// Returns the fully qualified, quoted table name.
+    fn quoted_name(&self) -> String {
+        quote_qualified(self.schema_name(), self.name())
+    }
+
+    /// Returns the columns of the table with their metadata, as
+    /// `Table::all_columns` does. Empty for tables known only by name.
+    fn declared_columns(&self) -> Vec<&dyn Column> {
+        Vec::new()
+    }
+}
+
+impl<T: Table> TableSource for T {
+    fn name(&self) -> &str {
+        T::table_name()
+    }
+
+    fn schema_name(&self) -> Option<&str> {
+        T::schema()
+    }
+
+    fn declared_columns(&self) -> Vec<&dyn Column> {
+        T::all_columns()
+    }
 }
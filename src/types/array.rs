@@ -0,0 +1,148 @@
+//! PostgreSQL's text representation of arrays, e.g. `{1,2,NULL}` or
+//! `{"a b","c\"d"}`. Drivers that only produce strings render array
+//! columns in this form, and `FromSqlValue` for `Vec<T>` reads it back.
+
+/// Renders one-dimensional array elements as an array literal.
+pub(crate) fn format_array_literal<'a, I>(elements: I) -> String
+where
+    I: IntoIterator<Item = Option<&'a str>>,
+{
+    let elements: Vec<String> = elements.into_iter().map(format_element).collect();
+    format!("{{{}}}", elements.join(","))
+}
+
+/// Renders an element, quoting it if it would otherwise be misread.
+fn format_element(element: Option<&str>) -> String {
+    let Some(element) = element else {
+        return "NULL".to_string();
+    };
+    let needs_quotes = element.is_empty()
+        || element.eq_ignore_ascii_case("null")
+        || element
+            .chars()
+            .any(|c| matches!(c, '{' | '}' | ',' | '"' | '\\') || c.is_whitespace());
+    if !needs_quotes {
+        return element.to_string();
+    }
+
+    let mut quoted = String::with_capacity(element.len() + 2);
+    quoted.push('"');
+    for c in element.chars() {
+        if matches!(c, '"' | '\\') {
+            quoted.push('\\');
+        }
+        quoted.push(c);
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// Parses a one-dimensional array literal. NULL elements are `None`.
+/// Returns `None` if the text is not a one-dimensional array literal.
+pub(crate) fn parse_array_literal(text: &str) -> Option<Vec<Option<String>>> {
+    let inner = text.strip_prefix('{')?.strip_suffix('}')?;
+    let mut elements = Vec::new();
+    if inner.is_empty() {
+        return Some(elements);
+    }
+
+    let mut chars = inner.chars().peekable();
+    loop {
+        if chars.peek() == Some(&'"') {
+            chars.next();
+            let mut element = String::new();
+            loop {
+                match chars.next()? {
+                    '"' => break,
+                    '\\' => element.push(chars.next()?),
+                    c => element.push(c),
+                }
+            }
+            elements.push(Some(element));
+        } else {
+            let mut element = String::new();
+            while let Some(&c) = chars.peek() {
+                if c == ',' {
+                    break;
+                }
+                // Nested arrays and stray quotes are not one-dimensional elements
+                if matches!(c, '{' | '}' | '"') {
+                    return None;
+                }
+                element.push(c);
+                chars.next();
+            }
+            let element = element.trim();
+            if element.is_empty() {
+                return None;
+            }
+            if element.eq_ignore_ascii_case("null") {
+                elements.push(None);
+            } else {
+                elements.push(Some(element.to_string()));
+            }
+        }
+
+        match chars.next() {
+            Some(',') => continue,
+            None => return Some(elements),
+            Some(_) => return None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_array_literal() {
+        assert_eq!(format_array_literal([]), "{}");
+        assert_eq!(
+            format_array_literal([Some("1"), None, Some("3")]),
+            "{1,NULL,3}"
+        );
+        assert_eq!(
+            format_array_literal([Some("a b"), Some(""), Some("null"), Some("q\"\\")]),
+            "{\"a b\",\"\",\"null\",\"q\\\"\\\\\"}"
+        );
+    }
+
+    #[test]
+    fn test_parse_array_literal() {
+        assert_eq!(parse_array_literal("{}"), Some(vec![]));
+        assert_eq!(
+            parse_array_literal("{1,NULL,3}"),
+            Some(vec![Some("1".to_string()), None, Some("3".to_string())])
+        );
+        assert_eq!(
+            parse_array_literal("{\"a b\",\"\",\"null\",\"q\\\"\\\\\"}"),
+            Some(vec![
+                Some("a b".to_string()),
+                Some(String::new()),
+                Some("null".to_string()),
+                Some("q\"\\".to_string()),
+            ])
+        );
+        for invalid in [
+            "",
+            "1,2",
+            "{1,{2}}",
+            "{{1},{2}}",
+            "{1,}",
+            "{\"a}",
+            "{\"a\"b}",
+        ] {
+            assert_eq!(parse_array_literal(invalid), None, "{:?}", invalid);
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let elements = [Some("x"), None, Some("{}"), Some(" padded "), Some("NULL")];
+        let parsed = parse_array_literal(&format_array_literal(elements)).unwrap();
+        let expected: Vec<Option<String>> =
+            elements.iter().map(|e| e.map(str::to_string)).collect();
+        assert_eq!(parsed, expected);
+    }
+}
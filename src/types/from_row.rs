@@ -0,0 +1,44 @@
+use crate::error::Result;
+
+use super::row::Row;
+
+/// Maps a row into a Rust type, for `QueryResult::into_structs` and
+/// `QueryResult::single_as`.
+///
+/// With the `derive` feature, `#[derive(pgrs::FromRow)]` implements this for
+/// structs with named fields, reading each field from the column of the same
+/// name.
+///
+/// # Example
+/// ```
+/// use pgrs::types::{FromRow, QueryResult, RawQueryResult, Row, SqlValue};
+/// use pgrs::Result;
+///
+/// struct User {
+///     id: i64,
+///     email: Option<String>,
+/// }
+///
+/// impl FromRow for User {
+///     fn from_row(row: &Row) -> Result<Self> {
+///         Ok(User {
+///             id: row.get_named_as("id")?,
+///             email: row.get_named_as("email")?,
+///         })
+///     }
+/// }
+///
+/// let raw = RawQueryResult::new(
+///     vec!["id".to_string(), "email".to_string()],
+///     vec![20, 25],
+///     vec![vec![SqlValue::Int64(7), SqlValue::Null]],
+/// );
+/// let user: User = QueryResult::from_raw(raw).single_as().unwrap();
+/// assert_eq!(user.id, 7);
+/// assert_eq!(user.email, None);
+/// ```
+pub trait FromRow: Sized {
+    /// Builds `Self` from the row, failing if a column is missing or cannot
+    /// be converted.
+    fn from_row(row: &Row) -> Result<Self>;
+}
@@ -0,0 +1,477 @@
+use super::array::parse_array_literal;
+use super::sql_value::SqlValue;
+
+/// Converts a value read from a row into a Rust type, for `Row::get_as`.
+///
+/// Values given as text, such as those decoded by drivers that only produce
+/// strings, are parsed. Implement this for your own types to read them
+/// directly from rows.
+///
+/// # Example
+/// ```
+/// use pgrs::types::{FromSqlValue, SqlValue};
+///
+/// struct Cents(i64);
+///
+/// impl FromSqlValue for Cents {
+///     fn from_sql_value(value: &SqlValue) -> Option<Self> {
+///         i64::from_sql_value(value).map(Cents)
+///     }
+/// }
+///
+/// let cents = Cents::from_sql_value(&SqlValue::Text("250".to_string())).unwrap();
+/// assert_eq!(cents.0, 250);
+/// ```
+pub trait FromSqlValue: Sized {
+    /// Converts the value, returning `None` if it cannot be represented as
+    /// `Self`. Only `Option` accepts `SqlValue::Null`.
+    fn from_sql_value(value: &SqlValue) -> Option<Self>;
+}
+
+impl<T: FromSqlValue> FromSqlValue for Option<T> {
+    fn from_sql_value(value: &SqlValue) -> Option<Self> {
+        match value {
+            SqlValue::Null => Some(None),
+            value => T::from_sql_value(value).map(Some),
+        }
+    }
+}
+
+impl FromSqlValue for String {
+    fn from_sql_value(value: &SqlValue) -> Option<Self> {
+        match value {
+            SqlValue::Text(s)
+            | SqlValue::Json(s)
+            | SqlValue::Numeric(s)
+            | SqlValue::Uuid(s)
+            | SqlValue::Date(s)
+            | SqlValue::Time(s)
+            | SqlValue::Timestamp(s)
+            | SqlValue::TimestampTz(s)
+            | SqlValue::Interval(s) => Some(s.clone()),
+            _ => None,
+        }
+    }
+}
+
+impl FromSqlValue for bool {
+    fn from_sql_value(value: &SqlValue) -> Option<Self> {
+        match value {
+            SqlValue::Bool(b) => Some(*b),
+            SqlValue::Text(s) => match s.as_str() {
+                "true" | "t" => Some(true),
+                "false" | "f" => Some(false),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+/// Integers convert from any integer value that fits, or from text.
+macro_rules! impl_from_sql_value_integer {
+    ($($target:ty),*) => {
+        $(
+            impl FromSqlValue for $target {
+                fn from_sql_value(value: &SqlValue) -> Option<Self> {
+                    match value {
+                        SqlValue::Int16(i) => (*i).try_into().ok(),
+                        SqlValue::Int32(i) => (*i).try_into().ok(),
+                        SqlValue::Int64(i) => (*i).try_into().ok(),
+                        SqlValue::Text(s) => s.parse().ok(),
+                        _ => None,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_from_sql_value_integer!(i16, i32, i64, u32, u64);
+
+impl FromSqlValue for f32 {
+    fn from_sql_value(value: &SqlValue) -> Option<Self> {
+        match value {
+            SqlValue::Float32(f) => Some(*f),
+            SqlValue::Int16(i) => Some((*i).into()),
+            SqlValue::Int32(i) => Some(*i as f32),
+            SqlValue::Numeric(s) | SqlValue::Text(s) => s.parse().ok(),
+            _ => None,
+        }
+    }
+}
+
+impl FromSqlValue for f64 {
+    fn from_sql_value(value: &SqlValue) -> Option<Self> {
+        match value {
+            SqlValue::Float32(f) => Some((*f).into()),
+            SqlValue::Float64(f) => Some(*f),
+            SqlValue::Int16(i) => Some((*i).into()),
+            SqlValue::Int32(i) => Some((*i).into()),
+            SqlValue::Int64(i) => Some(*i as f64),
+            SqlValue::Numeric(s) | SqlValue::Text(s) => s.parse().ok(),
+            _ => None,
+        }
+    }
+}
+
+/// Arrays convert element by element, from array values or array literals.
+impl<T: FromSqlValue> FromSqlValue for Vec<T> {
+    fn from_sql_value(value: &SqlValue) -> Option<Self> {
+        match value {
+            SqlValue::Array(elements) => elements.iter().map(T::from_sql_value).collect(),
+            SqlValue::Text(s) => parse_array_literal(s)?
+                .into_iter()
+                .map(|element| T::from_sql_value(&element.map_or(SqlValue::Null, SqlValue::Text)))
+                .collect(),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "uuid")]
+impl FromSqlValue for uuid::Uuid {
+    fn from_sql_value(value: &SqlValue) -> Option<Self> {
+        match value {
+            SqlValue::Uuid(s) | SqlValue::Text(s) => s.parse().ok(),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "rust_decimal")]
+impl FromSqlValue for rust_decimal::Decimal {
+    fn from_sql_value(value: &SqlValue) -> Option<Self> {
+        match value {
+            SqlValue::Numeric(s) | SqlValue::Text(s) => s.parse().ok(),
+            SqlValue::Int16(i) => Some((*i).into()),
+            SqlValue::Int32(i) => Some((*i).into()),
+            SqlValue::Int64(i) => Some((*i).into()),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl FromSqlValue for chrono::NaiveDate {
+    fn from_sql_value(value: &SqlValue) -> Option<Self> {
+        match value {
+            SqlValue::Date(s) | SqlValue::Text(s) => s.parse().ok(),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl FromSqlValue for chrono::NaiveTime {
+    fn from_sql_value(value: &SqlValue) -> Option<Self> {
+        match value {
+            SqlValue::Time(s) | SqlValue::Text(s) => s.parse().ok(),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl FromSqlValue for chrono::NaiveDateTime {
+    fn from_sql_value(value: &SqlValue) -> Option<Self> {
+        match value {
+            // PostgreSQL separates the date and time with a space
+            SqlValue::Timestamp(s) | SqlValue::Text(s) => {
+                chrono::NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S%.f")
+                    .or_else(|_| s.parse())
+                    .ok()
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl FromSqlValue for chrono::DateTime<chrono::FixedOffset> {
+    fn from_sql_value(value: &SqlValue) -> Option<Self> {
+        match value {
+            // PostgreSQL may omit the minutes of the offset, as in `+00`
+            SqlValue::TimestampTz(s) | SqlValue::Text(s) => {
+                chrono::DateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S%.f%#z")
+                    .or_else(|_| chrono::DateTime::parse_from_rfc3339(s))
+                    .ok()
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl FromSqlValue for chrono::DateTime<chrono::Utc> {
+    fn from_sql_value(value: &SqlValue) -> Option<Self> {
+        chrono::DateTime::<chrono::FixedOffset>::from_sql_value(value)
+            .map(|timestamp| timestamp.with_timezone(&chrono::Utc))
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl FromSqlValue for chrono::TimeDelta {
+    fn from_sql_value(value: &SqlValue) -> Option<Self> {
+        match value {
+            SqlValue::Interval(s) | SqlValue::Text(s) => {
+                interval_micros(s).map(chrono::TimeDelta::microseconds)
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "serde_json")]
+impl FromSqlValue for serde_json::Value {
+    fn from_sql_value(value: &SqlValue) -> Option<Self> {
+        match value {
+            SqlValue::Json(s) | SqlValue::Text(s) => serde_json::from_str(s).ok(),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "time")]
+impl FromSqlValue for time::Date {
+    fn from_sql_value(value: &SqlValue) -> Option<Self> {
+        match value {
+            SqlValue::Date(s) | SqlValue::Text(s) => {
+                time::Date::parse(s, time::macros::format_description!("[year]-[month]-[day]")).ok()
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "time")]
+impl FromSqlValue for time::Time {
+    fn from_sql_value(value: &SqlValue) -> Option<Self> {
+        match value {
+            SqlValue::Time(s) | SqlValue::Text(s) => time::Time::parse(
+                s,
+                time::macros::format_description!(
+                    "[hour]:[minute]:[second][optional [.[subsecond]]]"
+                ),
+            )
+            .ok(),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "time")]
+impl FromSqlValue for time::PrimitiveDateTime {
+    fn from_sql_value(value: &SqlValue) -> Option<Self> {
+        match value {
+            SqlValue::Timestamp(s) | SqlValue::Text(s) => time::PrimitiveDateTime::parse(
+                s,
+                time::macros::format_description!(
+                    "[year]-[month]-[day] [hour]:[minute]:[second][optional [.[subsecond]]]"
+                ),
+            )
+            .ok(),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "time")]
+impl FromSqlValue for time::OffsetDateTime {
+    fn from_sql_value(value: &SqlValue) -> Option<Self> {
+        match value {
+            // PostgreSQL may omit the minutes of the offset, as in `+00`
+            SqlValue::TimestampTz(s) | SqlValue::Text(s) => time::OffsetDateTime::parse(
+                s,
+                time::macros::format_description!(
+                    "[year]-[month]-[day] [hour]:[minute]:[second][optional [.[subsecond]]]\
+                     [offset_hour sign:mandatory][optional [:[offset_minute]]]\
+                     [optional [:[offset_second]]]"
+                ),
+            )
+            .or_else(|_| {
+                time::OffsetDateTime::parse(s, &time::format_description::well_known::Rfc3339)
+            })
+            .ok(),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "time")]
+impl FromSqlValue for time::Duration {
+    fn from_sql_value(value: &SqlValue) -> Option<Self> {
+        match value {
+            SqlValue::Interval(s) | SqlValue::Text(s) => {
+                interval_micros(s).map(time::Duration::microseconds)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Reads an interval with no months or years, which have no fixed length,
+/// as microseconds, counting a day as 24 hours.
+#[cfg(any(feature = "chrono", feature = "time"))]
+fn interval_micros(text: &str) -> Option<i64> {
+    match crate::drivers::binary::parse_interval(text)? {
+        (0, days, micros) => i64::from(days)
+            .checked_mul(86_400_000_000)?
+            .checked_add(micros),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(s: &str) -> SqlValue {
+        SqlValue::Text(s.to_string())
+    }
+
+    #[test]
+    fn test_integers() {
+        assert_eq!(i32::from_sql_value(&SqlValue::Int16(7)), Some(7));
+        assert_eq!(i64::from_sql_value(&text("-42")), Some(-42));
+        assert_eq!(i16::from_sql_value(&SqlValue::Int64(1 << 20)), None);
+        assert_eq!(i32::from_sql_value(&text("4.5")), None);
+        assert_eq!(i32::from_sql_value(&SqlValue::Null), None);
+    }
+
+    #[test]
+    fn test_scalars() {
+        assert_eq!(f64::from_sql_value(&SqlValue::Float32(1.5)), Some(1.5));
+        assert_eq!(f32::from_sql_value(&text("2.25")), Some(2.25));
+        assert_eq!(f32::from_sql_value(&SqlValue::Int32(3)), Some(3.0));
+        assert_eq!(
+            f64::from_sql_value(&SqlValue::Int64(1 << 40)),
+            Some(1099511627776.0)
+        );
+        assert_eq!(
+            f64::from_sql_value(&SqlValue::Numeric("12.50".to_string())),
+            Some(12.5)
+        );
+        assert_eq!(bool::from_sql_value(&text("t")), Some(true));
+        assert_eq!(bool::from_sql_value(&text("yes")), None);
+        assert_eq!(
+            String::from_sql_value(&SqlValue::Date("2024-01-31".to_string())),
+            Some("2024-01-31".to_string())
+        );
+        assert_eq!(String::from_sql_value(&SqlValue::Int32(1)), None);
+    }
+
+    #[test]
+    fn test_option_and_vec() {
+        assert_eq!(Option::<i32>::from_sql_value(&SqlValue::Null), Some(None));
+        assert_eq!(Option::<i32>::from_sql_value(&text("3")), Some(Some(3)));
+        assert_eq!(Option::<i32>::from_sql_value(&text("x")), None);
+
+        assert_eq!(
+            Vec::<Option<i32>>::from_sql_value(&text("{1,NULL,3}")),
+            Some(vec![Some(1), None, Some(3)])
+        );
+        assert_eq!(Vec::<i32>::from_sql_value(&text("{1,NULL}")), None);
+        assert_eq!(
+            Vec::<String>::from_sql_value(&SqlValue::Array(vec![text("a")])),
+            Some(vec!["a".to_string()])
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_chrono() {
+        use chrono::{DateTime, NaiveDate, TimeZone, Utc};
+
+        assert_eq!(
+            NaiveDate::from_sql_value(&text("2024-01-31")),
+            NaiveDate::from_ymd_opt(2024, 1, 31)
+        );
+        let expected = Utc.with_ymd_and_hms(2024, 1, 31, 14, 30, 0).unwrap();
+        assert_eq!(
+            DateTime::<Utc>::from_sql_value(&text("2024-01-31 16:30:00+02")),
+            Some(expected)
+        );
+        assert_eq!(
+            DateTime::<Utc>::from_sql_value(&SqlValue::from(expected)),
+            Some(expected)
+        );
+        assert_eq!(
+            chrono::NaiveDateTime::from_sql_value(&text("2024-01-31 14:30:00.5")),
+            NaiveDate::from_ymd_opt(2024, 1, 31).and_then(|d| d.and_hms_milli_opt(14, 30, 0, 500))
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_chrono_interval() {
+        use chrono::TimeDelta;
+
+        let value = SqlValue::from(TimeDelta::milliseconds(-1250));
+        assert_eq!(
+            TimeDelta::from_sql_value(&value),
+            Some(TimeDelta::milliseconds(-1250))
+        );
+        assert_eq!(
+            TimeDelta::from_sql_value(&SqlValue::Interval("-1 days +02:03:04.5".to_string())),
+            Some(TimeDelta::milliseconds(-(86_400_000 - 7_384_500)))
+        );
+        assert_eq!(
+            TimeDelta::from_sql_value(&SqlValue::Interval("1 mon 2 days".to_string())),
+            None
+        );
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn test_time() {
+        use time::macros::{date, datetime, time};
+        use time::Duration;
+
+        assert_eq!(
+            time::Date::from_sql_value(&SqlValue::Date("2024-01-31".to_string())),
+            Some(date!(2024 - 01 - 31))
+        );
+        assert_eq!(
+            time::Time::from_sql_value(&SqlValue::Time("14:30:00.5".to_string())),
+            Some(time!(14:30:00.5))
+        );
+        assert_eq!(
+            time::PrimitiveDateTime::from_sql_value(&text("2024-01-31 14:30:00")),
+            Some(datetime!(2024-01-31 14:30:00))
+        );
+        assert_eq!(
+            time::OffsetDateTime::from_sql_value(&SqlValue::TimestampTz(
+                "2024-01-31 16:30:00.25+02".to_string()
+            )),
+            Some(datetime!(2024-01-31 14:30:00.25 UTC))
+        );
+        assert_eq!(
+            time::OffsetDateTime::from_sql_value(&text("2024-01-31T14:30:00+05:30")),
+            Some(datetime!(2024-01-31 14:30:00 +05:30))
+        );
+        assert_eq!(
+            Duration::from_sql_value(&SqlValue::Interval("1 day 00:00:01".to_string())),
+            Some(Duration::seconds(86_401))
+        );
+        assert_eq!(
+            Duration::from_sql_value(&SqlValue::from(Duration::microseconds(-90_500_000))),
+            Some(Duration::microseconds(-90_500_000))
+        );
+        assert_eq!(time::Date::from_sql_value(&SqlValue::Int32(1)), None);
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn test_serde_json() {
+        let document = serde_json::json!({"theme": "dark", "beta": true});
+        assert_eq!(
+            serde_json::Value::from_sql_value(&SqlValue::from(document.clone())),
+            Some(document)
+        );
+        assert_eq!(
+            serde_json::Value::from_sql_value(&SqlValue::Json("{".to_string())),
+            None
+        );
+    }
+}
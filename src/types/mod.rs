@@ -1,5 +1,14 @@
+mod array;
+mod from_row;
+mod from_sql_value;
+mod pg_type;
+mod range;
 mod row;
 mod sql_value;
 
-pub use row::{QueryResult, RawQueryResult, Row};
+pub use from_row::FromRow;
+pub use from_sql_value::FromSqlValue;
+pub use pg_type::{PgType, ToPgType};
+pub use range::PgRange;
+pub use row::{QueryResult, RawQueryResult, Row, UNKNOWN_TYPE_OID};
 pub use sql_value::SqlValue;
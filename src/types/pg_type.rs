@@ -0,0 +1,399 @@
+use std::convert::Infallible;
+use std::fmt;
+use std::str::FromStr;
+
+/// A PostgreSQL column type.
+/// Rendered using the same spelling as PostgreSQL's `format_type()`, so the
+/// output can be compared directly against catalog information.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PgType {
+    SmallInt,
+    Integer,
+    BigInt,
+    SmallSerial,
+    Serial,
+    BigSerial,
+    Real,
+    DoublePrecision,
+    /// numeric, optionally with (precision, scale)
+    Numeric(Option<(u32, u32)>),
+    Text,
+    /// character varying, optionally with a maximum length
+    Varchar(Option<u32>),
+    /// character, optionally with a fixed length
+    Char(Option<u32>),
+    Boolean,
+    Bytea,
+    Uuid,
+    Date,
+    Time,
+    Timestamp,
+    TimestampTz,
+    Interval,
+    Json,
+    Jsonb,
+    /// An array of the inner type
+    Array(Box<PgType>),
+    /// Any other type, rendered verbatim (e.g. user-defined enums)
+    Custom(String),
+}
+
+impl PgType {
+    /// Returns the type with serial pseudo-types replaced by the integer
+    /// types PostgreSQL actually stores.
+    pub fn normalized(&self) -> PgType {
+        match self {
+            PgType::SmallSerial => PgType::SmallInt,
+            PgType::Serial => PgType::Integer,
+            PgType::BigSerial => PgType::BigInt,
+            PgType::Array(inner) => PgType::Array(Box::new(inner.normalized())),
+            other => other.clone(),
+        }
+    }
+}
+
+impl fmt::Display for PgType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PgType::SmallInt => f.write_str("smallint"),
+            PgType::Integer => f.write_str("integer"),
+            PgType::BigInt => f.write_str("bigint"),
+            PgType::SmallSerial => f.write_str("smallserial"),
+            PgType::Serial => f.write_str("serial"),
+            PgType::BigSerial => f.write_str("bigserial"),
+            PgType::Real => f.write_str("real"),
+            PgType::DoublePrecision => f.write_str("double precision"),
+            PgType::Numeric(None) => f.write_str("numeric"),
+            PgType::Numeric(Some((precision, scale))) => {
+                write!(f, "numeric({},{})", precision, scale)
+            }
+            PgType::Text => f.write_str("text"),
+            PgType::Varchar(None) => f.write_str("character varying"),
+            PgType::Varchar(Some(n)) => write!(f, "character varying({})", n),
+            PgType::Char(None) => f.write_str("character"),
+            PgType::Char(Some(n)) => write!(f, "character({})", n),
+            PgType::Boolean => f.write_str("boolean"),
+            PgType::Bytea => f.write_str("bytea"),
+            PgType::Uuid => f.write_str("uuid"),
+            PgType::Date => f.write_str("date"),
+            PgType::Time => f.write_str("time without time zone"),
+            PgType::Timestamp => f.write_str("timestamp without time zone"),
+            PgType::TimestampTz => f.write_str("timestamp with time zone"),
+            PgType::Interval => f.write_str("interval"),
+            PgType::Json => f.write_str("json"),
+            PgType::Jsonb => f.write_str("jsonb"),
+            PgType::Array(inner) => write!(f, "{}[]", inner),
+            PgType::Custom(name) => f.write_str(name),
+        }
+    }
+}
+
+impl FromStr for PgType {
+    type Err = Infallible;
+
+    /// Parses a type name as printed by `format_type()`, also accepting the
+    /// common aliases (`int8`, `varchar(255)`, `timestamptz`, ...).
+    /// Precision modifiers of time types, as in `timestamp(3) with time zone`,
+    /// and interval field restrictions are accepted and dropped.
+    /// Unrecognised names are returned as `PgType::Custom`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name = s.trim();
+
+        if let Some(inner) = name.strip_suffix("[]") {
+            return Ok(PgType::Array(Box::new(inner.parse()?)));
+        }
+        if let Some(inner) = name.strip_prefix('_') {
+            // Array types in pg_type are named after their element with a
+            // leading underscore; other names may start with one too.
+            let element: PgType = inner.parse()?;
+            if !matches!(element, PgType::Custom(_)) {
+                return Ok(PgType::Array(Box::new(element)));
+            }
+        }
+
+        let lower = name.to_ascii_lowercase();
+        let (base, modifiers) = split_modifiers(&lower);
+        let length = modifiers.and_then(|m| m.trim().parse::<u32>().ok());
+
+        let pg_type = match base.as_str() {
+            "smallint" | "int2" => PgType::SmallInt,
+            "integer" | "int" | "int4" => PgType::Integer,
+            "bigint" | "int8" => PgType::BigInt,
+            "smallserial" | "serial2" => PgType::SmallSerial,
+            "serial" | "serial4" => PgType::Serial,
+            "bigserial" | "serial8" => PgType::BigSerial,
+            "real" | "float4" => PgType::Real,
+            "double precision" | "float8" => PgType::DoublePrecision,
+            "numeric" | "decimal" => PgType::Numeric(modifiers.and_then(|m| {
+                let (precision, scale) = m.split_once(',').unwrap_or((m, "0"));
+                Some((precision.trim().parse().ok()?, scale.trim().parse().ok()?))
+            })),
+            "text" => PgType::Text,
+            "character varying" | "varchar" => PgType::Varchar(length),
+            "character" | "char" | "bpchar" => PgType::Char(length),
+            "boolean" | "bool" => PgType::Boolean,
+            "bytea" => PgType::Bytea,
+            "uuid" => PgType::Uuid,
+            "date" => PgType::Date,
+            "time" | "time without time zone" => PgType::Time,
+            "timestamp" | "timestamp without time zone" => PgType::Timestamp,
+            "timestamptz" | "timestamp with time zone" => PgType::TimestampTz,
+            "interval" => PgType::Interval,
+            interval if interval.starts_with("interval ") => PgType::Interval,
+            "json" => PgType::Json,
+            "jsonb" => PgType::Jsonb,
+            _ => PgType::Custom(name.to_string()),
+        };
+        Ok(pg_type)
+    }
+}
+
+/// Splits a lowercase type name into its name without the parenthesized
+/// modifiers and the modifiers, e.g. `timestamp(3) with time zone` into
+/// `timestamp with time zone` and `3`.
+fn split_modifiers(name: &str) -> (String, Option<&str>) {
+    let Some((before, rest)) = name.split_once('(') else {
+        return (name.to_string(), None);
+    };
+    let Some((modifiers, after)) = rest.split_once(')') else {
+        return (name.to_string(), None);
+    };
+    let base = match (before.trim(), after.trim()) {
+        (before, "") => before.to_string(),
+        (before, after) => format!("{} {}", before, after),
+    };
+    (base, Some(modifiers))
+}
+
+/// Maps a Rust type to the PostgreSQL type used to store it.
+/// Used by the `Table` derive and the `table!` macro to fill in column metadata.
+pub trait ToPgType {
+    /// Returns the PostgreSQL type for this Rust type.
+    fn pg_type() -> PgType;
+
+    /// Returns true if the Rust type can represent NULL.
+    fn nullable() -> bool {
+        false
+    }
+}
+
+// Each type also maps `Vec` of itself to an array. `Vec<T>` has no blanket
+// impl so that `Vec<u8>` can stay `bytea`.
+macro_rules! impl_to_pg_type {
+    ($($rust:ty => $pg:expr),* $(,)?) => {
+        $(
+            impl ToPgType for $rust {
+                fn pg_type() -> PgType {
+                    $pg
+                }
+            }
+
+            impl ToPgType for Vec<$rust> {
+                fn pg_type() -> PgType {
+                    PgType::Array(Box::new($pg))
+                }
+            }
+        )*
+    };
+}
+
+impl_to_pg_type! {
+    bool => PgType::Boolean,
+    i16 => PgType::SmallInt,
+    i32 => PgType::Integer,
+    i64 => PgType::BigInt,
+    f32 => PgType::Real,
+    f64 => PgType::DoublePrecision,
+    String => PgType::Text,
+    Vec<u8> => PgType::Bytea,
+}
+
+#[cfg(feature = "uuid")]
+impl_to_pg_type! {
+    uuid::Uuid => PgType::Uuid,
+}
+
+#[cfg(feature = "rust_decimal")]
+impl_to_pg_type! {
+    rust_decimal::Decimal => PgType::Numeric(None),
+}
+
+#[cfg(feature = "chrono")]
+impl_to_pg_type! {
+    chrono::NaiveDate => PgType::Date,
+    chrono::NaiveTime => PgType::Time,
+    chrono::NaiveDateTime => PgType::Timestamp,
+    chrono::TimeDelta => PgType::Interval,
+}
+
+#[cfg(feature = "chrono")]
+impl<Tz: chrono::TimeZone> ToPgType for chrono::DateTime<Tz> {
+    fn pg_type() -> PgType {
+        PgType::TimestampTz
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl<Tz: chrono::TimeZone> ToPgType for Vec<chrono::DateTime<Tz>> {
+    fn pg_type() -> PgType {
+        PgType::Array(Box::new(PgType::TimestampTz))
+    }
+}
+
+#[cfg(feature = "serde_json")]
+impl_to_pg_type! {
+    serde_json::Value => PgType::Jsonb,
+}
+
+#[cfg(feature = "time")]
+impl_to_pg_type! {
+    time::Date => PgType::Date,
+    time::Time => PgType::Time,
+    time::PrimitiveDateTime => PgType::Timestamp,
+    time::OffsetDateTime => PgType::TimestampTz,
+    time::Duration => PgType::Interval,
+}
+
+impl<T: ToPgType> ToPgType for Option<T> {
+    fn pg_type() -> PgType {
+        T::pg_type()
+    }
+
+    fn nullable() -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display_matches_format_type() {
+        assert_eq!(PgType::BigInt.to_string(), "bigint");
+        assert_eq!(
+            PgType::Varchar(Some(255)).to_string(),
+            "character varying(255)"
+        );
+        assert_eq!(PgType::Numeric(Some((10, 2))).to_string(), "numeric(10,2)");
+        assert_eq!(PgType::TimestampTz.to_string(), "timestamp with time zone");
+        assert_eq!(PgType::Array(Box::new(PgType::Text)).to_string(), "text[]");
+    }
+
+    #[test]
+    fn test_parse_type_names() {
+        assert_eq!("int8".parse::<PgType>().unwrap(), PgType::BigInt);
+        assert_eq!(
+            "character varying(64)".parse::<PgType>().unwrap(),
+            PgType::Varchar(Some(64))
+        );
+        assert_eq!(
+            "numeric(12, 4)".parse::<PgType>().unwrap(),
+            PgType::Numeric(Some((12, 4)))
+        );
+        assert_eq!(
+            "_int4".parse::<PgType>().unwrap(),
+            PgType::Array(Box::new(PgType::Integer))
+        );
+        assert_eq!(
+            "mood".parse::<PgType>().unwrap(),
+            PgType::Custom("mood".to_string())
+        );
+        assert_eq!(
+            "_legacy_status".parse::<PgType>().unwrap(),
+            PgType::Custom("_legacy_status".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_type_modifiers() {
+        assert_eq!(
+            "timestamp(3) with time zone".parse::<PgType>().unwrap(),
+            PgType::TimestampTz
+        );
+        assert_eq!(
+            "time(0) without time zone[]".parse::<PgType>().unwrap(),
+            PgType::Array(Box::new(PgType::Time))
+        );
+        assert_eq!(
+            "interval day to second(2)".parse::<PgType>().unwrap(),
+            PgType::Interval
+        );
+        assert_eq!(
+            "numeric(10,2)".parse::<PgType>().unwrap(),
+            PgType::Numeric(Some((10, 2)))
+        );
+    }
+
+    #[test]
+    fn test_parse_round_trips_display() {
+        for pg_type in [
+            PgType::DoublePrecision,
+            PgType::Char(Some(2)),
+            PgType::Time,
+            PgType::TimestampTz,
+            PgType::Array(Box::new(PgType::Jsonb)),
+        ] {
+            assert_eq!(pg_type.to_string().parse::<PgType>().unwrap(), pg_type);
+        }
+    }
+
+    #[test]
+    fn test_to_pg_type() {
+        assert_eq!(i64::pg_type(), PgType::BigInt);
+        assert!(!i64::nullable());
+        assert_eq!(<Option<String>>::pg_type(), PgType::Text);
+        assert!(<Option<String>>::nullable());
+    }
+
+    #[test]
+    fn test_to_pg_type_arrays() {
+        assert_eq!(
+            <Vec<String>>::pg_type(),
+            PgType::Array(Box::new(PgType::Text))
+        );
+        assert_eq!(
+            <Option<Vec<i64>>>::pg_type(),
+            PgType::Array(Box::new(PgType::BigInt))
+        );
+        assert_eq!(<Vec<u8>>::pg_type(), PgType::Bytea);
+        assert_eq!(
+            <Vec<Vec<u8>>>::pg_type(),
+            PgType::Array(Box::new(PgType::Bytea))
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "uuid", feature = "chrono", feature = "rust_decimal"))]
+    fn test_to_pg_type_feature_types() {
+        assert_eq!(uuid::Uuid::pg_type(), PgType::Uuid);
+        assert_eq!(rust_decimal::Decimal::pg_type(), PgType::Numeric(None));
+        assert_eq!(chrono::NaiveDateTime::pg_type(), PgType::Timestamp);
+        assert_eq!(
+            <chrono::DateTime<chrono::Utc>>::pg_type(),
+            PgType::TimestampTz
+        );
+        assert_eq!(
+            <Vec<chrono::DateTime<chrono::Utc>>>::pg_type(),
+            PgType::Array(Box::new(PgType::TimestampTz))
+        );
+        assert_eq!(
+            <Vec<uuid::Uuid>>::pg_type(),
+            PgType::Array(Box::new(PgType::Uuid))
+        );
+        assert!(<Option<chrono::NaiveDate>>::nullable());
+    }
+
+    #[test]
+    #[cfg(feature = "time")]
+    fn test_to_pg_type_time_types() {
+        assert_eq!(time::OffsetDateTime::pg_type(), PgType::TimestampTz);
+        assert_eq!(time::Duration::pg_type(), PgType::Interval);
+    }
+
+    #[test]
+    fn test_normalized_serial() {
+        assert_eq!(PgType::BigSerial.normalized(), PgType::BigInt);
+        assert_eq!(PgType::Text.normalized(), PgType::Text);
+    }
+}
@@ -0,0 +1,153 @@
+//! PostgreSQL range values, e.g. `[1,10)` or `["2024-01-01 10:00+00",)`.
+
+use std::fmt;
+use std::ops::{self, Bound};
+
+/// A range of values, as stored in PostgreSQL's range types.
+///
+/// Each bound is inclusive, exclusive or unbounded, using `std::ops::Bound`.
+/// Rust ranges convert directly: `1..5` is `[1,5)` and `1..=5` is `[1,5]`.
+///
+/// # Example
+/// ```
+/// use std::ops::Bound;
+/// use pgrs::types::{PgRange, SqlValue};
+///
+/// let seats: SqlValue = PgRange::from(1..5).into();
+/// assert_eq!(seats, SqlValue::Int4Range(PgRange::from(1..5)));
+///
+/// let period = PgRange::new(
+///     Bound::Included("2024-01-01 10:00+00".to_string()),
+///     Bound::Unbounded,
+/// );
+/// assert_eq!(period.to_string(), "[\"2024-01-01 10:00+00\",)");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum PgRange<T> {
+    /// The range containing no values.
+    Empty,
+    /// The values between a lower and an upper bound.
+    Bounds(Bound<T>, Bound<T>),
+}
+
+impl<T> PgRange<T> {
+    /// The values between a lower and an upper bound.
+    pub fn new(lower: Bound<T>, upper: Bound<T>) -> Self {
+        PgRange::Bounds(lower, upper)
+    }
+
+    /// The range containing no values.
+    pub fn empty() -> Self {
+        PgRange::Empty
+    }
+}
+
+impl<T> From<ops::Range<T>> for PgRange<T> {
+    fn from(range: ops::Range<T>) -> Self {
+        PgRange::new(Bound::Included(range.start), Bound::Excluded(range.end))
+    }
+}
+
+impl<T> From<ops::RangeInclusive<T>> for PgRange<T> {
+    fn from(range: ops::RangeInclusive<T>) -> Self {
+        let (start, end) = range.into_inner();
+        PgRange::new(Bound::Included(start), Bound::Included(end))
+    }
+}
+
+impl<T> From<ops::RangeFrom<T>> for PgRange<T> {
+    fn from(range: ops::RangeFrom<T>) -> Self {
+        PgRange::new(Bound::Included(range.start), Bound::Unbounded)
+    }
+}
+
+impl<T> From<ops::RangeTo<T>> for PgRange<T> {
+    fn from(range: ops::RangeTo<T>) -> Self {
+        PgRange::new(Bound::Unbounded, Bound::Excluded(range.end))
+    }
+}
+
+/// Renders the range literal, which PostgreSQL casts to any range type.
+impl<T: fmt::Display> fmt::Display for PgRange<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PgRange::Empty => f.write_str("empty"),
+            PgRange::Bounds(lower, upper) => {
+                let lower = lower.as_ref().map(|bound| bound.to_string());
+                let upper = upper.as_ref().map(|bound| bound.to_string());
+                f.write_str(&format_range_literal(
+                    lower.as_ref().map(String::as_str),
+                    upper.as_ref().map(String::as_str),
+                ))
+            }
+        }
+    }
+}
+
+/// Renders a non-empty range literal from the text of its bounds.
+fn format_range_literal(lower: Bound<&str>, upper: Bound<&str>) -> String {
+    let (open, lower) = match lower {
+        Bound::Included(bound) => ('[', format_bound(bound)),
+        Bound::Excluded(bound) => ('(', format_bound(bound)),
+        Bound::Unbounded => ('(', String::new()),
+    };
+    let (close, upper) = match upper {
+        Bound::Included(bound) => (']', format_bound(bound)),
+        Bound::Excluded(bound) => (')', format_bound(bound)),
+        Bound::Unbounded => (')', String::new()),
+    };
+    format!("{}{},{}{}", open, lower, upper, close)
+}
+
+/// Renders a bound, quoting it if it would otherwise be misread.
+fn format_bound(bound: &str) -> String {
+    let needs_quotes = bound.is_empty()
+        || bound
+            .chars()
+            .any(|c| matches!(c, '(' | ')' | '[' | ']' | ',' | '"' | '\\') || c.is_whitespace());
+    if !needs_quotes {
+        return bound.to_string();
+    }
+
+    let mut quoted = String::with_capacity(bound.len() + 2);
+    quoted.push('"');
+    for c in bound.chars() {
+        if matches!(c, '"' | '\\') {
+            quoted.push('\\');
+        }
+        quoted.push(c);
+    }
+    quoted.push('"');
+    quoted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_range() {
+        assert_eq!(PgRange::from(1..5).to_string(), "[1,5)");
+        assert_eq!(PgRange::from(1..=5).to_string(), "[1,5]");
+        assert_eq!(PgRange::from(3i64..).to_string(), "[3,)");
+        assert_eq!(PgRange::from(..3i64).to_string(), "(,3)");
+        assert_eq!(
+            PgRange::new(Bound::Excluded(1.5), Bound::Unbounded).to_string(),
+            "(1.5,)"
+        );
+        assert_eq!(PgRange::<i32>::empty().to_string(), "empty");
+    }
+
+    #[test]
+    fn test_format_range_quotes_bounds() {
+        let range = PgRange::new(
+            Bound::Included("2024-01-01 10:00+00".to_string()),
+            Bound::Excluded("a\"b".to_string()),
+        );
+        assert_eq!(range.to_string(), r#"["2024-01-01 10:00+00","a\"b")"#);
+        assert_eq!(
+            format_range_literal(Bound::Included(""), Bound::Unbounded),
+            "[\"\",)"
+        );
+    }
+}
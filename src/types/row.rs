@@ -1,3 +1,4 @@
+use std::borrow::Cow;
 use std::collections::HashMap;
 
 use crate::{
@@ -5,55 +6,141 @@ use crate::{
     Column,
 };
 
+use super::from_row::FromRow;
+use super::from_sql_value::FromSqlValue;
+use super::sql_value::SqlValue;
+
+/// Type OID of a column whose type the driver does not report.
+pub const UNKNOWN_TYPE_OID: u32 = 0;
+
 /// Driver-agnostic raw result from a database query.
-/// All values are converted to strings by the driver.
+/// Drivers decode each value according to its column's declared type.
 #[derive(Debug, Clone)]
 pub struct RawQueryResult {
     /// Column names in order
     pub columns: Vec<String>,
-    /// Rows, where each row is a vector of string values in column order
-    pub rows: Vec<Vec<String>>,
+    /// PostgreSQL type OIDs of the columns, in column order
+    pub column_types: Vec<u32>,
+    /// Rows, where each row is a vector of values in column order
+    pub rows: Vec<Vec<SqlValue>>,
 }
 
 impl RawQueryResult {
-    pub fn new(columns: Vec<String>, rows: Vec<Vec<String>>) -> Self {
-        Self { columns, rows }
+    pub fn new(columns: Vec<String>, column_types: Vec<u32>, rows: Vec<Vec<SqlValue>>) -> Self {
+        Self {
+            columns,
+            column_types,
+            rows,
+        }
     }
 
     pub fn empty() -> Self {
         Self {
             columns: Vec::new(),
+            column_types: Vec::new(),
             rows: Vec::new(),
         }
     }
 }
 
 /// A single row result from a query.
-/// Values are stored as strings and accessed by column name.
+/// Values are accessed by column name, either as text or as typed values.
 #[derive(Debug, Clone)]
 pub struct Row {
-    values: HashMap<String, String>,
+    values: HashMap<String, SqlValue>,
 }
 
 impl Row {
     /// Creates a new Row from column names and values.
-    pub(crate) fn new(columns: &[String], values: Vec<String>) -> Self {
-        let values = columns
-            .iter()
-            .zip(values.into_iter())
-            .map(|(col, val)| (col.clone(), val))
-            .collect();
+    pub(crate) fn new(columns: &[String], values: Vec<SqlValue>) -> Self {
+        let values = columns.iter().cloned().zip(values).collect();
         Self { values }
     }
 
-    /// Gets a value by column name.
-    pub fn get<T: Column + ?Sized>(&self, column: &T) -> Result<&str> {
+    /// Gets a value by column name, as text. Values held as text are
+    /// borrowed; others are rendered on each call.
+    ///
+    /// Fails with `PgRsError::UnexpectedNull` if the value is NULL; use
+    /// `get_opt` for nullable columns.
+    pub fn get<T: Column + ?Sized>(&self, column: &T) -> Result<Cow<'_, str>> {
+        self.get_opt(column)?
+            .ok_or_else(|| PgRsError::UnexpectedNull(column.qualified_name()))
+    }
+
+    /// Gets a value by column name, as text, or `None` if it is NULL.
+    pub fn get_opt<T: Column + ?Sized>(&self, column: &T) -> Result<Option<Cow<'_, str>>> {
+        self.value(column).map(text)
+    }
+
+    /// Returns true if the value of the column is NULL.
+    pub fn is_null<T: Column + ?Sized>(&self, column: &T) -> Result<bool> {
+        self.value(column).map(|value| *value == SqlValue::Null)
+    }
+
+    /// Gets a value by column name, as decoded by the driver.
+    pub fn value<T: Column + ?Sized>(&self, column: &T) -> Result<&SqlValue> {
         self.values
             .get(column.column_name())
-            .map(|s| s.as_str())
             .ok_or_else(|| PgRsError::ColumnNotFound(column.qualified_name()))
     }
 
+    /// Gets a value converted to `T`, such as `i64`, `Option<String>` or
+    /// `Vec<i32>`.
+    ///
+    /// Fails with `PgRsError::ConversionFailed` if the value cannot be
+    /// represented as `T`, including NULL unless `T` is an `Option`.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let id: i64 = row.get_as(&Users::columns().id)?;
+    /// let email: Option<String> = row.get_as(&Users::columns().email)?;
+    /// ```
+    pub fn get_as<T: FromSqlValue, C: Column + ?Sized>(&self, column: &C) -> Result<T> {
+        T::from_sql_value(self.value(column)?).ok_or_else(|| PgRsError::ConversionFailed {
+            column: column.qualified_name(),
+            target: std::any::type_name::<T>(),
+        })
+    }
+
+    /// Gets a value by its name in the result, such as the alias of a
+    /// selected expression.
+    ///
+    /// Fails with `PgRsError::UnexpectedNull` if the value is NULL.
+    pub fn get_named(&self, name: &str) -> Result<Cow<'_, str>> {
+        let value = self
+            .values
+            .get(name)
+            .ok_or_else(|| PgRsError::ColumnNotFound(name.to_string()))?;
+        text(value).ok_or_else(|| PgRsError::UnexpectedNull(name.to_string()))
+    }
+
+    /// Gets a value by its name in the result, converted to `T`.
+    pub fn get_named_as<T: FromSqlValue>(&self, name: &str) -> Result<T> {
+        let value = self
+            .values
+            .get(name)
+            .ok_or_else(|| PgRsError::ColumnNotFound(name.to_string()))?;
+        T::from_sql_value(value).ok_or_else(|| PgRsError::ConversionFailed {
+            column: name.to_string(),
+            target: std::any::type_name::<T>(),
+        })
+    }
+
+    /// Returns true if the row has a column with this name.
+    pub fn contains(&self, name: &str) -> bool {
+        self.values.contains_key(name)
+    }
+
+    /// Gets a one-dimensional array column as a `Vec`, converting each
+    /// element. Shorthand for `get_as::<Vec<T>, _>`.
+    ///
+    /// Fails with `PgRsError::ConversionFailed` if the value is not an
+    /// array, or an element cannot be represented as `T`. Read arrays with
+    /// NULL elements as `Vec<Option<T>>`.
+    pub fn get_array<T: FromSqlValue, C: Column + ?Sized>(&self, column: &C) -> Result<Vec<T>> {
+        self.get_as::<Vec<T>, C>(column)
+    }
+
     /// Returns all column names in this row.
     pub fn columns(&self) -> Vec<&str> {
         self.values.keys().map(|s| s.as_str()).collect()
@@ -70,10 +157,29 @@ impl Row {
     }
 }
 
+/// Renders a value as text, borrowing values already held as text.
+/// NULL has no text.
+fn text(value: &SqlValue) -> Option<Cow<'_, str>> {
+    match value {
+        SqlValue::Null => None,
+        SqlValue::Text(s)
+        | SqlValue::Json(s)
+        | SqlValue::Numeric(s)
+        | SqlValue::Uuid(s)
+        | SqlValue::Date(s)
+        | SqlValue::Time(s)
+        | SqlValue::Timestamp(s)
+        | SqlValue::TimestampTz(s)
+        | SqlValue::Interval(s) => Some(Cow::Borrowed(s)),
+        value => Some(Cow::Owned(value.to_string())),
+    }
+}
+
 /// Result of a query execution, containing zero or more rows.
 #[derive(Debug)]
 pub struct QueryResult {
     columns: Vec<String>,
+    column_types: Vec<u32>,
     rows: Vec<Row>,
 }
 
@@ -87,6 +193,7 @@ impl QueryResult {
             .collect();
         Self {
             columns: raw.columns,
+            column_types: raw.column_types,
             rows,
         }
     }
@@ -103,6 +210,17 @@ impl QueryResult {
         Ok(self.rows.into_iter().next().unwrap())
     }
 
+    /// Maps the single row of the result into `T`.
+    /// Returns an error if the result contains zero or more than one row.
+    pub fn single_as<T: FromRow>(self) -> Result<T> {
+        T::from_row(&self.single_row()?)
+    }
+
+    /// Maps every row of the result into `T`.
+    pub fn into_structs<T: FromRow>(self) -> Result<Vec<T>> {
+        self.rows.iter().map(T::from_row).collect()
+    }
+
     /// Returns all rows from the result.
     pub fn rows(self) -> Vec<Row> {
         self.rows
@@ -118,6 +236,11 @@ impl QueryResult {
         &self.columns
     }
 
+    /// Returns the PostgreSQL type OIDs of the columns, in column order.
+    pub fn column_types(&self) -> &[u32] {
+        &self.column_types
+    }
+
     /// Returns the number of rows in this result.
     pub fn len(&self) -> usize {
         self.rows.len()
@@ -133,6 +256,10 @@ impl QueryResult {
 mod tests {
     use super::*;
 
+    fn text(s: &str) -> SqlValue {
+        SqlValue::Text(s.to_string())
+    }
+
     // Test column implementations
     struct IdColumn;
     struct NameColumn;
@@ -168,19 +295,123 @@ mod tests {
     #[test]
     fn test_row_get() {
         let columns = vec!["id".to_string(), "name".to_string()];
-        let values = vec!["1".to_string(), "John".to_string()];
+        let values = vec![SqlValue::Int32(1), text("John")];
         let row = Row::new(&columns, values);
 
         assert_eq!(row.get(&IdColumn).unwrap(), "1");
         assert_eq!(row.get(&NameColumn).unwrap(), "John");
+        assert!(matches!(row.get(&NameColumn).unwrap(), Cow::Borrowed(_)));
         assert!(row.get(&MissingColumn).is_err());
     }
 
+    #[test]
+    fn test_row_null() {
+        let columns = vec!["id".to_string(), "name".to_string()];
+        let row = Row::new(&columns, vec![SqlValue::Null, text("NULL")]);
+
+        assert!(row.is_null(&IdColumn).unwrap());
+        assert!(!row.is_null(&NameColumn).unwrap());
+        assert_eq!(row.get_opt(&IdColumn).unwrap(), None);
+        assert_eq!(row.get_opt(&NameColumn).unwrap().as_deref(), Some("NULL"));
+        assert_eq!(row.get(&NameColumn).unwrap(), "NULL");
+        assert!(matches!(
+            row.get(&IdColumn),
+            Err(PgRsError::UnexpectedNull(column)) if column == "test.id"
+        ));
+        assert!(matches!(
+            row.get_named("id"),
+            Err(PgRsError::UnexpectedNull(_))
+        ));
+        assert!(row.is_null(&MissingColumn).is_err());
+    }
+
+    #[test]
+    fn test_row_get_as() {
+        let columns = vec!["id".to_string(), "name".to_string()];
+        let row = Row::new(&columns, vec![SqlValue::Int64(42), SqlValue::Null]);
+
+        assert_eq!(row.value(&IdColumn).unwrap(), &SqlValue::Int64(42));
+        assert_eq!(row.get_as::<i64, _>(&IdColumn).unwrap(), 42);
+        assert_eq!(row.get_as::<i16, _>(&IdColumn).unwrap(), 42);
+        assert_eq!(row.get_as::<Option<String>, _>(&NameColumn).unwrap(), None);
+        match row.get_as::<bool, _>(&IdColumn) {
+            Err(PgRsError::ConversionFailed { column, target }) => {
+                assert_eq!(column, "test.id");
+                assert_eq!(target, "bool");
+            }
+            other => panic!("Expected ConversionFailed, got {:?}", other),
+        }
+        assert!(row.get_as::<String, _>(&NameColumn).is_err());
+        assert!(matches!(
+            row.get_as::<i32, _>(&MissingColumn),
+            Err(PgRsError::ColumnNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_row_get_named() {
+        let columns = vec!["id".to_string(), "rank".to_string()];
+        let row = Row::new(&columns, vec![SqlValue::Int32(1), SqlValue::Float32(0.5)]);
+
+        assert_eq!(row.get_named("rank").unwrap(), "0.5");
+        assert!(matches!(
+            row.get_named("missing"),
+            Err(PgRsError::ColumnNotFound(name)) if name == "missing"
+        ));
+        assert_eq!(row.get_named_as::<f64>("rank").unwrap(), 0.5);
+        assert!(matches!(
+            row.get_named_as::<bool>("id"),
+            Err(PgRsError::ConversionFailed { column, .. }) if column == "id"
+        ));
+        assert!(row.contains("rank"));
+        assert!(!row.contains("missing"));
+    }
+
+    #[test]
+    fn test_row_get_array() {
+        let columns = vec!["id".to_string(), "name".to_string()];
+        let values = vec![
+            SqlValue::Array(vec![
+                SqlValue::Int64(1),
+                SqlValue::Int64(2),
+                SqlValue::Int64(3),
+            ]),
+            text("{\"a b\",c}"),
+        ];
+        let row = Row::new(&columns, values);
+
+        assert_eq!(row.get_array::<i64, _>(&IdColumn).unwrap(), vec![1, 2, 3]);
+        assert_eq!(
+            row.get_array::<String, _>(&NameColumn).unwrap(),
+            vec!["a b".to_string(), "c".to_string()]
+        );
+        match row.get_array::<i32, _>(&NameColumn) {
+            Err(PgRsError::ConversionFailed { column, target }) => {
+                assert_eq!(column, "test.name");
+                assert_eq!(target, "alloc::vec::Vec<i32>");
+            }
+            other => panic!("Expected ConversionFailed, got {:?}", other),
+        }
+
+        let values = vec![
+            SqlValue::Array(vec![SqlValue::Int32(1), SqlValue::Null]),
+            text("1"),
+        ];
+        let row = Row::new(&columns, values);
+        assert!(row.get_array::<i32, _>(&IdColumn).is_err());
+        assert_eq!(
+            row.get_array::<Option<i32>, _>(&IdColumn).unwrap(),
+            vec![Some(1), None]
+        );
+        assert!(row.get_array::<i32, _>(&NameColumn).is_err());
+    }
+
     #[test]
     fn test_query_result_single_row() {
         let raw = RawQueryResult {
             columns: vec!["id".to_string()],
-            rows: vec![vec!["1".to_string()]],
+            column_types: vec![UNKNOWN_TYPE_OID],
+            rows: vec![vec![text("1")]],
         };
         let result = QueryResult::from_raw(raw);
         let row = result.single_row().unwrap();
@@ -191,6 +422,7 @@ mod tests {
     fn test_query_result_single_row_error_on_empty() {
         let raw = RawQueryResult {
             columns: vec!["id".to_string()],
+            column_types: vec![UNKNOWN_TYPE_OID],
             rows: vec![],
         };
         let result = QueryResult::from_raw(raw);
@@ -208,7 +440,8 @@ mod tests {
     fn test_query_result_single_row_error_on_multiple() {
         let raw = RawQueryResult {
             columns: vec!["id".to_string()],
-            rows: vec![vec!["1".to_string()], vec!["2".to_string()]],
+            column_types: vec![UNKNOWN_TYPE_OID],
+            rows: vec![vec![text("1")], vec![text("2")]],
         };
         let result = QueryResult::from_raw(raw);
         let err = result.single_row().unwrap_err();
@@ -220,4 +453,40 @@ mod tests {
             _ => panic!("Expected UnexpectedRowCount error"),
         }
     }
+
+    struct Account {
+        id: i32,
+        name: Option<String>,
+    }
+
+    impl FromRow for Account {
+        fn from_row(row: &Row) -> Result<Self> {
+            Ok(Account {
+                id: row.get_named_as("id")?,
+                name: row.get_named_as("name")?,
+            })
+        }
+    }
+
+    #[test]
+    fn test_query_result_into_structs() {
+        let raw = RawQueryResult {
+            columns: vec!["id".to_string(), "name".to_string()],
+            column_types: vec![UNKNOWN_TYPE_OID; 2],
+            rows: vec![
+                vec![SqlValue::Int32(1), text("Ada")],
+                vec![SqlValue::Int32(2), SqlValue::Null],
+            ],
+        };
+        let accounts: Vec<Account> = QueryResult::from_raw(raw.clone()).into_structs().unwrap();
+        assert_eq!(accounts.len(), 2);
+        assert_eq!(accounts[0].id, 1);
+        assert_eq!(accounts[0].name.as_deref(), Some("Ada"));
+        assert_eq!(accounts[1].name, None);
+
+        assert!(matches!(
+            QueryResult::from_raw(raw).single_as::<Account>(),
+            Err(PgRsError::UnexpectedRowCount { actual: 2, .. })
+        ));
+    }
 }
@@ -1,12 +1,153 @@
+use std::fmt;
+
+use super::array::format_array_literal;
+use super::pg_type::PgType;
+use super::range::PgRange;
+
 /// Represents a SQL parameter value in a driver-agnostic way.
 /// Drivers are responsible for converting these to their native types.
+///
+/// The text of `Numeric`, `Uuid`, `Date`, `Time`, `Timestamp`,
+/// `TimestampTz` and `Interval` is a raw PostgreSQL literal. It is not
+/// checked here; a malformed value fails when the statement runs. The
+/// `From` conversions of the `uuid`, `rust_decimal`, `chrono` and `time`
+/// feature types always produce well-formed literals.
 #[derive(Debug, Clone, PartialEq)]
 pub enum SqlValue {
     Null,
     Text(String),
+    Int16(i16),
     Int32(i32),
     Int64(i64),
+    Float32(f32),
+    Float64(f64),
     Bool(bool),
+    /// A `numeric`, given as its decimal text so no precision is lost.
+    Numeric(String),
+    /// A `bytea`.
+    Bytea(Vec<u8>),
+    /// A `uuid`, given as its text, e.g. `67e55044-10b1-426f-9247-bb680e5fe0c8`.
+    Uuid(String),
+    /// A `date`, given as ISO 8601 text, e.g. `2024-01-31`.
+    Date(String),
+    /// A `time`, given as text, e.g. `14:30:00.5`.
+    Time(String),
+    /// A `timestamp`, given as text, e.g. `2024-01-31 14:30:00`.
+    Timestamp(String),
+    /// A `timestamptz`, given as text with an offset, e.g.
+    /// `2024-01-31T14:30:00+00:00`.
+    TimestampTz(String),
+    /// An `interval`, given as text, e.g. `1 day 02:00:00` or `90 seconds`.
+    Interval(String),
+    /// A JSON document, bound as `json` or `jsonb` rather than as text.
+    Json(String),
+    /// A one-dimensional array, bound as a single array parameter.
+    Array(Vec<SqlValue>),
+    /// An `int4range`.
+    Int4Range(PgRange<i32>),
+    /// An `int8range`.
+    Int8Range(PgRange<i64>),
+    /// A `numrange`, with bounds given as numeric text.
+    NumRange(PgRange<String>),
+    /// A `tsrange`, with bounds given as timestamp text.
+    TsRange(PgRange<String>),
+    /// A `tstzrange`, with bounds given as timestamp with time zone text.
+    TstzRange(PgRange<String>),
+    /// A `daterange`, with bounds given as date text.
+    DateRange(PgRange<String>),
+    /// A value of a type with no variant of its own, in the driver's binary
+    /// representation, as read from a result.
+    Raw(Vec<u8>),
+}
+
+impl SqlValue {
+    /// The type of values sent as text and cast to their type in the SQL,
+    /// because drivers have no native representation for them.
+    pub(crate) fn text_cast(&self) -> Option<PgType> {
+        let name = match self {
+            SqlValue::Numeric(_) => return Some(PgType::Numeric(None)),
+            SqlValue::Uuid(_) => return Some(PgType::Uuid),
+            SqlValue::Date(_) => return Some(PgType::Date),
+            SqlValue::Time(_) => return Some(PgType::Time),
+            SqlValue::Timestamp(_) => return Some(PgType::Timestamp),
+            SqlValue::TimestampTz(_) => return Some(PgType::TimestampTz),
+            SqlValue::Interval(_) => return Some(PgType::Interval),
+            SqlValue::Array(values) => {
+                let element = values.iter().find_map(SqlValue::text_cast)?;
+                return Some(PgType::Array(Box::new(element)));
+            }
+            SqlValue::Int4Range(_) => "int4range",
+            SqlValue::Int8Range(_) => "int8range",
+            SqlValue::NumRange(_) => "numrange",
+            SqlValue::TsRange(_) => "tsrange",
+            SqlValue::TstzRange(_) => "tstzrange",
+            SqlValue::DateRange(_) => "daterange",
+            _ => return None,
+        };
+        Some(PgType::Custom(name.to_string()))
+    }
+}
+
+/// Renders the value as text PostgreSQL accepts as input: arrays as
+/// `{1,2,NULL}`, ranges as `[1,5)` and bytea as `\x0001ff`. NULL renders
+/// as `NULL`.
+impl fmt::Display for SqlValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SqlValue::Null => f.write_str("NULL"),
+            SqlValue::Int16(i) => write!(f, "{}", i),
+            SqlValue::Int32(i) => write!(f, "{}", i),
+            SqlValue::Int64(i) => write!(f, "{}", i),
+            SqlValue::Float32(v) => write_float(f, (*v).into()),
+            SqlValue::Float64(v) => write_float(f, *v),
+            SqlValue::Bool(b) => write!(f, "{}", b),
+            SqlValue::Text(s)
+            | SqlValue::Json(s)
+            | SqlValue::Numeric(s)
+            | SqlValue::Uuid(s)
+            | SqlValue::Date(s)
+            | SqlValue::Time(s)
+            | SqlValue::Timestamp(s)
+            | SqlValue::TimestampTz(s)
+            | SqlValue::Interval(s) => f.write_str(s),
+            SqlValue::Bytea(bytes) | SqlValue::Raw(bytes) => {
+                f.write_str("\\x")?;
+                bytes.iter().try_for_each(|b| write!(f, "{:02x}", b))
+            }
+            SqlValue::Array(values) if values.iter().any(|v| matches!(v, SqlValue::Array(_))) => {
+                // Multi-dimensional: nested arrays are not quoted as elements
+                let inner: Vec<String> = values.iter().map(ToString::to_string).collect();
+                write!(f, "{{{}}}", inner.join(","))
+            }
+            SqlValue::Array(values) => {
+                let elements: Vec<Option<String>> = values
+                    .iter()
+                    .map(|value| match value {
+                        SqlValue::Null => None,
+                        value => Some(value.to_string()),
+                    })
+                    .collect();
+                f.write_str(&format_array_literal(elements.iter().map(|e| e.as_deref())))
+            }
+            SqlValue::Int4Range(range) => write!(f, "{}", range),
+            SqlValue::Int8Range(range) => write!(f, "{}", range),
+            SqlValue::NumRange(range)
+            | SqlValue::TsRange(range)
+            | SqlValue::TstzRange(range)
+            | SqlValue::DateRange(range) => write!(f, "{}", range),
+        }
+    }
+}
+
+/// Writes a float as PostgreSQL does, spelling out infinities.
+fn write_float(f: &mut fmt::Formatter<'_>, value: f64) -> fmt::Result {
+    if value.is_infinite() {
+        f.write_str(if value > 0.0 { "Infinity" } else { "-Infinity" })
+    } else if value.is_nan() {
+        f.write_str("NaN")
+    } else {
+        write!(f, "{}", value)
+    }
 }
 
 impl From<&str> for SqlValue {
@@ -21,6 +162,12 @@ impl From<String> for SqlValue {
     }
 }
 
+impl From<i16> for SqlValue {
+    fn from(value: i16) -> Self {
+        SqlValue::Int16(value)
+    }
+}
+
 impl From<i32> for SqlValue {
     fn from(value: i32) -> Self {
         SqlValue::Int32(value)
@@ -33,6 +180,30 @@ impl From<i64> for SqlValue {
     }
 }
 
+impl From<f32> for SqlValue {
+    fn from(value: f32) -> Self {
+        SqlValue::Float32(value)
+    }
+}
+
+impl From<f64> for SqlValue {
+    fn from(value: f64) -> Self {
+        SqlValue::Float64(value)
+    }
+}
+
+impl From<&[u8]> for SqlValue {
+    fn from(value: &[u8]) -> Self {
+        SqlValue::Bytea(value.to_vec())
+    }
+}
+
+impl From<std::time::Duration> for SqlValue {
+    fn from(value: std::time::Duration) -> Self {
+        interval(value.as_secs().into(), value.subsec_micros().into())
+    }
+}
+
 impl From<bool> for SqlValue {
     fn from(value: bool) -> Self {
         SqlValue::Bool(value)
@@ -47,3 +218,217 @@ impl<T: Into<SqlValue>> From<Option<T>> for SqlValue {
         }
     }
 }
+
+impl<T: Into<SqlValue>> From<Vec<T>> for SqlValue {
+    fn from(values: Vec<T>) -> Self {
+        SqlValue::Array(values.into_iter().map(Into::into).collect())
+    }
+}
+
+impl From<PgRange<i32>> for SqlValue {
+    fn from(value: PgRange<i32>) -> Self {
+        SqlValue::Int4Range(value)
+    }
+}
+
+impl From<PgRange<i64>> for SqlValue {
+    fn from(value: PgRange<i64>) -> Self {
+        SqlValue::Int8Range(value)
+    }
+}
+
+/// An interval of whole seconds plus microseconds, both with the same sign.
+fn interval(seconds: i128, microseconds: i128) -> SqlValue {
+    let total = seconds * 1_000_000 + microseconds;
+    let sign = if total < 0 { "-" } else { "" };
+    let total = total.abs();
+    SqlValue::Interval(format!(
+        "{}{}.{:06} seconds",
+        sign,
+        total / 1_000_000,
+        total % 1_000_000
+    ))
+}
+
+#[cfg(feature = "uuid")]
+impl From<uuid::Uuid> for SqlValue {
+    fn from(value: uuid::Uuid) -> Self {
+        SqlValue::Uuid(value.to_string())
+    }
+}
+
+#[cfg(feature = "rust_decimal")]
+impl From<rust_decimal::Decimal> for SqlValue {
+    fn from(value: rust_decimal::Decimal) -> Self {
+        SqlValue::Numeric(value.to_string())
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<chrono::NaiveDate> for SqlValue {
+    fn from(value: chrono::NaiveDate) -> Self {
+        SqlValue::Date(value.to_string())
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<chrono::NaiveTime> for SqlValue {
+    fn from(value: chrono::NaiveTime) -> Self {
+        SqlValue::Time(value.to_string())
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<chrono::NaiveDateTime> for SqlValue {
+    fn from(value: chrono::NaiveDateTime) -> Self {
+        SqlValue::Timestamp(value.to_string())
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl<Tz: chrono::TimeZone> From<chrono::DateTime<Tz>> for SqlValue
+where
+    Tz::Offset: std::fmt::Display,
+{
+    fn from(value: chrono::DateTime<Tz>) -> Self {
+        SqlValue::TimestampTz(value.to_rfc3339())
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<chrono::TimeDelta> for SqlValue {
+    fn from(value: chrono::TimeDelta) -> Self {
+        interval(value.num_seconds().into(), value.subsec_micros().into())
+    }
+}
+
+#[cfg(feature = "time")]
+impl From<time::Date> for SqlValue {
+    fn from(value: time::Date) -> Self {
+        SqlValue::Date(value.to_string())
+    }
+}
+
+#[cfg(feature = "time")]
+impl From<time::Time> for SqlValue {
+    fn from(value: time::Time) -> Self {
+        SqlValue::Time(value.to_string())
+    }
+}
+
+#[cfg(feature = "time")]
+impl From<time::PrimitiveDateTime> for SqlValue {
+    fn from(value: time::PrimitiveDateTime) -> Self {
+        SqlValue::Timestamp(value.to_string())
+    }
+}
+
+#[cfg(feature = "time")]
+impl From<time::OffsetDateTime> for SqlValue {
+    fn from(value: time::OffsetDateTime) -> Self {
+        // Rendered as date, time and offset, e.g. `2024-01-31 14:30:00.0 +00:00:00`
+        SqlValue::TimestampTz(value.to_string())
+    }
+}
+
+#[cfg(feature = "time")]
+impl From<time::Duration> for SqlValue {
+    fn from(value: time::Duration) -> Self {
+        interval(
+            value.whole_seconds().into(),
+            value.subsec_microseconds().into(),
+        )
+    }
+}
+
+#[cfg(feature = "serde_json")]
+impl From<serde_json::Value> for SqlValue {
+    fn from(value: serde_json::Value) -> Self {
+        SqlValue::Json(value.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_interval_from_duration() {
+        assert_eq!(
+            SqlValue::from(std::time::Duration::from_millis(90_500)),
+            SqlValue::Interval("90.500000 seconds".to_string())
+        );
+        assert_eq!(
+            interval(-1, -250_000),
+            SqlValue::Interval("-1.250000 seconds".to_string())
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        let cases = [
+            (SqlValue::Null, "NULL"),
+            (SqlValue::Bool(true), "true"),
+            (SqlValue::Float64(f64::NEG_INFINITY), "-Infinity"),
+            (SqlValue::Float32(1.5), "1.5"),
+            (SqlValue::Bytea(vec![0, 1, 255]), "\\x0001ff"),
+            (
+                SqlValue::Array(vec![
+                    SqlValue::Text("a b".to_string()),
+                    SqlValue::Null,
+                    SqlValue::Int32(3),
+                ]),
+                "{\"a b\",NULL,3}",
+            ),
+            (
+                SqlValue::Array(vec![
+                    SqlValue::Array(vec![SqlValue::Int32(1), SqlValue::Int32(2)]),
+                    SqlValue::Array(vec![SqlValue::Int32(3), SqlValue::Null]),
+                ]),
+                "{{1,2},{3,NULL}}",
+            ),
+            (SqlValue::Int4Range(PgRange::from(1..5)), "[1,5)"),
+        ];
+        for (value, expected) in cases {
+            assert_eq!(value.to_string(), expected);
+        }
+    }
+
+    #[test]
+    fn test_text_cast() {
+        assert_eq!(
+            SqlValue::Date("2024-01-31".to_string()).text_cast(),
+            Some(PgType::Date)
+        );
+        assert_eq!(
+            SqlValue::Array(vec![SqlValue::Null, SqlValue::Uuid(String::new())]).text_cast(),
+            Some(PgType::Array(Box::new(PgType::Uuid)))
+        );
+        assert_eq!(SqlValue::Float64(1.5).text_cast(), None);
+        assert_eq!(SqlValue::Array(vec![SqlValue::Int32(1)]).text_cast(), None);
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_from_chrono() {
+        use chrono::{NaiveDate, TimeZone, Utc};
+
+        let date = NaiveDate::from_ymd_opt(2024, 1, 31).unwrap();
+        assert_eq!(
+            SqlValue::from(date),
+            SqlValue::Date("2024-01-31".to_string())
+        );
+        assert_eq!(
+            SqlValue::from(date.and_hms_opt(14, 30, 0).unwrap()),
+            SqlValue::Timestamp("2024-01-31 14:30:00".to_string())
+        );
+        assert_eq!(
+            SqlValue::from(Utc.with_ymd_and_hms(2024, 1, 31, 14, 30, 0).unwrap()),
+            SqlValue::TimestampTz("2024-01-31T14:30:00+00:00".to_string())
+        );
+        assert_eq!(
+            SqlValue::from(chrono::TimeDelta::milliseconds(-1500)),
+            SqlValue::Interval("-1.500000 seconds".to_string())
+        );
+    }
+}
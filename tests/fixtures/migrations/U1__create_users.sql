@@ -0,0 +1 @@
+DROP TABLE pgrs_migrate.users;
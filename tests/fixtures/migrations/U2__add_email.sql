@@ -0,0 +1,2 @@
+DROP INDEX pgrs_migrate.users_email_idx;
+ALTER TABLE pgrs_migrate.users DROP COLUMN email;
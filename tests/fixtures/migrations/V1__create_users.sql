@@ -0,0 +1,3 @@
+CREATE TABLE pgrs_migrate.users (
+    id bigint PRIMARY KEY
+);
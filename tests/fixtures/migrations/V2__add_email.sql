@@ -0,0 +1,2 @@
+ALTER TABLE pgrs_migrate.users ADD COLUMN email text;
+CREATE INDEX users_email_idx ON pgrs_migrate.users (email);
@@ -0,0 +1,23 @@
+#[test]
+fn test_embed_migrations() {
+    let migrations = pgrs::embed_migrations![
+        "fixtures/migrations/V1__create_users.sql",
+        "fixtures/migrations/U1__create_users.sql",
+        "fixtures/migrations/V2__add_email.sql",
+        "fixtures/migrations/U2__add_email.sql",
+    ]
+    .unwrap();
+
+    assert_eq!(migrations.len(), 2);
+    let first = migrations.get(1).unwrap();
+    assert_eq!(first.name, "create_users");
+    assert!(first.up.starts_with("CREATE TABLE pgrs_migrate.users"));
+    assert_eq!(
+        first.down.as_deref(),
+        Some("DROP TABLE pgrs_migrate.users;\n")
+    );
+    assert_eq!(
+        migrations,
+        pgrs::migrations::Migrations::from_dir("tests/fixtures/migrations").unwrap()
+    );
+}
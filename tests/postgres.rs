@@ -0,0 +1,1119 @@
+//! Tests against a live PostgreSQL database.
+//!
+//! These are ignored by default. Run them with a scratch database:
+//! `PGRS_TEST_DATABASE_URL=postgres://localhost/pgrs_test cargo test -- --ignored`
+
+use pgrs::codegen::{generate, CodegenOptions};
+use std::ops::Bound;
+use std::sync::Arc;
+
+use pgrs::clauses::{InListStyle, Operand, Operator, SortDirection, TextSearch};
+use pgrs::drivers::TokioPostgresDriver;
+use pgrs::dynamic::DynTable;
+use pgrs::error::PgRsError;
+use pgrs::migrations::{MigrationState, Migrations, Migrator};
+use pgrs::schema::{check_drift, diff, introspect, DatabaseSchema, DriftIssue, TableDef};
+use pgrs::traits::{Column, DatabaseDriver, Table};
+use pgrs::types::{PgRange, PgType, QueryResult, SqlValue};
+use pgrs::{PgRsClient, WhereClause};
+
+async fn connect() -> TokioPostgresDriver {
+    let url = std::env::var("PGRS_TEST_DATABASE_URL")
+        .expect("PGRS_TEST_DATABASE_URL must be set to run PostgreSQL tests");
+    TokioPostgresDriver::connect(&url).await.unwrap()
+}
+
+async fn reset_schema(driver: &TokioPostgresDriver, schema: &str) {
+    for sql in [
+        format!("DROP SCHEMA IF EXISTS {} CASCADE", schema),
+        format!("CREATE SCHEMA {}", schema),
+    ] {
+        driver.execute(&sql, &[]).await.unwrap();
+    }
+}
+
+#[tokio::test]
+#[ignore = "requires PGRS_TEST_DATABASE_URL"]
+async fn test_introspect_and_codegen() {
+    let driver = connect().await;
+    reset_schema(&driver, "pgrs_introspect").await;
+    for sql in [
+        "CREATE TYPE pgrs_introspect.status AS ENUM ('active', 'disabled')",
+        "CREATE TABLE pgrs_introspect.accounts (\
+         id bigserial PRIMARY KEY, \
+         email character varying(255) NOT NULL UNIQUE, \
+         status pgrs_introspect.status, \
+         tags text[], \
+         seen_at timestamp(3) with time zone)",
+    ] {
+        driver.execute(sql, &[]).await.unwrap();
+    }
+
+    let schema = introspect(&driver, &["pgrs_introspect"]).await.unwrap();
+
+    let accounts = schema
+        .table(Some("pgrs_introspect"), "accounts")
+        .expect("accounts table");
+    let names: Vec<&str> = accounts.columns.iter().map(|c| c.name.as_str()).collect();
+    assert_eq!(names, ["id", "email", "status", "tags", "seen_at"]);
+
+    let id = accounts.column("id").unwrap();
+    assert_eq!(id.sql_type, PgType::BigInt);
+    assert!(id.primary_key);
+    assert!(!id.nullable);
+    assert!(id.default.as_deref().unwrap().starts_with("nextval("));
+
+    let email = accounts.column("email").unwrap();
+    assert_eq!(email.sql_type, PgType::Varchar(Some(255)));
+    assert!(email.unique);
+
+    assert_eq!(
+        accounts.column("tags").unwrap().sql_type,
+        PgType::Array(Box::new(PgType::Text))
+    );
+    assert_eq!(
+        accounts.column("seen_at").unwrap().sql_type,
+        PgType::TimestampTz
+    );
+
+    let status = schema
+        .enum_type(Some("pgrs_introspect"), "status")
+        .expect("status enum");
+    assert_eq!(status.labels, ["active", "disabled"]);
+
+    let files = generate(&schema, &CodegenOptions::default()).unwrap();
+    assert_eq!(files[0].path, "pgrs_introspect.rs");
+    assert!(files[0].contents.contains("pub status: Option<Status>,"));
+    assert_eq!(
+        files,
+        generate(&schema, &CodegenOptions::default()).unwrap()
+    );
+
+    let options = CodegenOptions::from_features(["chrono"]).unwrap();
+    let files = generate(&schema, &options).unwrap();
+    assert!(files[0]
+        .contents
+        .contains("pub seen_at: Option<chrono::DateTime<chrono::Utc>>,"));
+}
+
+pgrs::table! {
+    pgrs_drift.accounts as Accounts, AccountsColumns {
+        #[primary_key]
+        id as AccountsId: i64,
+        email as AccountsEmail: String,
+        age as AccountsAge: i32,
+        nickname as AccountsNickname: Option<String>,
+    }
+}
+
+#[tokio::test]
+#[ignore = "requires PGRS_TEST_DATABASE_URL"]
+async fn test_check_drift() {
+    let driver = connect().await;
+    reset_schema(&driver, "pgrs_drift").await;
+    driver
+        .execute(
+            "CREATE TABLE pgrs_drift.accounts (id bigserial PRIMARY KEY, email text, age bigint NOT NULL)",
+            &[],
+        )
+        .await
+        .unwrap();
+
+    let tables = [TableDef::of::<Accounts>().unwrap()];
+    let report = check_drift(&driver, &tables).await.unwrap();
+    assert_eq!(report.issues.len(), 3, "{}", report);
+    assert!(matches!(
+        &report.issues[0],
+        DriftIssue::NullabilityMismatch { column, .. } if column == "email"
+    ));
+    assert!(matches!(
+        &report.issues[1],
+        DriftIssue::TypeMismatch { column, .. } if column == "age"
+    ));
+    assert!(matches!(
+        &report.issues[2],
+        DriftIssue::MissingColumn { column, .. } if column == "nickname"
+    ));
+
+    for sql in [
+        "ALTER TABLE pgrs_drift.accounts ALTER COLUMN email SET NOT NULL",
+        "ALTER TABLE pgrs_drift.accounts ALTER COLUMN age TYPE integer",
+        "ALTER TABLE pgrs_drift.accounts ADD COLUMN nickname text",
+    ] {
+        driver.execute(sql, &[]).await.unwrap();
+    }
+    let report = check_drift(&driver, &tables).await.unwrap();
+    assert!(report.is_empty(), "{}", report);
+}
+
+#[tokio::test]
+#[ignore = "requires PGRS_TEST_DATABASE_URL"]
+async fn test_migrations() {
+    let driver = connect().await;
+    reset_schema(&driver, "pgrs_migrate").await;
+    driver
+        .batch_execute("DROP TABLE IF EXISTS pgrs_migrations")
+        .await
+        .unwrap();
+    let driver = Arc::new(driver);
+    let migrations = Migrations::from_dir("tests/fixtures/migrations").unwrap();
+    let migrator = Migrator::new(driver.clone(), migrations.clone());
+
+    let states: Vec<MigrationState> = migrator
+        .status()
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|s| s.state)
+        .collect();
+    assert_eq!(states, [MigrationState::Pending, MigrationState::Pending]);
+    let result = driver
+        .execute("SELECT to_regclass('pgrs_migrations')::text", &[])
+        .await
+        .unwrap();
+    assert_eq!(result.rows[0][0], SqlValue::Null);
+
+    assert_eq!(migrator.up().await.unwrap(), [1, 2]);
+    assert!(migrator.up().await.unwrap().is_empty());
+    migrator.verify().await.unwrap();
+    driver
+        .execute(
+            "INSERT INTO pgrs_migrate.users (id, email) VALUES ($1, $2)",
+            &[1i64.into(), "a@example.com".into()],
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(migrator.down().await.unwrap(), Some(2));
+    let states: Vec<MigrationState> = migrator
+        .status()
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|s| s.state)
+        .collect();
+    assert_eq!(states, [MigrationState::Applied, MigrationState::Pending]);
+
+    // A failing migration is rolled back and not recorded
+    let broken = Migrations::new(vec![
+        migrations.get(1).unwrap().clone(),
+        migrations.get(2).unwrap().clone(),
+        pgrs::migrations::Migration::new(
+            3,
+            "broken",
+            "ALTER TABLE pgrs_migrate.users ADD COLUMN age integer; SELECT missing_column FROM pgrs_migrate.users;",
+        ),
+    ])
+    .unwrap();
+    let migrator = Migrator::new(driver.clone(), broken);
+    assert!(migrator.up().await.is_err());
+    let states: Vec<MigrationState> = migrator
+        .status()
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|s| s.state)
+        .collect();
+    assert_eq!(
+        states,
+        [
+            MigrationState::Applied,
+            MigrationState::Applied,
+            MigrationState::Pending
+        ]
+    );
+    let columns = driver
+        .execute(
+            "SELECT column_name FROM information_schema.columns \
+             WHERE table_schema = 'pgrs_migrate' AND column_name = 'age'",
+            &[],
+        )
+        .await
+        .unwrap();
+    assert!(columns.rows.is_empty());
+}
+
+mod diff_tables {
+    pgrs::table! {
+        pgrs_diff.accounts as Accounts, AccountsColumns {
+            #[primary_key]
+            id as AccountsId: i64,
+            email as AccountsEmail: String,
+            nickname as AccountsNickname: Option<String>,
+        }
+    }
+}
+
+#[tokio::test]
+#[ignore = "requires PGRS_TEST_DATABASE_URL"]
+async fn test_diff_migration() {
+    let driver = connect().await;
+    reset_schema(&driver, "pgrs_diff").await;
+    driver
+        .batch_execute(
+            "CREATE TABLE pgrs_diff.accounts (id integer PRIMARY KEY, email text, legacy text); \
+             CREATE TABLE pgrs_diff.old_sessions (id integer)",
+        )
+        .await
+        .unwrap();
+
+    let tables = [TableDef::of::<diff_tables::Accounts>().unwrap()];
+    let actual = introspect(&driver, &["pgrs_diff"]).await.unwrap();
+    let changes = diff(&tables, &actual).unwrap();
+    assert!(changes.needs_review());
+    driver
+        .batch_execute(&changes.to_migration_sql())
+        .await
+        .unwrap();
+
+    // Only the flagged drops remain
+    let actual = introspect(&driver, &["pgrs_diff"]).await.unwrap();
+    let remaining = diff(&tables, &actual).unwrap();
+    let sqls: Vec<&str> = remaining.changes.iter().map(|c| c.sql.as_str()).collect();
+    assert_eq!(
+        sqls,
+        [
+            "ALTER TABLE pgrs_diff.accounts ALTER COLUMN email SET NOT NULL",
+            "ALTER TABLE pgrs_diff.accounts DROP COLUMN legacy",
+            "DROP TABLE pgrs_diff.old_sessions",
+        ]
+    );
+
+    for change in &remaining.changes {
+        driver.batch_execute(&change.sql).await.unwrap();
+    }
+    let actual = introspect(&driver, &["pgrs_diff"]).await.unwrap();
+    assert!(diff(&tables, &actual).unwrap().is_empty());
+
+    // A snapshot of the declared tables produces no further changes
+    let snapshot = DatabaseSchema {
+        tables: tables.to_vec(),
+        enums: Vec::new(),
+    }
+    .to_snapshot();
+    let snapshot = DatabaseSchema::from_snapshot(&snapshot).unwrap();
+    assert!(diff(&tables, &snapshot).unwrap().is_empty());
+}
+
+/// A table whose schema, table and column names all need quoting.
+struct Orders;
+struct OrdersUser;
+struct OrdersCreatedAt;
+
+impl Table for Orders {
+    type Columns = ();
+
+    fn table_name() -> &'static str {
+        "order"
+    }
+
+    fn schema() -> Option<&'static str> {
+        Some("pgrs_Quote")
+    }
+
+    fn columns() -> Self::Columns {}
+}
+
+impl Column for OrdersUser {
+    fn column_name(&self) -> &'static str {
+        "user"
+    }
+
+    fn table_name(&self) -> &'static str {
+        "order"
+    }
+
+    fn schema_name(&self) -> Option<&'static str> {
+        Some("pgrs_Quote")
+    }
+}
+
+impl Column for OrdersCreatedAt {
+    fn column_name(&self) -> &'static str {
+        "Created \"At\""
+    }
+
+    fn table_name(&self) -> &'static str {
+        "order"
+    }
+
+    fn schema_name(&self) -> Option<&'static str> {
+        Some("pgrs_Quote")
+    }
+}
+
+#[tokio::test]
+#[ignore = "requires PGRS_TEST_DATABASE_URL"]
+async fn test_quoted_identifiers() {
+    let driver = connect().await;
+    reset_schema(&driver, "\"pgrs_Quote\"").await;
+    driver
+        .batch_execute(
+            "CREATE TABLE \"pgrs_Quote\".\"order\" (\"user\" text, \"Created \"\"At\"\"\" text); \
+             INSERT INTO \"pgrs_Quote\".\"order\" VALUES ('alice', 'today'), ('bob', 'yesterday')",
+        )
+        .await
+        .unwrap();
+
+    let client = PgRsClient::with_driver(Arc::new(driver));
+    let row = client
+        .querier()
+        .select()
+        .columns(&[&OrdersCreatedAt])
+        .from(Orders)
+        .where_(WhereClause::eq(&OrdersUser, "bob"))
+        .execute()
+        .await
+        .unwrap()
+        .single_row()
+        .unwrap();
+    assert_eq!(row.get(&OrdersCreatedAt).unwrap(), "yesterday");
+}
+
+#[tokio::test]
+#[ignore = "requires PGRS_TEST_DATABASE_URL"]
+async fn test_in_lists() {
+    let driver = connect().await;
+    reset_schema(&driver, "pgrs_in").await;
+    driver
+        .batch_execute(
+            "CREATE TABLE pgrs_in.items (id bigint, name text); \
+             INSERT INTO pgrs_in.items VALUES (1, 'a'), (2, 'b'), (3, NULL)",
+        )
+        .await
+        .unwrap();
+
+    let client = PgRsClient::with_driver(Arc::new(driver));
+    let items = DynTable::new("items").unwrap().schema("pgrs_in").unwrap();
+    let id = items.column("id").unwrap();
+    let name = items.column("name").unwrap();
+
+    let clauses = [
+        (WhereClause::in_list(&id, [1i64, 3]), vec!["1", "3"]),
+        (
+            WhereClause::in_list_with(&id, [1i64, 3], InListStyle::Any),
+            vec!["1", "3"],
+        ),
+        (
+            WhereClause::not_in_with(&name, ["b"], InListStyle::Any),
+            vec!["1"],
+        ),
+        (
+            WhereClause::is_distinct_from(&name, None::<String>)
+                .and(WhereClause::not_in(&id, Vec::<i64>::new())),
+            vec!["1", "2"],
+        ),
+    ];
+    for (clause, expected) in clauses {
+        let result = client
+            .querier()
+            .select()
+            .columns(&[&id])
+            .from(items.clone())
+            .where_(clause)
+            .execute()
+            .await
+            .unwrap();
+        let ids: Vec<String> = result
+            .rows_ref()
+            .iter()
+            .map(|row| row.get(&id).unwrap().into_owned())
+            .collect();
+        assert_eq!(ids, expected);
+    }
+}
+
+#[tokio::test]
+#[ignore = "requires PGRS_TEST_DATABASE_URL"]
+async fn test_pattern_matching() {
+    let driver = connect().await;
+    reset_schema(&driver, "pgrs_pattern").await;
+    driver
+        .batch_execute(
+            "CREATE TABLE pgrs_pattern.notes (id int, body text); \
+             INSERT INTO pgrs_pattern.notes VALUES \
+             (1, '50% off'), (2, '500 off'), (3, 'snake_case'), (4, 'snakeXcase'), (5, 'C:\\dir')",
+        )
+        .await
+        .unwrap();
+
+    let client = PgRsClient::with_driver(Arc::new(driver));
+    let notes = DynTable::new("notes")
+        .unwrap()
+        .schema("pgrs_pattern")
+        .unwrap();
+    let id = notes.column("id").unwrap();
+    let body = notes.column("body").unwrap();
+
+    let clauses = [
+        (WhereClause::contains(&body, "0%"), vec!["1"]),
+        (WhereClause::like(&body, "50%"), vec!["1", "2"]),
+        (WhereClause::starts_with(&body, "snake_"), vec!["3"]),
+        (WhereClause::iends_with(&body, "\\DIR"), vec!["5"]),
+        (
+            WhereClause::regex_imatch(&body, "^SNAKE.CASE$"),
+            vec!["3", "4"],
+        ),
+        (WhereClause::similar_to(&body, "5(0|00)%"), vec!["1", "2"]),
+    ];
+    for (clause, expected) in clauses {
+        let result = client
+            .querier()
+            .select()
+            .columns(&[&id])
+            .from(notes.clone())
+            .where_(clause)
+            .execute()
+            .await
+            .unwrap();
+        let ids: Vec<String> = result
+            .rows_ref()
+            .iter()
+            .map(|row| row.get(&id).unwrap().into_owned())
+            .collect();
+        assert_eq!(ids, expected);
+    }
+}
+
+#[tokio::test]
+#[ignore = "requires PGRS_TEST_DATABASE_URL"]
+async fn test_jsonb() {
+    let driver = connect().await;
+    reset_schema(&driver, "pgrs_json").await;
+    driver
+        .batch_execute(
+            "CREATE TABLE pgrs_json.docs (id int, data jsonb); \
+             INSERT INTO pgrs_json.docs VALUES \
+             (1, '{\"name\": \"ann\", \"tags\": [\"a\", \"b\"], \"profile\": {\"age\": 30}}'), \
+             (2, '{\"name\": \"bob\", \"tags\": [\"c\"], \"profile\": {\"age\": 40}}')",
+        )
+        .await
+        .unwrap();
+
+    let client = PgRsClient::with_driver(Arc::new(driver));
+    let docs = DynTable::new("docs").unwrap().schema("pgrs_json").unwrap();
+    let id = docs.column("id").unwrap();
+    let data = docs.column("data").unwrap();
+
+    let clauses = [
+        (
+            WhereClause::eq(Operand::column(&data).get_text("name"), "bob"),
+            vec!["2"],
+        ),
+        (
+            WhereClause::eq(Operand::column(&data).get("tags").get_text(1), "b"),
+            vec!["1"],
+        ),
+        (
+            WhereClause::eq(
+                Operand::column(&data).get_path_text(["profile", "age"]),
+                "40",
+            ),
+            vec!["2"],
+        ),
+        (
+            WhereClause::json_contains(&data, r#"{"tags": ["c"]}"#),
+            vec!["2"],
+        ),
+        (
+            WhereClause::json_contained_by(
+                Operand::column(&data).get_path(["profile"]),
+                r#"{"age": 30, "city": "x"}"#,
+            ),
+            vec!["1"],
+        ),
+        (WhereClause::has_key(&data, "profile"), vec!["1", "2"]),
+        (
+            WhereClause::has_any_key(Operand::column(&data).get("profile"), ["age", "x"]),
+            vec!["1", "2"],
+        ),
+        (
+            WhereClause::has_all_keys(&data, ["name", "missing"]),
+            vec![],
+        ),
+        (
+            WhereClause::json_path_exists(&data, "$.tags[*] ? (@ == \"a\")"),
+            vec!["1"],
+        ),
+        (
+            WhereClause::json_path_match(&data, "$.profile.age > 35"),
+            vec!["2"],
+        ),
+        (
+            WhereClause::compare(
+                &data,
+                Operator::Contains,
+                Operand::column(&data).concat(SqlValue::Json(r#"{"name": "bob"}"#.to_string())),
+            ),
+            vec!["2"],
+        ),
+    ];
+    for (clause, expected) in clauses {
+        let result = client
+            .querier()
+            .select()
+            .columns(&[&id])
+            .from(docs.clone())
+            .where_(clause)
+            .execute()
+            .await
+            .unwrap();
+        let ids: Vec<String> = result
+            .rows_ref()
+            .iter()
+            .map(|row| row.get(&id).unwrap().into_owned())
+            .collect();
+        assert_eq!(ids, expected);
+    }
+
+    let result = client
+        .querier()
+        .update()
+        .table(docs.clone())
+        .set_expr(
+            &data,
+            Operand::column(&data)
+                .jsonb_set(["profile", "age"], SqlValue::Json("31".to_string()))
+                .concat(SqlValue::Json(r#"{"seen": true}"#.to_string())),
+        )
+        .where_(WhereClause::eq(&id, 1))
+        .returning(&[&data])
+        .execute()
+        .await
+        .unwrap();
+    let row = result.single_row().unwrap();
+    assert_eq!(
+        row.get(&data).unwrap(),
+        r#"{"name": "ann", "seen": true, "tags": ["a", "b"], "profile": {"age": 31}}"#
+    );
+}
+
+#[tokio::test]
+#[ignore]
+async fn test_arrays() {
+    let driver = connect().await;
+    reset_schema(&driver, "pgrs_array").await;
+    driver
+        .batch_execute(
+            "CREATE TABLE pgrs_array.items (id int, tags text[], nums int8[], ids uuid[]); \
+             INSERT INTO pgrs_array.items VALUES \
+             (1, '{a,b}', '{1,2}', '{00000000-0000-0000-0000-000000000001}'), \
+             (2, '{\"two words\",\"x,y\"}', '{3}', '{}')",
+        )
+        .await
+        .unwrap();
+
+    let client = PgRsClient::with_driver(Arc::new(driver));
+    let items = DynTable::new("items")
+        .unwrap()
+        .schema("pgrs_array")
+        .unwrap();
+    let id = items.column("id").unwrap();
+    let tags = items.column("tags").unwrap();
+    let nums = items.column("nums").unwrap();
+    let ids = items.column("ids").unwrap();
+
+    let clauses = [
+        (WhereClause::array_contains(&tags, ["a"]), vec!["1"]),
+        (
+            WhereClause::array_contains(&tags, Vec::<String>::new()),
+            vec!["1", "2"],
+        ),
+        (
+            WhereClause::array_contained_by(&nums, [1i64, 2, 5]),
+            vec!["1"],
+        ),
+        (
+            WhereClause::array_overlaps(&nums, [2i64, 3]),
+            vec!["1", "2"],
+        ),
+        (WhereClause::eq_any(&tags, "x,y"), vec!["2"]),
+    ];
+    for (clause, expected) in clauses {
+        let result = client
+            .querier()
+            .select()
+            .columns(&[&id])
+            .from(items.clone())
+            .where_(clause)
+            .execute()
+            .await
+            .unwrap();
+        let found: Vec<String> = result
+            .rows_ref()
+            .iter()
+            .map(|row| row.get(&id).unwrap().into_owned())
+            .collect();
+        assert_eq!(found, expected);
+    }
+
+    let result = client
+        .querier()
+        .select()
+        .columns(&[&tags, &nums, &ids])
+        .from(items.clone())
+        .where_(WhereClause::eq(&id, 2))
+        .execute()
+        .await
+        .unwrap();
+    let row = result.single_row().unwrap();
+    assert_eq!(
+        row.get_array::<String, _>(&tags).unwrap(),
+        vec!["two words", "x,y"]
+    );
+    assert_eq!(row.get_array::<i64, _>(&nums).unwrap(), vec![3]);
+    assert_eq!(row.get_as::<Vec<i64>, _>(&nums).unwrap(), vec![3]);
+    assert_eq!(
+        row.get_as::<Vec<Option<String>>, _>(&tags).unwrap(),
+        vec![Some("two words".to_string()), Some("x,y".to_string())]
+    );
+    assert!(row.get_array::<String, _>(&ids).unwrap().is_empty());
+
+    let result = client
+        .querier()
+        .select()
+        .columns(&[&ids])
+        .from(items.clone())
+        .where_(WhereClause::eq(&id, 1))
+        .execute()
+        .await
+        .unwrap();
+    assert_eq!(
+        result
+            .single_row()
+            .unwrap()
+            .get_array::<String, _>(&ids)
+            .unwrap(),
+        vec!["00000000-0000-0000-0000-000000000001"]
+    );
+}
+
+#[tokio::test]
+#[ignore]
+async fn test_full_text_search() {
+    let driver = connect().await;
+    reset_schema(&driver, "pgrs_fts").await;
+    driver
+        .batch_execute(
+            "CREATE TABLE pgrs_fts.posts (id int, body text); \
+             INSERT INTO pgrs_fts.posts VALUES \
+             (1, 'The quick brown fox jumps over the lazy dog'), \
+             (2, 'Foxes are quick. Quick foxes jump quickly.'), \
+             (3, 'A slow turtle')",
+        )
+        .await
+        .unwrap();
+
+    let client = PgRsClient::with_driver(Arc::new(driver));
+    let posts = DynTable::new("posts").unwrap().schema("pgrs_fts").unwrap();
+    let id = posts.column("id").unwrap();
+    let body = posts.column("body").unwrap();
+    let search = TextSearch::new("english");
+    let vector = search.to_tsvector(&body);
+
+    let query = search.websearch_to_tsquery("quick fox -lazy");
+    let result = client
+        .querier()
+        .select()
+        .columns(&[&id])
+        .expression(vector.clone().ts_rank_cd(query.clone()), "rank")
+        .expression(search.headline(&body, query.clone()), "snippet")
+        .from(posts.clone())
+        .where_(WhereClause::text_match(vector.clone(), query))
+        .execute()
+        .await
+        .unwrap();
+    let row = result.single_row().unwrap();
+    assert_eq!(row.get(&id).unwrap(), "2");
+    assert!(row.get_named("rank").unwrap().parse::<f32>().unwrap() > 0.0);
+    assert!(row.get_named("snippet").unwrap().contains("<b>Foxes</b>"));
+
+    let query = search.plainto_tsquery("quick foxes");
+    let result = client
+        .querier()
+        .select()
+        .columns(&[&id])
+        .from(posts.clone())
+        .where_(WhereClause::text_match(vector.clone(), query.clone()))
+        .order_by(vector.ts_rank(query), SortDirection::Desc)
+        .execute()
+        .await
+        .unwrap();
+    let ids: Vec<String> = result
+        .rows_ref()
+        .iter()
+        .map(|row| row.get(&id).unwrap().into_owned())
+        .collect();
+    assert_eq!(ids, vec!["2", "1"]);
+}
+
+#[tokio::test]
+#[ignore]
+async fn test_ranges() {
+    let driver = connect().await;
+    reset_schema(&driver, "pgrs_range").await;
+    driver
+        .batch_execute(
+            "CREATE TABLE pgrs_range.bookings (id int, seats int4range, during tstzrange); \
+             INSERT INTO pgrs_range.bookings VALUES \
+             (1, '[1,5)', '[2024-01-01 10:00+00,2024-01-01 12:00+00)'), \
+             (2, '[5,9]', '[2024-01-01 12:00+00,2024-01-01 14:00+00)'), \
+             (3, 'empty', '[2024-01-02 10:00+00,)')",
+        )
+        .await
+        .unwrap();
+
+    let client = PgRsClient::with_driver(Arc::new(driver));
+    let bookings = DynTable::new("bookings")
+        .unwrap()
+        .schema("pgrs_range")
+        .unwrap();
+    let id = bookings.column("id").unwrap();
+    let seats = bookings.column("seats").unwrap();
+    let during = bookings.column("during").unwrap();
+    let period = |lower: &str, upper: &str| {
+        SqlValue::TstzRange(PgRange::new(
+            Bound::Included(lower.to_string()),
+            Bound::Excluded(upper.to_string()),
+        ))
+    };
+
+    let clauses = [
+        (WhereClause::range_contains_element(&seats, 3), vec!["1"]),
+        (
+            WhereClause::range_contains(&seats, PgRange::from(6..=9)),
+            vec!["2"],
+        ),
+        (
+            WhereClause::range_contained_by(&seats, PgRange::from(0..)),
+            vec!["1", "2", "3"],
+        ),
+        (
+            WhereClause::range_overlaps(
+                &during,
+                period("2024-01-01 11:00+00", "2024-01-01 13:00+00"),
+            ),
+            vec!["1", "2"],
+        ),
+        (
+            WhereClause::range_adjacent(
+                &during,
+                period("2024-01-01 08:00+00", "2024-01-01 10:00+00"),
+            ),
+            vec!["1"],
+        ),
+        (
+            WhereClause::range_left_of(&seats, PgRange::from(5..7)),
+            vec!["1"],
+        ),
+        (
+            WhereClause::range_right_of(
+                &during,
+                period("2024-01-01 00:00+00", "2024-01-01 12:00+00"),
+            ),
+            vec!["2", "3"],
+        ),
+    ];
+    for (clause, expected) in clauses {
+        let result = client
+            .querier()
+            .select()
+            .columns(&[&id])
+            .from(bookings.clone())
+            .where_(clause)
+            .execute()
+            .await
+            .unwrap();
+        let ids: Vec<String> = result
+            .rows_ref()
+            .iter()
+            .map(|row| row.get(&id).unwrap().into_owned())
+            .collect();
+        assert_eq!(ids, expected);
+    }
+
+    let result = client
+        .querier()
+        .update()
+        .table(bookings.clone())
+        .set(&seats, PgRange::new(Bound::Excluded(10), Bound::Unbounded))
+        .where_(WhereClause::eq(&id, 3))
+        .returning(&[&seats])
+        .execute()
+        .await
+        .unwrap();
+    assert_eq!(result.single_row().unwrap().get(&seats).unwrap(), "[11,)");
+
+    let result = client
+        .querier()
+        .select()
+        .columns(&[&seats])
+        .from(bookings.clone())
+        .where_(WhereClause::eq(&id, 2))
+        .execute()
+        .await
+        .unwrap();
+    assert_eq!(result.single_row().unwrap().get(&seats).unwrap(), "[5,10)");
+}
+
+#[tokio::test]
+#[ignore]
+async fn test_scalar_types() {
+    let driver = connect().await;
+    reset_schema(&driver, "pgrs_scalar").await;
+    driver
+        .batch_execute(
+            "CREATE TABLE pgrs_scalar.samples (id int, small smallint, single real, \
+             double double precision, amount numeric(30,10), blob bytea, ident uuid, \
+             day date, clock time, stamp timestamp, stamptz timestamptz, span interval); \
+             INSERT INTO pgrs_scalar.samples VALUES \
+             (1, 7, 1.5, 2.25, 12345678901.0123456789, '\\x0001ff', \
+              '67e55044-10b1-426f-9247-bb680e5fe0c8', '2024-01-31', '14:30:00', \
+              '2024-01-31 14:30:00', '2024-01-31 14:30:00+00', '90 seconds'), \
+             (2, 8, 2.5, 3.25, 1, '\\x', \
+              '00000000-0000-0000-0000-000000000000', '2024-02-01', '08:00:00', \
+              '2024-02-01 08:00:00', '2024-02-01 08:00:00+00', '1 day')",
+        )
+        .await
+        .unwrap();
+
+    let client = PgRsClient::with_driver(Arc::new(driver));
+    let samples = DynTable::new("samples")
+        .unwrap()
+        .schema("pgrs_scalar")
+        .unwrap();
+    let id = samples.column("id").unwrap();
+    let column = |name: &str| samples.column(name).unwrap();
+    let text = |s: &str| s.to_string();
+
+    let clauses = [
+        WhereClause::eq(&column("small"), 7i16),
+        WhereClause::eq(&column("single"), 1.5f32),
+        WhereClause::eq(&column("double"), 2.25f64),
+        WhereClause::eq(
+            &column("amount"),
+            SqlValue::Numeric(text("12345678901.0123456789")),
+        ),
+        WhereClause::eq(&column("blob"), &[0u8, 1, 255][..]),
+        WhereClause::eq(
+            &column("ident"),
+            SqlValue::Uuid(text("67e55044-10b1-426f-9247-bb680e5fe0c8")),
+        ),
+        WhereClause::eq(&column("day"), SqlValue::Date(text("2024-01-31"))),
+        WhereClause::eq(&column("clock"), SqlValue::Time(text("14:30:00"))),
+        WhereClause::eq(
+            &column("stamp"),
+            SqlValue::Timestamp(text("2024-01-31 14:30:00")),
+        ),
+        WhereClause::eq(
+            &column("stamptz"),
+            SqlValue::TimestampTz(text("2024-01-31T16:30:00+02:00")),
+        ),
+        WhereClause::eq(&column("span"), std::time::Duration::from_secs(90)),
+        WhereClause::between(
+            &column("day"),
+            SqlValue::Date(text("2024-01-01")),
+            SqlValue::Date(text("2024-01-31")),
+        ),
+        WhereClause::in_list_with(
+            &column("ident"),
+            [SqlValue::Uuid(text("67e55044-10b1-426f-9247-bb680e5fe0c8"))],
+            InListStyle::Any,
+        ),
+    ];
+    for clause in clauses {
+        let result = client
+            .querier()
+            .select()
+            .columns(&[&id])
+            .from(samples.clone())
+            .where_(clause)
+            .execute()
+            .await
+            .unwrap();
+        let ids: Vec<String> = result
+            .rows_ref()
+            .iter()
+            .map(|row| row.get(&id).unwrap().into_owned())
+            .collect();
+        assert_eq!(ids, vec!["1"]);
+    }
+
+    // Values are decoded according to their declared types
+    let names = [
+        "small", "single", "double", "amount", "blob", "ident", "day", "clock", "stamp", "stamptz",
+        "span",
+    ];
+    let selected: Vec<_> = names.iter().map(|name| column(name)).collect();
+    let selected_refs: Vec<&dyn Column> = selected.iter().map(|c| c as &dyn Column).collect();
+    let result = client
+        .querier()
+        .select()
+        .columns(&selected_refs)
+        .from(samples.clone())
+        .where_(WhereClause::eq(&id, 1))
+        .execute()
+        .await
+        .unwrap();
+    assert_eq!(
+        result.column_types(),
+        &[21, 700, 701, 1700, 17, 2950, 1082, 1083, 1114, 1184, 1186]
+    );
+    let row = result.single_row().unwrap();
+    let values: Vec<SqlValue> = selected
+        .iter()
+        .map(|c| row.value(c).unwrap().clone())
+        .collect();
+    assert_eq!(
+        values,
+        vec![
+            SqlValue::Int16(7),
+            SqlValue::Float32(1.5),
+            SqlValue::Float64(2.25),
+            SqlValue::Numeric(text("12345678901.0123456789")),
+            SqlValue::Bytea(vec![0, 1, 255]),
+            SqlValue::Uuid(text("67e55044-10b1-426f-9247-bb680e5fe0c8")),
+            SqlValue::Date(text("2024-01-31")),
+            SqlValue::Time(text("14:30:00")),
+            SqlValue::Timestamp(text("2024-01-31 14:30:00")),
+            SqlValue::TimestampTz(text("2024-01-31 14:30:00+00")),
+            SqlValue::Interval(text("00:01:30")),
+        ]
+    );
+    assert_eq!(row.get(&column("blob")).unwrap(), "\\x0001ff");
+
+    let driver = connect().await;
+    let result = driver
+        .execute(
+            "SELECT NULL::int AS nothing, 'NULL'::text AS word, \
+             '{{1,2},{3,NULL}}'::int[] AS grid, '(1.5,2]'::numrange AS span, \
+             'd'::\"char\" AS letter, '1.2.3.4'::inet AS address, \
+             '-2 years -1 mons 3 days'::interval AS back",
+            &[],
+        )
+        .await
+        .unwrap();
+    assert_eq!(
+        result.rows[0],
+        vec![
+            SqlValue::Null,
+            SqlValue::Text(text("NULL")),
+            SqlValue::Array(vec![
+                SqlValue::Array(vec![SqlValue::Int32(1), SqlValue::Int32(2)]),
+                SqlValue::Array(vec![SqlValue::Int32(3), SqlValue::Null]),
+            ]),
+            SqlValue::NumRange(PgRange::new(
+                Bound::Excluded(text("1.5")),
+                Bound::Included(text("2")),
+            )),
+            SqlValue::Text(text("d")),
+            SqlValue::Raw(vec![2, 32, 0, 4, 1, 2, 3, 4]),
+            SqlValue::Interval(text("-2 years -1 mons 3 days")),
+        ]
+    );
+    let row = &QueryResult::from_raw(result).rows()[0];
+    assert!(matches!(
+        row.get_named("nothing"),
+        Err(PgRsError::UnexpectedNull(_))
+    ));
+    assert_eq!(row.get_named("word").unwrap(), "NULL");
+
+    let result = driver
+        .execute("SELECT id FROM pgrs_scalar.samples WHERE false", &[])
+        .await
+        .unwrap();
+    assert_eq!(result.columns, vec!["id"]);
+    assert_eq!(result.column_types, vec![23]);
+}
+
+#[tokio::test]
+#[ignore = "requires PGRS_TEST_DATABASE_URL"]
+async fn test_typed_placeholders() {
+    let driver = connect().await;
+    let text = |s: &str| s.to_string();
+    let range = |lower: &str, upper: &str| {
+        PgRange::new(Bound::Included(text(lower)), Bound::Excluded(text(upper)))
+    };
+
+    // Each value is bound in its type's binary format and read back
+    let cases = [
+        (
+            "numeric",
+            SqlValue::Numeric(text("-12345678901.0123456789")),
+        ),
+        (
+            "uuid",
+            SqlValue::Uuid(text("67e55044-10b1-426f-9247-bb680e5fe0c8")),
+        ),
+        ("date", SqlValue::Date(text("2024-01-31"))),
+        ("time", SqlValue::Time(text("14:30:00.5"))),
+        (
+            "timestamp",
+            SqlValue::Timestamp(text("2024-01-31 14:30:00")),
+        ),
+        (
+            "timestamptz",
+            SqlValue::TimestampTz(text("2024-01-31 14:30:00.25+00")),
+        ),
+        (
+            "interval",
+            SqlValue::Interval(text("1 year 2 mons -3 days 04:05:06.5")),
+        ),
+        ("int4range", SqlValue::Int4Range(PgRange::from(1..5))),
+        ("int8range", SqlValue::Int8Range(PgRange::from(1..5))),
+        ("numrange", SqlValue::NumRange(range("1.5", "2.25"))),
+        (
+            "tsrange",
+            SqlValue::TsRange(range("2024-01-01 00:00:00", "2024-02-01 00:00:00")),
+        ),
+        (
+            "tstzrange",
+            SqlValue::TstzRange(range("2024-01-01 00:00:00+00", "2024-02-01 00:00:00+00")),
+        ),
+        (
+            "daterange",
+            SqlValue::DateRange(range("2024-01-01", "2024-02-01")),
+        ),
+        ("int4range", SqlValue::Int4Range(PgRange::empty())),
+        (
+            "uuid[]",
+            SqlValue::Array(vec![SqlValue::Uuid(text(
+                "00000000-0000-0000-0000-000000000001",
+            ))]),
+        ),
+    ];
+    for (pg_type, value) in cases {
+        let sql = format!("SELECT $1::{}", pg_type);
+        let result = driver
+            .execute(&sql, std::slice::from_ref(&value))
+            .await
+            .unwrap();
+        assert_eq!(result.rows[0][0], value, "{}", pg_type);
+    }
+
+    // Offsets other than UTC are converted, and values compare as their type
+    let result = driver
+        .execute(
+            "SELECT $1 = '2024-01-31 14:30:00+00'::timestamptz, $2 = '90 seconds'::interval, \
+             $3 = 12.5::numeric",
+            &[
+                SqlValue::TimestampTz(text("2024-01-31T16:30:00+02:00")),
+                SqlValue::Interval(text("90.000000 seconds")),
+                SqlValue::Numeric(text("12.50")),
+            ],
+        )
+        .await
+        .unwrap();
+    assert_eq!(result.rows[0], vec![SqlValue::Bool(true); 3]);
+
+    let err = driver
+        .execute("SELECT $1::uuid", &[SqlValue::Uuid(text("not-a-uuid"))])
+        .await
+        .unwrap_err();
+    assert!(matches!(err, PgRsError::QueryFailed(_)));
+    let err = driver
+        .execute("SELECT $1::date", &[SqlValue::Uuid(text("not-a-uuid"))])
+        .await
+        .unwrap_err();
+    assert!(matches!(err, PgRsError::QueryFailed(_)));
+}
@@ -2,8 +2,9 @@ use std::sync::Arc;
 
 use pgrs::drivers::{InMemoryTestDriver, InMemoryTestResponseBuilder};
 use pgrs::error::PgRsError;
+use pgrs::schema::{ColumnDef, CreateTableBuilder};
 use pgrs::traits::{Column, DatabaseDriver, Table};
-use pgrs::types::{QueryResult, SqlValue};
+use pgrs::types::{PgType, QueryResult, SqlValue};
 use pgrs::{PgRsClient, WhereClause};
 
 // Test table and columns
@@ -190,6 +191,75 @@ async fn test_select_empty_result() {
     }
 }
 
+#[tokio::test]
+async fn test_select_typed_values() {
+    let in_memory_test_driver = Arc::new(
+        InMemoryTestDriver::new().with_response(
+            InMemoryTestResponseBuilder::new()
+                .columns(&["id", "name"])
+                .column_types(&[20, 25])
+                .typed_row(vec![SqlValue::Int64(7), SqlValue::Null])
+                .build(),
+        ),
+    );
+    let client = PgRsClient::with_driver(in_memory_test_driver);
+
+    let result = client
+        .querier()
+        .select()
+        .columns(&[&Users::columns().id, &Users::columns().name])
+        .from(Users)
+        .execute()
+        .await
+        .unwrap();
+
+    assert_eq!(result.column_types(), &[20, 25]);
+    let row = result.single_row().unwrap();
+    assert_eq!(
+        row.value(&Users::columns().id).unwrap(),
+        &SqlValue::Int64(7)
+    );
+    assert_eq!(row.get_as::<i64, _>(&Users::columns().id).unwrap(), 7);
+    assert_eq!(
+        row.get_as::<Option<String>, _>(&Users::columns().name)
+            .unwrap(),
+        None
+    );
+}
+
+#[tokio::test]
+async fn test_select_null_values() {
+    let in_memory_test_driver = Arc::new(
+        InMemoryTestDriver::new().with_response(
+            InMemoryTestResponseBuilder::new()
+                .columns(&["id", "name"])
+                .row_opt(&[Some("1"), None])
+                .row_opt(&[Some("2"), Some("NULL")])
+                .build(),
+        ),
+    );
+    let client = PgRsClient::with_driver(in_memory_test_driver);
+
+    let result = client
+        .querier()
+        .select()
+        .columns(&[&Users::columns().id, &Users::columns().name])
+        .from(Users)
+        .execute()
+        .await
+        .unwrap();
+
+    let rows = result.rows();
+    assert!(rows[0].is_null(&Users::columns().name).unwrap());
+    assert_eq!(rows[0].get_opt(&Users::columns().name).unwrap(), None);
+    assert!(matches!(
+        rows[0].get(&Users::columns().name),
+        Err(PgRsError::UnexpectedNull(_))
+    ));
+    assert!(!rows[1].is_null(&Users::columns().name).unwrap());
+    assert_eq!(rows[1].get(&Users::columns().name).unwrap(), "NULL");
+}
+
 #[tokio::test]
 async fn test_multiple_queries() {
     let in_memory_test_driver = Arc::new(
@@ -285,10 +355,65 @@ async fn test_compound_where_clause() {
 
     // Verify compound WHERE clause
     in_memory_test_driver.assert_last_query(
-        "SELECT users.name FROM users WHERE (users.name = $1) AND (users.id = $2)",
+        "SELECT users.name FROM users WHERE users.name = $1 AND users.id = $2",
         &[SqlValue::Text("Admin".to_string()), SqlValue::Int32(1)],
     );
 
     let row = result.single_row().unwrap();
     assert_eq!(row.get(&Users::columns().name).unwrap(), "Admin");
 }
+
+#[tokio::test]
+async fn test_comparison_where_clause() {
+    let in_memory_test_driver = Arc::new(InMemoryTestDriver::new());
+    let driver: Arc<dyn DatabaseDriver> =
+        Arc::clone(&in_memory_test_driver) as Arc<dyn DatabaseDriver>;
+    let client = PgRsClient::with_driver(driver);
+
+    client
+        .querier()
+        .select()
+        .columns(&[&Users::columns().name])
+        .from(Users)
+        .where_(
+            WhereClause::gt(&Users::columns().id, 10)
+                .and(WhereClause::ne(&Users::columns().name, "Admin"))
+                .and(WhereClause::col_ne(
+                    &Users::columns().name,
+                    &Users::columns().id,
+                )),
+        )
+        .execute()
+        .await
+        .unwrap();
+
+    in_memory_test_driver.assert_last_query(
+        "SELECT users.name FROM users WHERE users.id > $1 AND users.name <> $2 \
+         AND users.name <> users.id",
+        &[SqlValue::Int32(10), SqlValue::Text("Admin".to_string())],
+    );
+}
+
+#[tokio::test]
+async fn test_execute_create_table() {
+    let in_memory_test_driver = Arc::new(InMemoryTestDriver::new());
+    let driver: Arc<dyn DatabaseDriver> =
+        Arc::clone(&in_memory_test_driver) as Arc<dyn DatabaseDriver>;
+    let client = PgRsClient::with_driver(driver);
+    let querier = client.querier();
+
+    querier
+        .execute_ddl(
+            &CreateTableBuilder::for_table(Users)
+                .if_not_exists()
+                .column(ColumnDef::for_column(&Users::columns().id, PgType::Integer).primary_key())
+                .column(ColumnDef::for_column(&Users::columns().name, PgType::Text).not_null()),
+        )
+        .await
+        .unwrap();
+
+    in_memory_test_driver.assert_last_query(
+        "CREATE TABLE IF NOT EXISTS users (id integer PRIMARY KEY, name text NOT NULL)",
+        &[],
+    );
+}
@@ -0,0 +1,133 @@
+use std::sync::Arc;
+
+use pgrs::drivers::InMemoryTestDriver;
+use pgrs::schema::{CreateTableBuilder, DdlStatement};
+use pgrs::traits::{Column, DatabaseDriver, Table};
+use pgrs::types::{PgType, SqlValue};
+use pgrs::{PgRsClient, WhereClause};
+
+pgrs::table! {
+    app.users as Users, UsersColumns {
+        #[primary_key]
+        id as UsersId: i64,
+        #[unique]
+        email as UsersEmail: String,
+        name as UsersName: Option<String>,
+    }
+}
+
+pgrs::table! {
+    tags as Tags, TagsColumns {
+        label as TagsLabel: String,
+        r#type as TagsType: String,
+        uuid as TagsUuid: i32,
+    }
+}
+
+mod request {
+    pgrs::table! { app.users { id: i64, name: Option<String> } }
+}
+
+pgrs::table! {
+    events {
+        #[primary_key]
+        id: i64,
+        r#type: String,
+        std: std::string::String,
+    }
+}
+
+#[test]
+fn test_table_macro_module_form() {
+    use request::users;
+
+    assert_eq!(users::table::qualified_name(), "app.users");
+    assert_eq!(users::table::columns().id.column_name(), "id");
+    assert_eq!(users::id.schema_name(), Some("app"));
+    assert_eq!(users::name.sql_type(), Some(PgType::Text));
+    assert!(users::name.is_nullable());
+    assert!(!users::id.is_primary_key());
+
+    let names: Vec<&str> = events::table::all_columns()
+        .iter()
+        .map(|c| c.column_name())
+        .collect();
+    assert_eq!(names, ["id", "type", "std"]);
+    assert!(events::id.is_primary_key());
+    assert_eq!(events::table::schema(), None);
+    assert_eq!(events::r#type.table_name(), "events");
+
+    let sql = CreateTableBuilder::from_table(users::table)
+        .unwrap()
+        .build_sql()
+        .unwrap();
+    assert_eq!(
+        sql,
+        "CREATE TABLE app.users (id bigint NOT NULL, name text)"
+    );
+}
+
+#[test]
+fn test_table_macro_metadata() {
+    assert_eq!(Users::table_name(), "users");
+    assert_eq!(Users::schema(), Some("app"));
+    assert_eq!(Tags::schema(), None);
+    assert_eq!(Tags::columns().r#type.column_name(), "type");
+
+    let names: Vec<&str> = Users::all_columns()
+        .iter()
+        .map(|c| c.column_name())
+        .collect();
+    assert_eq!(names, ["id", "email", "name"]);
+}
+
+#[test]
+fn test_table_macro_column_metadata() {
+    let columns = Users::columns();
+
+    assert_eq!(columns.id.table_name(), "users");
+    assert_eq!(columns.id.sql_type(), Some(PgType::BigInt));
+    assert!(columns.id.is_primary_key());
+    assert!(!columns.id.is_unique());
+
+    assert!(columns.email.is_unique());
+    assert!(!columns.email.is_nullable());
+
+    assert_eq!(columns.name.sql_type(), Some(PgType::Text));
+    assert!(columns.name.is_nullable());
+}
+
+#[test]
+fn test_table_macro_create_table() {
+    let sql = CreateTableBuilder::from_table(Users)
+        .unwrap()
+        .build_sql()
+        .unwrap();
+    assert_eq!(
+        sql,
+        "CREATE TABLE app.users (id bigint PRIMARY KEY, email text NOT NULL UNIQUE, name text)"
+    );
+}
+
+#[tokio::test]
+async fn test_table_macro_select() {
+    let in_memory_test_driver = Arc::new(InMemoryTestDriver::new());
+    let driver: Arc<dyn DatabaseDriver> =
+        Arc::clone(&in_memory_test_driver) as Arc<dyn DatabaseDriver>;
+    let client = PgRsClient::with_driver(driver);
+
+    client
+        .querier()
+        .select()
+        .columns(&[&UsersId, &UsersName])
+        .from(Users)
+        .where_(WhereClause::eq(&UsersEmail, "a@example.com"))
+        .execute()
+        .await
+        .unwrap();
+
+    in_memory_test_driver.assert_last_query(
+        "SELECT app.users.id, app.users.name FROM app.users WHERE app.users.email = $1",
+        &[SqlValue::Text("a@example.com".to_string())],
+    );
+}